@@ -1,6 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use allen_interval_relations::{
+use allen_intervals::{
     FromIntervals, Interval, IntervalFrom, IntervalFull, IntervalTo, NonEmpty, Relation,
 };
 
@@ -38,12 +38,12 @@ where
                     Interval { start: 3, end: 4 },
                     Interval { start: 4, end: 5 },
                 ] {
-                    black_box(f(
-                        black_box(unsafe { &NonEmpty::new_unchecked(interval_full.clone()) }),
-                        black_box(unsafe { &NonEmpty::new_unchecked(interval_from.clone()) }),
-                        black_box(unsafe { &NonEmpty::new_unchecked(interval_to.clone()) }),
-                        black_box(unsafe { &NonEmpty::new_unchecked(interval.clone()) }),
-                    ));
+                    f(
+                        black_box(unsafe { &NonEmpty::new_unchecked(*interval_full) }),
+                        black_box(unsafe { &NonEmpty::new_unchecked(*interval_from) }),
+                        black_box(unsafe { &NonEmpty::new_unchecked(*interval_to) }),
+                        black_box(unsafe { &NonEmpty::new_unchecked(*interval) }),
+                    );
                 }
             }
         }
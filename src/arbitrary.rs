@@ -0,0 +1,157 @@
+use core::cmp::Ordering;
+
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+use crate::{Interval, IntervalFrom, IntervalFull, IntervalTo, NonEmpty, Relation};
+
+impl<'a, T> Arbitrary<'a> for Interval<T>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let start = T::arbitrary(u)?;
+        let end = T::arbitrary(u)?;
+        Ok(Self { start, end })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(T, T)>::size_hint(depth)
+    }
+}
+
+impl<'a, T> Arbitrary<'a> for IntervalFrom<T>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let start = T::arbitrary(u)?;
+        Ok(Self { start })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+impl<'a, T> Arbitrary<'a> for IntervalTo<T>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let end = T::arbitrary(u)?;
+        Ok(Self { end })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for IntervalFull {
+    fn arbitrary(_u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+impl<'a, T> Arbitrary<'a> for NonEmpty<Interval<T>>
+where
+    T: Arbitrary<'a> + PartialOrd,
+{
+    /// Generates a pair of values and orders them,
+    /// failing with `Error::IncorrectFormat` if they are equal or unordered.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let a = T::arbitrary(u)?;
+        let b = T::arbitrary(u)?;
+
+        match a.partial_cmp(&b) {
+            Some(Ordering::Less) => Ok(Self(Interval { start: a, end: b })),
+            Some(Ordering::Greater) => Ok(Self(Interval { start: b, end: a })),
+            Some(Ordering::Equal) | None => Err(Error::IncorrectFormat),
+        }
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(T, T)>::size_hint(depth)
+    }
+}
+
+impl<'a, T> Arbitrary<'a> for NonEmpty<IntervalFrom<T>>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        IntervalFrom::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        IntervalFrom::<T>::size_hint(depth)
+    }
+}
+
+impl<'a, T> Arbitrary<'a> for NonEmpty<IntervalTo<T>>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        IntervalTo::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        IntervalTo::<T>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for NonEmpty<IntervalFull> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        IntervalFull::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        IntervalFull::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Relation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&Self::ALL).copied()
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_empty_interval() {
+        let data = [3, 1, 2, 2];
+        let mut u = Unstructured::new(&data);
+
+        assert_eq!(
+            NonEmpty::<Interval<u8>>::arbitrary(&mut u),
+            Ok(NonEmpty(Interval { start: 1, end: 3 }))
+        );
+        assert_eq!(
+            NonEmpty::<Interval<u8>>::arbitrary(&mut u),
+            Err(Error::IncorrectFormat)
+        );
+    }
+
+    #[test]
+    fn relation() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&data);
+
+        while !u.is_empty() {
+            let relation = Relation::arbitrary(&mut u).unwrap();
+            assert!(Relation::ALL.contains(&relation));
+        }
+    }
+}
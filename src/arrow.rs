@@ -0,0 +1,273 @@
+//! Vectorized [Apache Arrow](https://arrow.apache.org) kernels for Allen's interval relations.
+//!
+//! The kernels operate on columns of interval endpoints,
+//! interpreting null endpoints as unbounded (i.e. [`Bound::Unbounded`]),
+//! and produce null results for rows containing an empty (or unordered) interval.
+//!
+//! ```
+//! use allen_intervals::{arrow::{relation_eq, relations, IntervalColumns}, Relation};
+//! use arrow_array::{BooleanArray, Int32Array, UInt8Array};
+//!
+//! let s_start = Int32Array::from(vec![Some(1), Some(1), None]);
+//! let s_end = Int32Array::from(vec![Some(4), Some(1), Some(3)]);
+//! let t_start = Int32Array::from(vec![Some(5), Some(2), Some(2)]);
+//! let t_end = Int32Array::from(vec![Some(8), Some(3), None]);
+//!
+//! let s = IntervalColumns::new(&s_start, &s_end);
+//! let t = IntervalColumns::new(&t_start, &t_end);
+//!
+//! let overlaps = Relation::Overlaps { is_inverted: false };
+//!
+//! // Relations are encoded as their stable codes (see `BasicRelation`):
+//! assert_eq!(
+//!     relations(s, t).unwrap(),
+//!     UInt8Array::from(vec![Some(0), None, Some(2)])
+//! );
+//! assert_eq!(
+//!     relation_eq(s, t, overlaps).unwrap(),
+//!     BooleanArray::from(vec![Some(false), None, Some(true)])
+//! );
+//! ```
+
+use arrow_array::{types::ArrowPrimitiveType, BooleanArray, PrimitiveArray, UInt8Array};
+use arrow_schema::ArrowError;
+
+use crate::{Bound, Bounds, Relation};
+
+/// The start and end columns of a batch of intervals.
+#[derive(Debug)]
+pub struct IntervalColumns<'a, T>
+where
+    T: ArrowPrimitiveType,
+{
+    /// The intervals' start endpoints, with nulls denoting unbounded starts.
+    pub start: &'a PrimitiveArray<T>,
+    /// The intervals' end endpoints, with nulls denoting unbounded ends.
+    pub end: &'a PrimitiveArray<T>,
+}
+
+impl<'a, T> IntervalColumns<'a, T>
+where
+    T: ArrowPrimitiveType,
+{
+    /// Creates a batch of intervals from its start and end columns.
+    pub fn new(start: &'a PrimitiveArray<T>, end: &'a PrimitiveArray<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the number of intervals in the batch.
+    pub fn len(&self) -> usize {
+        self.start.len()
+    }
+
+    /// Returns `true` iff the batch contains no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.start.is_empty()
+    }
+
+    fn bounds(&self) -> impl Iterator<Item = Bounds<T::Native>> + 'a {
+        let bound = |value: Option<T::Native>| value.map_or(Bound::Unbounded, Bound::Bounded);
+
+        self.start
+            .iter()
+            .zip(self.end.iter())
+            .map(move |(start, end)| Bounds {
+                start: bound(start),
+                end: bound(end),
+            })
+    }
+}
+
+impl<T> Clone for IntervalColumns<'_, T>
+where
+    T: ArrowPrimitiveType,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for IntervalColumns<'_, T> where T: ArrowPrimitiveType {}
+
+/// Computes the relation between each pair of intervals `s[i]` and `t[i]`,
+/// encoded as the relation's stable code (see [`BasicRelation`](crate::BasicRelation)).
+///
+/// Rows for which either interval is empty (or unordered) are null.
+pub fn relations<T>(
+    s: IntervalColumns<'_, T>,
+    t: IntervalColumns<'_, T>,
+) -> Result<UInt8Array, ArrowError>
+where
+    T: ArrowPrimitiveType,
+{
+    Ok(classify(s, t)?
+        .map(|relation| relation.map(|relation| relation.code()))
+        .collect())
+}
+
+/// Checks whether each pair of intervals `s[i]` and `t[i]` stands in `relation`.
+///
+/// Rows for which either interval is empty (or unordered) are null.
+pub fn relation_eq<T>(
+    s: IntervalColumns<'_, T>,
+    t: IntervalColumns<'_, T>,
+    relation: Relation,
+) -> Result<BooleanArray, ArrowError>
+where
+    T: ArrowPrimitiveType,
+{
+    relation_in(s, t, &[relation])
+}
+
+/// Checks whether each pair of intervals `s[i]` and `t[i]` stands in any of `relations`.
+///
+/// Rows for which either interval is empty (or unordered) are null.
+pub fn relation_in<T>(
+    s: IntervalColumns<'_, T>,
+    t: IntervalColumns<'_, T>,
+    relations: &[Relation],
+) -> Result<BooleanArray, ArrowError>
+where
+    T: ArrowPrimitiveType,
+{
+    let mut mask = [false; 13];
+
+    for relation in relations {
+        mask[usize::from(relation.code())] = true;
+    }
+
+    Ok(classify(s, t)?
+        .map(|relation| relation.map(|relation| mask[usize::from(relation.code())]))
+        .collect())
+}
+
+fn classify<'a, T>(
+    s: IntervalColumns<'a, T>,
+    t: IntervalColumns<'a, T>,
+) -> Result<impl Iterator<Item = Option<Relation>> + 'a, ArrowError>
+where
+    T: ArrowPrimitiveType,
+{
+    let len = s.len();
+
+    if [s.end.len(), t.start.len(), t.end.len()]
+        .iter()
+        .any(|&other| other != len)
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "all interval columns must have the same length".to_owned(),
+        ));
+    }
+
+    Ok(s.bounds().zip(t.bounds()).map(|(s, t)| {
+        if !is_non_empty(&s) || !is_non_empty(&t) {
+            return None;
+        }
+
+        Relation::try_from_bounds(&s, &t).ok()
+    }))
+}
+
+fn is_non_empty<T>(bounds: &Bounds<T>) -> bool
+where
+    T: PartialOrd,
+{
+    match (&bounds.start, &bounds.end) {
+        (Bound::Bounded(start), Bound::Bounded(end)) => start < end,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Float64Array, Int64Array};
+
+    use crate::{FromIntervals, Interval, IntervalFrom, IntervalFull, IntervalTo, NonEmpty};
+
+    use super::*;
+
+    #[test]
+    fn relations_match_from_intervals() {
+        let s_start = Int64Array::from(vec![Some(1), None, Some(3), None, Some(2)]);
+        let s_end = Int64Array::from(vec![Some(5), Some(4), None, None, Some(6)]);
+        let t_start = Int64Array::from(vec![Some(5), Some(4), None, Some(1), Some(2)]);
+        let t_end = Int64Array::from(vec![Some(9), None, Some(3), None, Some(4)]);
+
+        let actual = relations(
+            IntervalColumns::new(&s_start, &s_end),
+            IntervalColumns::new(&t_start, &t_end),
+        )
+        .unwrap();
+
+        let interval = |start, end| NonEmpty::try_from(Interval { start, end }).unwrap();
+        let from = |start| NonEmpty::from(IntervalFrom { start });
+        let to = |end| NonEmpty::from(IntervalTo { end });
+        let full = NonEmpty::from(IntervalFull);
+
+        let expected = UInt8Array::from(vec![
+            Relation::from_intervals(&interval(1, 5), &interval(5, 9)).code(),
+            Relation::from_intervals(&to(4), &from(4)).code(),
+            Relation::from_intervals(&from(3), &to(3)).code(),
+            Relation::from_intervals(&full, &from(1_i64)).code(),
+            Relation::from_intervals(&interval(2, 6), &interval(2, 4)).code(),
+        ]);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn empty_and_unordered_intervals_are_null() {
+        let s_start = Float64Array::from(vec![1.0, 2.0, f64::NAN, 1.0]);
+        let s_end = Float64Array::from(vec![2.0, 2.0, 3.0, 3.0]);
+        let t_start = Float64Array::from(vec![1.5, 0.0, 0.0, 4.0]);
+        let t_end = Float64Array::from(vec![2.5, 1.0, 1.0, 3.0]);
+
+        let s = IntervalColumns::new(&s_start, &s_end);
+        let t = IntervalColumns::new(&t_start, &t_end);
+
+        assert_eq!(
+            relations(s, t).unwrap(),
+            UInt8Array::from(vec![
+                Some(Relation::Overlaps { is_inverted: false }.code()),
+                None,
+                None,
+                None,
+            ])
+        );
+    }
+
+    #[test]
+    fn predicates() {
+        let s_start = Int64Array::from(vec![3, 1, 1]);
+        let s_end = Int64Array::from(vec![4, 9, 4]);
+        let t_start = Int64Array::from(vec![1, 2, 2]);
+        let t_end = Int64Array::from(vec![9, 4, 4]);
+
+        let s = IntervalColumns::new(&s_start, &s_end);
+        let t = IntervalColumns::new(&t_start, &t_end);
+
+        let during = Relation::Contains { is_inverted: true };
+        let finished_by = Relation::Finishes { is_inverted: true };
+
+        assert_eq!(
+            relation_eq(s, t, during).unwrap(),
+            BooleanArray::from(vec![true, false, false])
+        );
+        assert_eq!(
+            relation_in(s, t, &[during, finished_by]).unwrap(),
+            BooleanArray::from(vec![true, false, true])
+        );
+    }
+
+    #[test]
+    fn mismatched_lengths() {
+        let short = Int64Array::from(vec![1]);
+        let long = Int64Array::from(vec![1, 2]);
+
+        assert!(relations(
+            IntervalColumns::new(&short, &short),
+            IntervalColumns::new(&short, &long),
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,471 @@
+use std::{error::Error, io::Write};
+
+use allen_intervals::{
+    Bound, Bounds, Interval, IntervalError, IntervalFrom, IntervalFull, IntervalTo, NonEmpty,
+    Relation, RelationSet, TryFromIntervals,
+};
+
+use crate::input::{Cell, InputArgs, Raw, Table, Value};
+
+/// A labeled interval read from a table.
+struct Labeled<T> {
+    id: String,
+    bounds: Bounds<T>,
+}
+
+/// Returns the table's valid intervals, warning about any skipped invalid ones.
+fn intervals<T>(input: &InputArgs, table: &Table, name: &str) -> Vec<Labeled<T>>
+where
+    T: Value,
+{
+    let mut skipped = 0;
+
+    let intervals = table
+        .records
+        .iter()
+        .filter_map(|record| match input.bounds(&record.start, &record.end) {
+            Ok(bounds) => Some(Labeled {
+                id: record.id.clone(),
+                bounds,
+            }),
+            Err(_) => {
+                skipped += 1;
+                None
+            }
+        })
+        .collect();
+
+    if skipped > 0 {
+        eprintln!("allen: skipped {skipped} invalid interval(s) in {name} (see `allen validate`)");
+    }
+
+    intervals
+}
+
+/// A non-empty interval of any of the crate's interval types.
+enum AnyInterval<T> {
+    Interval(NonEmpty<Interval<T>>),
+    From(NonEmpty<IntervalFrom<T>>),
+    To(NonEmpty<IntervalTo<T>>),
+    Full(NonEmpty<IntervalFull>),
+}
+
+impl<T> AnyInterval<T>
+where
+    T: PartialOrd + Copy,
+{
+    fn new(bounds: &Bounds<T>) -> Result<Self, IntervalError> {
+        match (bounds.start, bounds.end) {
+            (Bound::Bounded(start), Bound::Bounded(end)) => {
+                NonEmpty::try_from(Interval { start, end }).map(Self::Interval)
+            }
+            (Bound::Bounded(start), Bound::Unbounded) => {
+                Ok(Self::From(IntervalFrom { start }.into()))
+            }
+            (Bound::Unbounded, Bound::Bounded(end)) => Ok(Self::To(IntervalTo { end }.into())),
+            (Bound::Unbounded, Bound::Unbounded) => Ok(Self::Full(IntervalFull.into())),
+        }
+    }
+}
+
+/// Returns the relation between the intervals with bounds `s` and `t`.
+fn relation<T>(s: &Bounds<T>, t: &Bounds<T>) -> Result<Relation, IntervalError>
+where
+    T: PartialOrd + Copy,
+{
+    macro_rules! relation_to_t {
+        ($s:expr, $t:expr) => {
+            match $t {
+                AnyInterval::Interval(t) => Relation::try_from_intervals($s, &t),
+                AnyInterval::From(t) => Relation::try_from_intervals($s, &t),
+                AnyInterval::To(t) => Relation::try_from_intervals($s, &t),
+                AnyInterval::Full(t) => Relation::try_from_intervals($s, &t),
+            }
+        };
+    }
+
+    let t = AnyInterval::new(t)?;
+
+    match AnyInterval::new(s)? {
+        AnyInterval::Interval(s) => relation_to_t!(&s, t),
+        AnyInterval::From(s) => relation_to_t!(&s, t),
+        AnyInterval::To(s) => relation_to_t!(&s, t),
+        AnyInterval::Full(s) => relation_to_t!(&s, t),
+    }
+}
+
+fn relation_text(relation: Relation, names: bool) -> String {
+    match names {
+        true => relation.name().to_owned(),
+        false => relation.abbreviation().to_owned(),
+    }
+}
+
+/// Writes the relation between each pair of intervals,
+/// either between each interval of `table` and each later one,
+/// or between each interval of `table` and each interval of `other`.
+pub fn pairs<T>(
+    input: &InputArgs,
+    table: &Table,
+    other: Option<&Table>,
+    names: bool,
+    output: impl Write,
+) -> Result<(), Box<dyn Error>>
+where
+    T: Value,
+{
+    let s_intervals = intervals::<T>(input, table, "FILE");
+    let t_intervals = other.map(|other| intervals::<T>(input, other, "OTHER"));
+
+    let mut writer = csv::Writer::from_writer(output);
+    writer.write_record(["s", "t", "relation"])?;
+
+    for (index, s) in s_intervals.iter().enumerate() {
+        let t_intervals = match &t_intervals {
+            Some(t_intervals) => &t_intervals[..],
+            None => &s_intervals[index + 1..],
+        };
+
+        for t in t_intervals {
+            let relation = relation(&s.bounds, &t.bounds)?;
+            writer.write_record([&s.id, &t.id, &relation_text(relation, names)])?;
+        }
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Parses a reference interval in range syntax (e.g. `1..5`, `2024-01-01..` or `..`).
+pub fn reference<T>(input: &InputArgs, reference: &str) -> Result<Bounds<T>, Box<dyn Error>>
+where
+    T: Value,
+{
+    let (start, end) = reference.split_once("..").ok_or_else(|| {
+        format!("expected a reference interval as `START..END`, found {reference:?}")
+    })?;
+
+    input
+        .bounds(&Cell::Text(start.to_owned()), &Cell::Text(end.to_owned()))
+        .map_err(|issue| format!("invalid reference interval {reference:?}: {issue}").into())
+}
+
+/// Parses a set of relations either in set notation (e.g. `{o, s, d}`),
+/// or as a comma-separated list (e.g. `o,s,d`).
+pub fn relations(relations: &str) -> Result<RelationSet, String> {
+    let relations = relations.trim();
+
+    let set = if relations.starts_with('{') {
+        relations.parse()
+    } else {
+        relations.split(',').map(str::parse).collect()
+    };
+
+    set.map_err(|error| format!("{error}"))
+}
+
+/// Writes the records of `table` whose intervals stand in any of `relations` to `reference`.
+pub fn filter<T>(
+    input: &InputArgs,
+    table: &Table,
+    relations: RelationSet,
+    reference: &Bounds<T>,
+    mut output: impl Write,
+) -> Result<(), Box<dyn Error>>
+where
+    T: Value,
+{
+    let mut skipped = 0;
+    let mut matches = vec![];
+
+    for record in &table.records {
+        let Ok(bounds) = input.bounds::<T>(&record.start, &record.end) else {
+            skipped += 1;
+            continue;
+        };
+
+        if relations.contains(relation(&bounds, reference)?) {
+            matches.push(&record.raw);
+        }
+    }
+
+    if skipped > 0 {
+        eprintln!("allen: skipped {skipped} invalid interval(s) (see `allen validate`)");
+    }
+
+    // A table's records are either all CSV or all JSON records.
+    let mut records = vec![];
+
+    for raw in matches {
+        match raw {
+            Raw::Csv(raw) => records.push(raw),
+            Raw::Json(raw) => writeln!(output, "{raw}")?,
+        }
+    }
+
+    if table.headers.is_some() || !records.is_empty() {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(input.delimiter_byte()?)
+            .flexible(true)
+            .from_writer(&mut output);
+
+        if let Some(headers) = &table.headers {
+            writer.write_record(headers)?;
+        }
+
+        for record in records {
+            writer.write_record(record)?;
+        }
+
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Writes the number of pairs (of an interval of `table` and an interval of `other`)
+/// standing in each relation.
+pub fn histogram<T>(
+    input: &InputArgs,
+    table: &Table,
+    other: &Table,
+    names: bool,
+    output: impl Write,
+) -> Result<(), Box<dyn Error>>
+where
+    T: Value,
+{
+    let s_intervals = intervals::<T>(input, table, "FILE");
+    let t_intervals = intervals::<T>(input, other, "OTHER");
+
+    let mut counts = [0_usize; 13];
+
+    for s in &s_intervals {
+        for t in &t_intervals {
+            let relation = relation(&s.bounds, &t.bounds)?;
+            counts[usize::from(relation.code())] += 1;
+        }
+    }
+
+    let mut writer = csv::Writer::from_writer(output);
+    writer.write_record(["relation", "count"])?;
+
+    for (relation, count) in Relation::ALL.into_iter().zip(counts) {
+        writer.write_record([relation_text(relation, names), count.to_string()])?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Writes a report of the records of `table` not denoting valid non-empty intervals,
+/// returning `true` iff all of them are valid.
+pub fn validate<T>(
+    input: &InputArgs,
+    table: &Table,
+    output: impl Write,
+) -> Result<bool, Box<dyn Error>>
+where
+    T: Value,
+{
+    let mut writer = csv::Writer::from_writer(output);
+    writer.write_record(["row", "id", "issue"])?;
+
+    let mut invalid = 0;
+
+    for (index, record) in table.records.iter().enumerate() {
+        if let Err(issue) = input.bounds::<T>(&record.start, &record.end) {
+            invalid += 1;
+            writer.write_record([&(index + 1).to_string(), &record.id, &issue.to_string()])?;
+        }
+    }
+
+    writer.flush()?;
+
+    eprintln!(
+        "allen: {invalid} of {} interval(s) invalid",
+        table.records.len()
+    );
+
+    Ok(invalid == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use crate::Cli;
+
+    use super::*;
+
+    const CSV: &str = "\
+id,start,end
+a,1,5
+b,5,9
+c,,3
+d,4,4
+e,2,
+";
+
+    fn input(args: &[&str]) -> InputArgs {
+        let args = ["allen"].into_iter().chain(args.iter().copied());
+        Cli::parse_from(args.chain(["validate", "-"])).input
+    }
+
+    fn run(f: impl FnOnce(&mut Vec<u8>) -> Result<(), Box<dyn Error>>) -> String {
+        let mut output = vec![];
+        f(&mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn relation_of_any_intervals() {
+        let bounded = |start, end| Bounds {
+            start: Bound::Bounded(start),
+            end: Bound::Bounded(end),
+        };
+        let from = |start| Bounds {
+            start: Bound::Bounded(start),
+            end: Bound::Unbounded,
+        };
+
+        assert_eq!(
+            relation(&bounded(1, 2), &from(2)),
+            Ok(Relation::Meets { is_inverted: false })
+        );
+        assert_eq!(
+            relation(&from(2), &bounded(1, 2)),
+            Ok(Relation::Meets { is_inverted: true })
+        );
+        assert_eq!(
+            relation(&bounded(2, 2), &from(2)),
+            Err(IntervalError::EmptyInterval)
+        );
+    }
+
+    #[test]
+    fn pairs_within_table() {
+        let input = input(&["--id", "id"]);
+        let table = input.read_csv(CSV.as_bytes()).unwrap();
+
+        assert_eq!(
+            run(|output| pairs::<f64>(&input, &table, None, false, output)),
+            "\
+s,t,relation
+a,b,m
+a,c,oi
+a,e,o
+b,c,pi
+b,e,d
+c,e,o
+"
+        );
+    }
+
+    #[test]
+    fn pairs_between_tables() {
+        let input = input(&["--id", "id"]);
+        let table = input.read_csv(CSV.as_bytes()).unwrap();
+        let other = input.read_csv("id,start,end\nx,0,10\n".as_bytes()).unwrap();
+
+        assert_eq!(
+            run(|output| pairs::<f64>(&input, &table, Some(&other), true, output)),
+            "\
+s,t,relation
+a,x,is contained by
+b,x,is contained by
+c,x,overlaps
+e,x,is overlapped by
+"
+        );
+    }
+
+    #[test]
+    fn filter_by_relation() {
+        let input = input(&[]);
+        let table = input.read_csv(CSV.as_bytes()).unwrap();
+        let bounds = reference::<f64>(&input, "0..6").unwrap();
+
+        assert_eq!(
+            run(|output| filter(&input, &table, relations("d,s").unwrap(), &bounds, output)),
+            "\
+id,start,end
+a,1,5
+"
+        );
+
+        let bounds = reference::<f64>(&input, "3..").unwrap();
+
+        assert_eq!(
+            run(|output| filter(
+                &input,
+                &table,
+                relations("{p, m}").unwrap(),
+                &bounds,
+                output
+            )),
+            "\
+id,start,end
+c,,3
+"
+        );
+
+        assert!(reference::<f64>(&input, "3").is_err());
+        assert!(reference::<f64>(&input, "3..3").is_err());
+        assert!(relations("{p, x}").is_err());
+    }
+
+    #[test]
+    fn filter_jsonl() {
+        let input = input(&[]);
+        let table = input
+            .read_jsonl("{\"start\": 1, \"end\": 2}\n{\"start\": 3, \"end\": null}\n".as_bytes())
+            .unwrap();
+        let bounds = reference::<f64>(&input, "..2").unwrap();
+
+        assert_eq!(
+            run(|output| filter(&input, &table, relations("pi").unwrap(), &bounds, output)),
+            "{\"start\": 3, \"end\": null}\n"
+        );
+    }
+
+    #[test]
+    fn histogram_between_tables() {
+        let input = input(&[]);
+        let table = input.read_csv(CSV.as_bytes()).unwrap();
+        let other = input.read_csv("start,end\n0,10\n1,5\n".as_bytes()).unwrap();
+
+        let output = run(|output| histogram::<f64>(&input, &table, &other, false, output));
+        let lines: Vec<_> = output.lines().collect();
+
+        assert_eq!(lines.len(), 14);
+        assert_eq!(lines[0], "relation,count");
+        assert!(lines.contains(&"eq,1"));
+        assert!(lines.contains(&"d,2"));
+        assert!(lines.contains(&"mi,1"));
+        assert!(lines.contains(&"p,0"));
+    }
+
+    #[test]
+    fn validation_report() {
+        let input = input(&["--id", "id"]);
+        let table = input
+            .read_csv((CSV.to_owned() + "f,x,1\n").as_bytes())
+            .unwrap();
+
+        let mut output = vec![];
+        let is_valid = validate::<f64>(&input, &table, &mut output).unwrap();
+
+        assert!(!is_valid);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\
+row,id,issue
+4,d,empty interval
+6,f,\"invalid start \"\"x\"\"\"
+"
+        );
+    }
+}
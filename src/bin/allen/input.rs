@@ -0,0 +1,543 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::Path,
+};
+
+use allen_intervals::{iso8601::Iso8601Point, Bound, Bounds};
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use clap::{Args, ValueEnum};
+
+/// The format of an input file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Comma-separated values.
+    Csv,
+    /// JSON Lines (i.e. one JSON object per line).
+    Jsonl,
+}
+
+/// The type of the intervals' endpoints.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ValueType {
+    /// Numbers (e.g. `42` or `-1.5`).
+    Number,
+    /// ISO 8601 calendar dates (e.g. `2024-01-31`).
+    Date,
+    /// ISO 8601 date-times with a UTC offset (e.g. `2024-01-31T12:00Z`).
+    Datetime,
+}
+
+/// Options for reading intervals from input files.
+#[derive(Clone, Debug, Args)]
+pub struct InputArgs {
+    /// The input format (inferred from the file extension by default, or CSV for stdin).
+    #[arg(long, value_enum, global = true)]
+    pub format: Option<Format>,
+
+    /// The column (or JSON key) of the intervals' starts
+    /// (a zero-based index for CSV input with `--no-header`).
+    #[arg(long, default_value = "start", global = true)]
+    pub start: String,
+
+    /// The column (or JSON key) of the intervals' ends
+    /// (a zero-based index for CSV input with `--no-header`).
+    #[arg(long, default_value = "end", global = true)]
+    pub end: String,
+
+    /// The column (or JSON key) identifying intervals in the output
+    /// (the one-based row number by default).
+    #[arg(long, global = true)]
+    pub id: Option<String>,
+
+    /// The type of the intervals' endpoints.
+    #[arg(long = "type", value_enum, default_value_t = ValueType::Number, global = true)]
+    pub value_type: ValueType,
+
+    /// A value denoting an unbounded start or end (may be repeated).
+    ///
+    /// JSON `null` always denotes an unbounded endpoint.
+    #[arg(
+        long = "unbounded",
+        value_name = "MARKER",
+        default_values = ["", "..", "inf", "+inf", "-inf", "∞", "+∞", "-∞"],
+        global = true
+    )]
+    pub unbounded_markers: Vec<String>,
+
+    /// A value denoting an empty interval, if found in either endpoint's column (may be repeated).
+    #[arg(
+        long = "empty",
+        value_name = "MARKER",
+        default_values = ["empty"],
+        global = true
+    )]
+    pub empty_markers: Vec<String>,
+
+    /// The field delimiter of CSV input.
+    #[arg(long, default_value_t = ',', global = true)]
+    pub delimiter: char,
+
+    /// Treats the first row of CSV input as data, rather than as a header.
+    #[arg(long, global = true)]
+    pub no_header: bool,
+}
+
+/// The (unparsed) value of a record's field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Cell {
+    /// The field does not exist.
+    Missing,
+    /// The field is JSON `null`.
+    Null,
+    /// The field is text.
+    Text(String),
+    /// The field is a JSON number (kept exact until parsed as a [`Value`]).
+    Number(serde_json::Number),
+}
+
+/// The raw representation of a record, for passing it through unchanged.
+#[derive(Clone, Debug)]
+pub enum Raw {
+    Csv(csv::StringRecord),
+    Json(String),
+}
+
+/// A record read from an input file.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub id: String,
+    pub start: Cell,
+    pub end: Cell,
+    pub raw: Raw,
+}
+
+/// The records of an input file.
+#[derive(Clone, Debug)]
+pub struct Table {
+    pub headers: Option<csv::StringRecord>,
+    pub records: Vec<Record>,
+}
+
+impl InputArgs {
+    /// Reads the records of the file at `path` (or stdin, for `-`).
+    pub fn read(&self, path: &Path) -> Result<Table, Box<dyn Error>> {
+        let reader: Box<dyn Read> = if path == Path::new("-") {
+            Box::new(io::stdin())
+        } else {
+            let file = File::open(path)
+                .map_err(|error| format!("failed to open {}: {error}", path.display()))?;
+            Box::new(file)
+        };
+
+        let format = self.format.unwrap_or_else(|| {
+            match path.extension().and_then(|extension| extension.to_str()) {
+                Some("jsonl" | "ndjson" | "json") => Format::Jsonl,
+                _ => Format::Csv,
+            }
+        });
+
+        let table = match format {
+            Format::Csv => self.read_csv(reader),
+            Format::Jsonl => self.read_jsonl(reader),
+        };
+
+        table.map_err(|error| format!("failed to read {}: {error}", path.display()).into())
+    }
+
+    /// Returns the CSV delimiter as a byte, or an error if it is not ASCII.
+    pub fn delimiter_byte(&self) -> Result<u8, Box<dyn Error>> {
+        match self.delimiter.is_ascii() {
+            true => Ok(self.delimiter as u8),
+            false => Err("the delimiter must be an ASCII character".into()),
+        }
+    }
+
+    pub(crate) fn read_csv(&self, reader: impl Read) -> Result<Table, Box<dyn Error>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter_byte()?)
+            .has_headers(!self.no_header)
+            .flexible(true)
+            .from_reader(reader);
+
+        let headers = match self.no_header {
+            true => None,
+            false => Some(reader.headers()?.clone()),
+        };
+
+        let column = |name: &str| -> Result<usize, Box<dyn Error>> {
+            match &headers {
+                Some(headers) => headers
+                    .iter()
+                    .position(|header| header == name)
+                    .ok_or_else(|| format!("unknown column {name:?}").into()),
+                None => name
+                    .parse()
+                    .map_err(|_| format!("expected a column index, found {name:?}").into()),
+            }
+        };
+
+        let start = column(&self.start)?;
+        let end = column(&self.end)?;
+        let id = self.id.as_deref().map(column).transpose()?;
+
+        let cell = |record: &csv::StringRecord, index: usize| match record.get(index) {
+            Some(text) => Cell::Text(text.to_owned()),
+            None => Cell::Missing,
+        };
+
+        let mut records = vec![];
+
+        for (index, record) in reader.records().enumerate() {
+            let record = record?;
+
+            records.push(Record {
+                id: match id {
+                    Some(id) => record.get(id).unwrap_or_default().to_owned(),
+                    None => (index + 1).to_string(),
+                },
+                start: cell(&record, start),
+                end: cell(&record, end),
+                raw: Raw::Csv(record),
+            });
+        }
+
+        Ok(Table { headers, records })
+    }
+
+    pub(crate) fn read_jsonl(&self, reader: impl Read) -> Result<Table, Box<dyn Error>> {
+        let mut records = vec![];
+
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let value: serde_json::Value = serde_json::from_str(&line)
+                .map_err(|error| format!("line {}: {error}", index + 1))?;
+
+            let object = value
+                .as_object()
+                .ok_or_else(|| format!("line {}: expected a JSON object", index + 1))?;
+
+            let cell = |key: &str| match object.get(key) {
+                None => Cell::Missing,
+                Some(serde_json::Value::Null) => Cell::Null,
+                Some(serde_json::Value::String(text)) => Cell::Text(text.clone()),
+                Some(serde_json::Value::Number(number)) => Cell::Number(number.clone()),
+                Some(value) => Cell::Text(value.to_string()),
+            };
+
+            let id = match &self.id {
+                Some(id) => match cell(id) {
+                    Cell::Text(text) => text,
+                    Cell::Number(number) => number.to_string(),
+                    Cell::Missing | Cell::Null => String::new(),
+                },
+                None => (records.len() + 1).to_string(),
+            };
+
+            records.push(Record {
+                id,
+                start: cell(&self.start),
+                end: cell(&self.end),
+                raw: Raw::Json(line),
+            });
+        }
+
+        Ok(Table {
+            headers: None,
+            records,
+        })
+    }
+
+    /// Parses a record's cells into the bounds of a non-empty interval.
+    pub fn bounds<T>(&self, start: &Cell, end: &Cell) -> Result<Bounds<T>, Issue>
+    where
+        T: Value,
+    {
+        let start: Bound<T> = self.bound(start, Endpoint::Start)?;
+        let end: Bound<T> = self.bound(end, Endpoint::End)?;
+
+        for value in [&start, &end] {
+            if let Bound::Bounded(value) = value {
+                if value.partial_cmp(value).is_none() {
+                    return Err(Issue::Ambiguous);
+                }
+            }
+        }
+
+        if let (Bound::Bounded(start), Bound::Bounded(end)) = (&start, &end) {
+            match start.partial_cmp(end) {
+                Some(Ordering::Less) => {}
+                Some(Ordering::Equal) => return Err(Issue::Empty),
+                Some(Ordering::Greater) => return Err(Issue::Inverted),
+                None => return Err(Issue::Ambiguous),
+            }
+        }
+
+        Ok(Bounds { start, end })
+    }
+
+    fn bound<T>(&self, cell: &Cell, endpoint: Endpoint) -> Result<Bound<T>, Issue>
+    where
+        T: Value,
+    {
+        match cell {
+            Cell::Missing => Err(Issue::Missing(endpoint)),
+            Cell::Null => Ok(Bound::Unbounded),
+            Cell::Number(number) => T::from_number(number)
+                .map(Bound::Bounded)
+                .ok_or_else(|| Issue::Invalid(endpoint, number.to_string())),
+            Cell::Text(text) => {
+                let text = text.trim();
+
+                if self.empty_markers.iter().any(|marker| marker == text) {
+                    return Err(Issue::Empty);
+                }
+
+                if self.unbounded_markers.iter().any(|marker| marker == text) {
+                    return Ok(Bound::Unbounded);
+                }
+
+                T::parse(text)
+                    .map(Bound::Bounded)
+                    .ok_or_else(|| Issue::Invalid(endpoint, text.to_owned()))
+            }
+        }
+    }
+}
+
+/// Either endpoint of an interval.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Endpoint {
+    Start,
+    End,
+}
+
+impl Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Start => f.write_str("start"),
+            Self::End => f.write_str("end"),
+        }
+    }
+}
+
+/// A reason for a record not denoting a valid non-empty interval.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Issue {
+    /// The endpoint's column is missing.
+    Missing(Endpoint),
+    /// The endpoint's value could not be parsed.
+    Invalid(Endpoint, String),
+    /// The interval is empty (i.e. its start equals its end, or it is marked as empty).
+    Empty,
+    /// The interval's start is after its end.
+    Inverted,
+    /// The interval's endpoints are not comparable (e.g. `NaN`).
+    Ambiguous,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(endpoint) => write!(f, "missing {endpoint}"),
+            Self::Invalid(endpoint, value) => write!(f, "invalid {endpoint} {value:?}"),
+            Self::Empty => f.write_str("empty interval"),
+            Self::Inverted => f.write_str("start after end"),
+            Self::Ambiguous => f.write_str("ambiguous order"),
+        }
+    }
+}
+
+/// A type of the intervals' endpoints.
+pub trait Value: PartialOrd + Copy {
+    fn parse(text: &str) -> Option<Self>;
+
+    fn from_number(number: &serde_json::Number) -> Option<Self>;
+}
+
+impl Value for f64 {
+    fn parse(text: &str) -> Option<Self> {
+        text.parse().ok()
+    }
+
+    fn from_number(number: &serde_json::Number) -> Option<Self> {
+        number.as_f64()
+    }
+}
+
+impl Value for NaiveDate {
+    fn parse(text: &str) -> Option<Self> {
+        Self::parse_iso8601(text)
+    }
+
+    fn from_number(_number: &serde_json::Number) -> Option<Self> {
+        None
+    }
+}
+
+impl Value for DateTime<FixedOffset> {
+    fn parse(text: &str) -> Option<Self> {
+        Self::parse_iso8601(text)
+    }
+
+    fn from_number(_number: &serde_json::Number) -> Option<Self> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        input: InputArgs,
+    }
+
+    fn args(args: &[&str]) -> InputArgs {
+        Cli::parse_from(iter_args(args)).input
+    }
+
+    fn iter_args<'a>(args: &'a [&'a str]) -> impl Iterator<Item = &'a str> {
+        std::iter::once("allen").chain(args.iter().copied())
+    }
+
+    fn text(text: &str) -> Cell {
+        Cell::Text(text.to_owned())
+    }
+
+    #[test]
+    fn read_csv() {
+        let input = args(&["--start", "from", "--end", "to", "--id", "name"]);
+        let table = input
+            .read_csv("name,from,to\na,1,2\nb,3\n".as_bytes())
+            .unwrap();
+
+        assert_eq!(table.records.len(), 2);
+        assert_eq!(table.records[0].id, "a");
+        assert_eq!(table.records[0].start, text("1"));
+        assert_eq!(table.records[1].end, Cell::Missing);
+
+        let input = args(&["--no-header", "--start", "1", "--end", "0"]);
+        let table = input.read_csv("2,1\n".as_bytes()).unwrap();
+
+        assert_eq!(table.records[0].id, "1");
+        assert_eq!(table.records[0].start, text("1"));
+
+        let input = args(&["--start", "missing"]);
+        assert!(input.read_csv("start,end\n".as_bytes()).is_err());
+
+        // Latin-1 characters fit in a byte, but are not ASCII:
+        let input = args(&["--delimiter", "§"]);
+        assert!(input.read_csv("start§end\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn read_jsonl() {
+        let input = args(&["--id", "id"]);
+        let table = input
+            .read_jsonl(
+                "{\"id\": \"a\", \"start\": 1, \"end\": null}\n\n{\"id\": 2, \"start\": \"x\"}\n"
+                    .as_bytes(),
+            )
+            .unwrap();
+
+        assert_eq!(table.records.len(), 2);
+        assert_eq!(table.records[0].id, "a");
+        assert_eq!(table.records[0].start, Cell::Number(1.into()));
+        assert_eq!(table.records[0].end, Cell::Null);
+        assert_eq!(table.records[1].id, "2");
+        assert_eq!(table.records[1].start, text("x"));
+        assert_eq!(table.records[1].end, Cell::Missing);
+
+        assert!(input.read_jsonl("[1, 2]\n".as_bytes()).is_err());
+
+        // Integers beyond 2^53 are not rounded to the nearest `f64`:
+        let table = input
+            .read_jsonl("{\"id\": 9007199254740993, \"start\": -9007199254740993}\n".as_bytes())
+            .unwrap();
+
+        assert_eq!(table.records[0].id, "9007199254740993");
+        assert_eq!(
+            table.records[0].start,
+            Cell::Number((-9007199254740993_i64).into())
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let input = args(&[]);
+
+        assert_eq!(
+            input.bounds::<f64>(&text("1"), &Cell::Number(2.into())),
+            Ok(Bounds {
+                start: Bound::Bounded(1.0),
+                end: Bound::Bounded(2.0),
+            })
+        );
+        assert_eq!(
+            input.bounds::<f64>(&text(""), &text("∞")),
+            Ok(Bounds {
+                start: Bound::Unbounded,
+                end: Bound::Unbounded,
+            })
+        );
+        assert_eq!(
+            input.bounds::<f64>(&text("2"), &text("2")),
+            Err(Issue::Empty)
+        );
+        assert_eq!(
+            input.bounds::<f64>(&text("empty"), &Cell::Null),
+            Err(Issue::Empty)
+        );
+        assert_eq!(
+            input.bounds::<f64>(&text("3"), &text("2")),
+            Err(Issue::Inverted)
+        );
+        assert_eq!(
+            input.bounds::<f64>(&text("NaN"), &Cell::Null),
+            Err(Issue::Ambiguous)
+        );
+        assert_eq!(
+            input.bounds::<f64>(&text("x"), &Cell::Null),
+            Err(Issue::Invalid(Endpoint::Start, "x".to_owned()))
+        );
+        assert_eq!(
+            input.bounds::<f64>(&Cell::Null, &Cell::Missing),
+            Err(Issue::Missing(Endpoint::End))
+        );
+        assert_eq!(
+            input.bounds::<NaiveDate>(&text("2024-01-01"), &Cell::Number(1.into())),
+            Err(Issue::Invalid(Endpoint::End, "1".to_owned()))
+        );
+
+        let input = args(&["--unbounded", "open", "--empty", "-"]);
+
+        assert_eq!(
+            input.bounds::<f64>(&text("open"), &text("1")),
+            Ok(Bounds {
+                start: Bound::Unbounded,
+                end: Bound::Bounded(1.0),
+            })
+        );
+        assert_eq!(
+            input.bounds::<f64>(&text(""), &text("1")),
+            Err(Issue::Invalid(Endpoint::Start, String::new()))
+        );
+        assert_eq!(
+            input.bounds::<f64>(&text("-"), &text("1")),
+            Err(Issue::Empty)
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! `allen`: classifies intervals read from CSV or JSON Lines files by Allen's interval relations.
+
+use std::{
+    error::Error,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use allen_intervals::RelationSet;
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use clap::{Parser, Subcommand};
+
+mod commands;
+mod input;
+
+use self::input::{InputArgs, Value, ValueType};
+
+/// Classifies intervals read from CSV or JSON Lines files by Allen's interval relations.
+///
+/// Relations are written in their conventional abbreviations
+/// (i.e. `p`, `m`, `o`, `fi`, `di`, `s`, `eq`, `si`, `d`, `f`, `oi`, `mi`, `pi`).
+#[derive(Debug, Parser)]
+#[command(name = "allen", version)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Writes the relation between each pair of intervals as CSV.
+    ///
+    /// Pairs each interval of FILE with each later interval of FILE,
+    /// or (if given) with each interval of OTHER.
+    Pairs {
+        /// The input file (or `-` for stdin).
+        file: PathBuf,
+        /// Another input file, to pair FILE's intervals with.
+        other: Option<PathBuf>,
+        /// Writes the relations' full names (e.g. `is overlapped by`), rather than abbreviations.
+        #[arg(long)]
+        names: bool,
+    },
+    /// Writes the rows whose intervals stand in any of the given relations to a reference interval.
+    Filter {
+        /// The input file (or `-` for stdin).
+        file: PathBuf,
+        /// The relations of the rows' intervals to the reference interval to keep
+        /// (e.g. `d,s,f`, or `{d, s, f}`).
+        #[arg(long, value_parser = commands::relations)]
+        relation: RelationSet,
+        /// The reference interval, in range syntax (e.g. `1..5`, `2024-01-01..` or `..10`).
+        #[arg(long, allow_hyphen_values = true)]
+        reference: String,
+    },
+    /// Writes the number of pairs of intervals of FILE and OTHER standing in each relation as CSV.
+    Histogram {
+        /// The input file (or `-` for stdin).
+        file: PathBuf,
+        /// Another input file, to pair FILE's intervals with.
+        other: PathBuf,
+        /// Writes the relations' full names (e.g. `is overlapped by`), rather than abbreviations.
+        #[arg(long)]
+        names: bool,
+    },
+    /// Writes a report of the rows not denoting valid non-empty intervals as CSV.
+    ///
+    /// Exits with a non-zero status if any row is invalid.
+    Validate {
+        /// The input file (or `-` for stdin).
+        file: PathBuf,
+    },
+}
+
+fn run<T>(cli: &Cli) -> Result<ExitCode, Box<dyn Error>>
+where
+    T: Value,
+{
+    let input = &cli.input;
+    let mut output = BufWriter::new(io::stdout().lock());
+
+    match &cli.command {
+        Command::Pairs { file, other, names } => {
+            let table = input.read(file)?;
+            let other = other
+                .as_deref()
+                .map(|other| input.read(other))
+                .transpose()?;
+            commands::pairs::<T>(input, &table, other.as_ref(), *names, &mut output)?;
+        }
+        Command::Filter {
+            file,
+            relation,
+            reference,
+        } => {
+            let reference = commands::reference::<T>(input, reference)?;
+            let table = input.read(file)?;
+            commands::filter(input, &table, *relation, &reference, &mut output)?;
+        }
+        Command::Histogram { file, other, names } => {
+            let table = input.read(file)?;
+            let other = input.read(other)?;
+            commands::histogram::<T>(input, &table, &other, *names, &mut output)?;
+        }
+        Command::Validate { file } => {
+            let table = input.read(file)?;
+
+            if !commands::validate::<T>(input, &table, &mut output)? {
+                output.flush()?;
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+
+    output.flush()?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.input.value_type {
+        ValueType::Number => run::<f64>(&cli),
+        ValueType::Date => run::<NaiveDate>(&cli),
+        ValueType::Datetime => run::<DateTime<FixedOffset>>(&cli),
+    };
+
+    match result {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            eprintln!("allen: error: {error}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,385 @@
+//! The n-dimensional block algebra, relating axis-aligned blocks by an Allen relation per axis.
+//!
+//! > Balbiani, P., Condotta, J. F., & Fariñas del Cerro, L. (2002).
+//! > Tractability results in the block algebra.
+//! > Journal of Logic and Computation, 12(5), 885-909.
+//!
+//! [`Block<T, N>`] generalizes [`Rectangle<X, Y>`](crate::rectangle::Rectangle) to `N` axes,
+//! each of which may be bounded or unbounded (e.g. a region that is bounded in space,
+//! but extends indefinitely into the future), and [`BlockRelation<N>`] pairs their Allen relations.
+//!
+//! ```
+//! use allen_intervals::{
+//!     block::{Block, BlockRelation},
+//!     rectangle::Topology,
+//!     Interval, IntervalBounds, IntervalFrom, NonEmpty, Relation,
+//! };
+//!
+//! let interval = |start, end| NonEmpty::try_from(Interval { start, end }).unwrap().bounds();
+//! let from = |start| NonEmpty::from(IntervalFrom { start }).bounds();
+//!
+//! // x, y and time:
+//! let reserved = Block::new([interval(0, 10), interval(0, 10), from(100)]).unwrap();
+//! let visit = Block::new([interval(2, 4), interval(2, 4), interval(90, 110)]).unwrap();
+//!
+//! let relation = BlockRelation::from_blocks(&visit, &reserved);
+//! assert_eq!(relation.axis(2), Relation::Overlaps { is_inverted: false });
+//! assert_eq!(relation.topology(), Topology::Overlapping);
+//! ```
+
+use core::fmt::{self, Display};
+
+use crate::{
+    rectangle::{Rectangle, Topology},
+    Bound, Bounds, IntervalBounds, IntervalError, NonEmpty, Relation, RelationSet,
+};
+
+/// An axis-aligned block of `N` dimensions, of a non-empty (possibly unbounded) extent per axis.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Block<T, const N: usize> {
+    axes: [Bounds<T>; N],
+}
+
+impl<T, const N: usize> Block<T, N> {
+    /// Creates a block from the bounds of its extent along each axis
+    /// (e.g. as obtained from non-empty intervals of any type via [`IntervalBounds::bounds`]),
+    /// or returns an error if any of the extents is empty, or its bounds are not totally ordered.
+    pub fn new(axes: [Bounds<T>; N]) -> Result<Self, IntervalError>
+    where
+        T: PartialOrd,
+    {
+        for bounds in &axes {
+            if let (Bound::Bounded(start), Bound::Bounded(end)) = (&bounds.start, &bounds.end) {
+                match start.partial_cmp(end) {
+                    Some(core::cmp::Ordering::Less) => {}
+                    Some(_) => return Err(IntervalError::EmptyInterval),
+                    None => return Err(IntervalError::AmbiguousOrder),
+                }
+            }
+        }
+
+        Ok(Self { axes })
+    }
+
+    /// Returns the bounds of the block's extents along each axis.
+    #[inline]
+    pub fn axes(&self) -> &[Bounds<T>; N] {
+        &self.axes
+    }
+
+    /// Returns the bounds of the block's extent along `axis` (i.e. its projection onto the axis).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis >= N`.
+    #[inline]
+    pub fn axis(&self, axis: usize) -> &Bounds<T> {
+        &self.axes[axis]
+    }
+
+    /// Returns the bounds of the block's extents along each axis.
+    #[inline]
+    pub fn into_axes(self) -> [Bounds<T>; N] {
+        self.axes
+    }
+}
+
+impl<T, X, Y> From<Rectangle<X, Y>> for Block<T, 2>
+where
+    NonEmpty<X>: IntervalBounds<T>,
+    NonEmpty<Y>: IntervalBounds<T>,
+{
+    #[inline]
+    fn from(rectangle: Rectangle<X, Y>) -> Self {
+        Self {
+            axes: [rectangle.x.bounds(), rectangle.y.bounds()],
+        }
+    }
+}
+
+/// A basic relation of the block algebra between two blocks (e.g. `s` and `t`),
+/// i.e. the Allen relation of their extents along each axis.
+///
+/// The relations are comparable (via `Ord`) lexicographically by their axes' relations.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct BlockRelation<const N: usize> {
+    axes: [Relation; N],
+}
+
+impl<const N: usize> BlockRelation<N> {
+    /// Creates the relation of the Allen relations along each axis.
+    #[inline]
+    pub const fn new(axes: [Relation; N]) -> Self {
+        Self { axes }
+    }
+
+    /// Returns the relation between the blocks `s` and `t`.
+    #[inline]
+    pub fn from_blocks<T>(s: &Block<T, N>, t: &Block<T, N>) -> Self
+    where
+        T: Ord,
+    {
+        let mut axes = [Relation::Equals; N];
+
+        for (axis, relation) in axes.iter_mut().enumerate() {
+            *relation = Relation::from_bounds(&s.axes[axis], &t.axes[axis]);
+        }
+
+        Self { axes }
+    }
+
+    /// Returns the relation between the blocks `s` and `t`,
+    /// or an error if any of their endpoints are not totally ordered.
+    #[inline]
+    pub fn try_from_blocks<T>(s: &Block<T, N>, t: &Block<T, N>) -> Result<Self, IntervalError>
+    where
+        T: PartialOrd,
+    {
+        let mut axes = [Relation::Equals; N];
+
+        for (axis, relation) in axes.iter_mut().enumerate() {
+            *relation = Relation::try_from_bounds(&s.axes[axis], &t.axes[axis])?;
+        }
+
+        Ok(Self { axes })
+    }
+
+    /// Returns the Allen relations along each axis.
+    #[inline]
+    pub const fn axes(&self) -> &[Relation; N] {
+        &self.axes
+    }
+
+    /// Returns the Allen relation along `axis` (i.e. the relation of the blocks' projections onto the axis).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis >= N`.
+    #[inline]
+    pub const fn axis(&self, axis: usize) -> Relation {
+        self.axes[axis]
+    }
+
+    /// Returns the relation's converse.
+    #[inline]
+    pub fn as_converse(&self) -> Self {
+        Self {
+            axes: self.axes.map(|relation| relation.as_converse()),
+        }
+    }
+
+    /// Returns `true` iff the relation along each axis is contained in the axis' set of relations.
+    ///
+    /// ```
+    /// use allen_intervals::{block::BlockRelation, Relation, RelationSet};
+    ///
+    /// let relation = BlockRelation::new([Relation::Equals, Relation::Meets { is_inverted: false }]);
+    ///
+    /// assert!(relation.matches(&[RelationSet::all(), "{p, m}".parse().unwrap()]));
+    /// ```
+    #[inline]
+    pub fn matches(&self, relations: &[RelationSet; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(relations)
+            .all(|(relation, relations)| relations.contains(*relation))
+    }
+
+    /// Returns the relations possible along each axis between `s` and `u`,
+    /// given that `s` stands in `self` to `t`, and `t` stands in `other` to `u`.
+    #[inline]
+    pub fn compose(&self, other: &Self) -> [RelationSet; N] {
+        let mut axes = [RelationSet::empty(); N];
+
+        for (axis, relations) in axes.iter_mut().enumerate() {
+            *relations = self.axes[axis].compose(&other.axes[axis]);
+        }
+
+        axes
+    }
+
+    /// Returns the topological relation between the blocks.
+    #[inline]
+    pub fn topology(&self) -> Topology {
+        Topology::from_axes(&self.axes)
+    }
+
+    /// Returns `true` iff the blocks share no points.
+    #[inline]
+    pub fn is_disjoint(&self) -> bool {
+        self.topology() == Topology::Disjoint
+    }
+
+    /// Returns `true` iff the blocks share boundary points, but no interior points.
+    #[inline]
+    pub fn is_touching(&self) -> bool {
+        self.topology() == Topology::Touching
+    }
+
+    /// Returns `true` iff the blocks share interior points
+    /// (including if one is inside the other).
+    #[inline]
+    pub fn is_overlapping(&self) -> bool {
+        !matches!(self.topology(), Topology::Disjoint | Topology::Touching)
+    }
+
+    /// Returns `true` iff `s` is inside (or equal to) `t`.
+    #[inline]
+    pub fn is_inside(&self) -> bool {
+        matches!(
+            self.topology(),
+            Topology::Inside | Topology::TangentiallyInside | Topology::Equal
+        )
+    }
+
+    /// Returns `true` iff `t` is inside (or equal to) `s`.
+    #[inline]
+    pub fn is_containing(&self) -> bool {
+        self.as_converse().is_inside()
+    }
+}
+
+/// Formats as the tuple of Allen relations (e.g. `(o, d, m)`),
+/// using their full names for the alternate (`{:#}`) format.
+impl<const N: usize> Display for BlockRelation<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (axis, relation) in self.axes.iter().enumerate() {
+            if axis > 0 {
+                f.write_str(", ")?;
+            }
+            if f.alternate() {
+                write!(f, "{relation:#}")?;
+            } else {
+                write!(f, "{relation}")?;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rectangle::RectangleRelation, test_helpers::set, Interval, IntervalFrom, IntervalTo,
+    };
+
+    use super::*;
+
+    fn interval(start: i32, end: i32) -> Bounds<i32> {
+        crate::test_helpers::interval(start, end).bounds()
+    }
+
+    fn from(start: i32) -> Bounds<i32> {
+        NonEmpty::from(IntervalFrom { start }).bounds()
+    }
+
+    fn to(end: i32) -> Bounds<i32> {
+        NonEmpty::from(IntervalTo { end }).bounds()
+    }
+
+    #[test]
+    fn new() {
+        assert!(Block::new([interval(0, 1), from(0), to(0)]).is_ok());
+        assert_eq!(
+            Block::new([
+                interval(0, 1),
+                Bounds {
+                    start: Bound::Bounded(2),
+                    end: Bound::Bounded(2)
+                }
+            ]),
+            Err(IntervalError::EmptyInterval)
+        );
+        assert_eq!(
+            Block::new([Bounds {
+                start: Bound::Bounded(f64::NAN),
+                end: Bound::Unbounded
+            }])
+            .map(|_| ()),
+            Ok(())
+        );
+        assert_eq!(
+            Block::new([Bounds {
+                start: Bound::Bounded(f64::NAN),
+                end: Bound::Bounded(1.0)
+            }]),
+            Err(IntervalError::AmbiguousOrder)
+        );
+    }
+
+    #[test]
+    fn from_blocks() {
+        let s = Block::new([interval(0, 4), to(5), from(3)]).unwrap();
+        let t = Block::new([interval(4, 8), interval(2, 5), interval(0, 3)]).unwrap();
+
+        let relation = BlockRelation::from_blocks(&s, &t);
+
+        assert_eq!(
+            relation.axes(),
+            &[
+                Relation::Meets { is_inverted: false },
+                Relation::Finishes { is_inverted: true },
+                Relation::Meets { is_inverted: true },
+            ]
+        );
+        assert_eq!(relation.axis(1), Relation::Finishes { is_inverted: true });
+        assert_eq!(BlockRelation::from_blocks(&t, &s), relation.as_converse());
+        assert_eq!(BlockRelation::try_from_blocks(&s, &t), Ok(relation));
+        assert_eq!(relation.to_string(), "(m, fi, mi)");
+        assert_eq!(s.axis(2), &from(3));
+        assert!(relation.is_touching());
+    }
+
+    #[test]
+    fn matches_rectangle_algebra() {
+        let rectangles: Vec<Rectangle<Interval<i32>, Interval<i32>>> =
+            [(0, 2), (1, 3), (2, 4), (0, 4)]
+                .into_iter()
+                .flat_map(|x| [(0, 2), (1, 3), (0, 4)].into_iter().map(move |y| (x, y)))
+                .map(|((x0, x1), (y0, y1))| {
+                    Rectangle::new(
+                        Interval { start: x0, end: x1 }.try_into().unwrap(),
+                        Interval { start: y0, end: y1 }.try_into().unwrap(),
+                    )
+                })
+                .collect();
+
+        for s in &rectangles {
+            for t in &rectangles {
+                let expected = RectangleRelation::from_rectangles(s, t);
+                let relation = BlockRelation::from_blocks(&Block::from(*s), &Block::from(*t));
+
+                assert_eq!(relation.axes(), &[expected.x(), expected.y()]);
+                assert_eq!(relation.topology(), expected.topology());
+                assert_eq!(relation.is_inside(), expected.is_inside());
+                assert_eq!(relation.is_containing(), expected.is_containing());
+                assert_eq!(relation.is_overlapping(), expected.is_overlapping());
+                assert_eq!(relation.is_disjoint(), expected.is_disjoint());
+            }
+        }
+    }
+
+    #[test]
+    fn matches_and_compose() {
+        let relation = BlockRelation::new([
+            Relation::Contains { is_inverted: true },
+            Relation::Precedes { is_inverted: false },
+            Relation::Equals,
+        ]);
+
+        assert!(relation.matches(&[set("{s, d, f}"), set("{p, m}"), RelationSet::all()]));
+        assert!(!relation.matches(&[set("{s, f}"), set("{p, m}"), RelationSet::all()]));
+
+        let other = BlockRelation::new([
+            Relation::Meets { is_inverted: false },
+            Relation::Precedes { is_inverted: false },
+            Relation::Overlaps { is_inverted: true },
+        ]);
+
+        assert_eq!(
+            relation.compose(&other),
+            [set("{p}"), set("{p}"), set("{oi}")]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     interval::{Interval, IntervalFrom, IntervalFull, IntervalTo},
-    NonEmpty,
+    IntervalError, NonEmpty,
 };
 
 /// An endpoint of an interval of time.
@@ -114,3 +114,59 @@ impl<T> IntervalBounds<T> for IntervalFull {
         Bound::Unbounded
     }
 }
+
+impl<T> TryFrom<Bounds<T>> for Interval<T> {
+    type Error = IntervalError;
+
+    fn try_from(value: Bounds<T>) -> Result<Self, Self::Error> {
+        match value {
+            Bounds {
+                start: Bound::Bounded(start),
+                end: Bound::Bounded(end),
+            } => Ok(Self { start, end }),
+            _ => Err(IntervalError::MismatchedBounds),
+        }
+    }
+}
+
+impl<T> TryFrom<Bounds<T>> for IntervalFrom<T> {
+    type Error = IntervalError;
+
+    fn try_from(value: Bounds<T>) -> Result<Self, Self::Error> {
+        match value {
+            Bounds {
+                start: Bound::Bounded(start),
+                end: Bound::Unbounded,
+            } => Ok(Self { start }),
+            _ => Err(IntervalError::MismatchedBounds),
+        }
+    }
+}
+
+impl<T> TryFrom<Bounds<T>> for IntervalTo<T> {
+    type Error = IntervalError;
+
+    fn try_from(value: Bounds<T>) -> Result<Self, Self::Error> {
+        match value {
+            Bounds {
+                start: Bound::Unbounded,
+                end: Bound::Bounded(end),
+            } => Ok(Self { end }),
+            _ => Err(IntervalError::MismatchedBounds),
+        }
+    }
+}
+
+impl<T> TryFrom<Bounds<T>> for IntervalFull {
+    type Error = IntervalError;
+
+    fn try_from(value: Bounds<T>) -> Result<Self, Self::Error> {
+        match value {
+            Bounds {
+                start: Bound::Unbounded,
+                end: Bound::Unbounded,
+            } => Ok(Self),
+            _ => Err(IntervalError::MismatchedBounds),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! `const` construction and classification of intervals.
+//!
+//! Trait methods cannot be evaluated in `const` contexts,
+//! hence the `const` counterparts of `TryFrom`, `FromIntervals` and the predicate traits
+//! are provided as inherent methods on `NonEmpty<Interval<T>>` for all primitive integer types `T`.
+
+use core::cmp::Ordering;
+
+use crate::{Bb, Be, Eb, Ee, Interval, IntervalFrom, IntervalFull, IntervalTo, NonEmpty, Relation};
+
+impl<T> NonEmpty<IntervalFrom<T>> {
+    /// Wraps `interval`, which is never empty (usable in `const` contexts).
+    #[inline]
+    pub const fn new(interval: IntervalFrom<T>) -> Self {
+        Self(interval)
+    }
+}
+
+impl<T> NonEmpty<IntervalTo<T>> {
+    /// Wraps `interval`, which is never empty (usable in `const` contexts).
+    #[inline]
+    pub const fn new(interval: IntervalTo<T>) -> Self {
+        Self(interval)
+    }
+}
+
+impl NonEmpty<IntervalFull> {
+    /// Wraps `interval`, which is never empty (usable in `const` contexts).
+    #[inline]
+    pub const fn new(interval: IntervalFull) -> Self {
+        Self(interval)
+    }
+}
+
+macro_rules! const_fn_impl {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl NonEmpty<Interval<$t>> {
+                /// Wraps `interval` if it is not empty (usable in `const` contexts).
+                #[inline]
+                pub const fn new(interval: Interval<$t>) -> Option<Self> {
+                    match interval.start < interval.end {
+                        true => Some(Self(interval)),
+                        false => None,
+                    }
+                }
+
+                /// Creates the interval `START..END`, failing to compile if it is empty.
+                ///
+                /// The check is performed during monomorphization
+                /// (i.e. by `cargo build`, but not necessarily by `cargo check`),
+                /// or during `const` evaluation when used in a `const` item.
+                #[inline]
+                pub const fn from_literal<const START: $t, const END: $t>() -> Self {
+                    struct Check<const START: $t, const END: $t>;
+
+                    impl<const START: $t, const END: $t> Check<START, END> {
+                        const NON_EMPTY: () = assert!(START < END, "empty interval");
+                    }
+
+                    #[allow(clippy::let_unit_value)]
+                    let () = Check::<START, END>::NON_EMPTY;
+
+                    Self(Interval { start: START, end: END })
+                }
+
+                /// Returns the relation of `self` to `other` (usable in `const` contexts).
+                ///
+                /// Equivalent to `Relation::from_intervals(self, other)`.
+                #[inline]
+                pub const fn relation(&self, other: &Self) -> Relation {
+                    const fn ordering(s: $t, t: $t) -> Ordering {
+                        if s < t {
+                            Ordering::Less
+                        } else if s > t {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Equal
+                        }
+                    }
+
+                    let (s, t) = (&self.0, &other.0);
+
+                    Relation::from_atomic_relations(
+                        Bb(ordering(s.start, t.start)),
+                        Be(ordering(s.start, t.end)),
+                        Eb(ordering(s.end, t.start)),
+                        Ee(ordering(s.end, t.end)),
+                    )
+                }
+
+                const_fn_impl!(@predicates $t;
+                    const_precedes => Relation::Precedes { is_inverted: false },
+                    const_is_preceded_by => Relation::Precedes { is_inverted: true },
+                    const_meets => Relation::Meets { is_inverted: false },
+                    const_is_met_by => Relation::Meets { is_inverted: true },
+                    const_overlaps => Relation::Overlaps { is_inverted: false },
+                    const_is_overlapped_by => Relation::Overlaps { is_inverted: true },
+                    const_finishes => Relation::Finishes { is_inverted: false },
+                    const_is_finished_by => Relation::Finishes { is_inverted: true },
+                    const_contains => Relation::Contains { is_inverted: false },
+                    const_is_contained_by => Relation::Contains { is_inverted: true },
+                    const_starts => Relation::Starts { is_inverted: false },
+                    const_is_started_by => Relation::Starts { is_inverted: true },
+                    const_equals => Relation::Equals,
+                );
+            }
+        )*
+    };
+    (@predicates $t:ty; $($name:ident => $relation:pat),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "Returns `true` iff `self` stands in `",
+                stringify!($relation),
+                "` to `other` (usable in `const` contexts).",
+            )]
+            #[inline]
+            pub const fn $name(&self, other: &Self) -> bool {
+                matches!(self.relation(other), $relation)
+            }
+        )*
+    };
+}
+
+const_fn_impl!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::{FromIntervals, Meets, Precedes};
+
+    use super::*;
+
+    const MORNING: NonEmpty<Interval<u16>> = NonEmpty::<Interval<u16>>::from_literal::<480, 720>();
+    const AFTERNOON: NonEmpty<Interval<u16>> =
+        NonEmpty::<Interval<u16>>::from_literal::<720, 1020>();
+    const LUNCH: Option<NonEmpty<Interval<u16>>> = NonEmpty::<Interval<u16>>::new(Interval {
+        start: 720,
+        end: 780,
+    });
+    const EMPTY: Option<NonEmpty<Interval<u16>>> = NonEmpty::<Interval<u16>>::new(Interval {
+        start: 720,
+        end: 720,
+    });
+
+    const _: () = assert!(MORNING.const_meets(&AFTERNOON));
+    const _: () = assert!(matches!(
+        AFTERNOON.relation(&MORNING).as_converse(),
+        Relation::Meets { is_inverted: false }
+    ));
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            LUNCH.map(NonEmpty::into_inner),
+            Some(Interval {
+                start: 720,
+                end: 780
+            })
+        );
+        assert_eq!(EMPTY, None);
+        assert_eq!(
+            NonEmpty::<Interval<i8>>::new(Interval { start: 1, end: -1 }),
+            None
+        );
+
+        const FROM: NonEmpty<IntervalFrom<i32>> =
+            NonEmpty::<IntervalFrom<i32>>::new(IntervalFrom { start: 1 });
+        const TO: NonEmpty<IntervalTo<i32>> =
+            NonEmpty::<IntervalTo<i32>>::new(IntervalTo { end: 1 });
+        const FULL: NonEmpty<IntervalFull> = NonEmpty::<IntervalFull>::new(IntervalFull);
+
+        assert!(TO.meets(&FROM));
+        assert_eq!(FULL.into_inner(), IntervalFull);
+    }
+
+    #[test]
+    fn relation_matches_from_intervals() {
+        let intervals: Vec<NonEmpty<Interval<i64>>> = (0..4)
+            .flat_map(|start| (start + 1..5).map(move |end| Interval { start, end }))
+            .map(|interval| NonEmpty::<Interval<i64>>::new(interval).unwrap())
+            .collect();
+
+        for s in &intervals {
+            for t in &intervals {
+                let relation = Relation::from_intervals(s, t);
+
+                assert_eq!(s.relation(t), relation);
+                assert_eq!(s.const_precedes(t), s.precedes(t));
+                assert_eq!(
+                    s.const_is_contained_by(t),
+                    relation == Relation::Contains { is_inverted: true }
+                );
+                assert_eq!(s.const_equals(t), relation == Relation::Equals);
+            }
+        }
+    }
+
+    #[test]
+    fn extreme_values() {
+        let full = NonEmpty::<Interval<u8>>::from_literal::<0, 255>();
+        let end = NonEmpty::<Interval<u8>>::from_literal::<254, 255>();
+
+        assert!(end.const_finishes(&full));
+        assert!(full.const_is_finished_by(&end));
+    }
+}
@@ -0,0 +1,666 @@
+//! The cyclic interval algebra, relating intervals on a circle (e.g. of clock time, days of the week, or angles).
+//!
+//! > Balbiani, P., & Osmani, A. (2000).
+//! > A model for reasoning about topologic relations between cyclic intervals.
+//! > In KR 2000 (pp. 378-385).
+//!
+//! A cyclic interval runs from its start forward to its end, wrapping around the end of the cycle
+//! if its end lies before its start (e.g. a night shift from `22` to `6` o'clock).
+//! On a circle, disjoint intervals precede each other in both directions,
+//! and intervals may meet or overlap each other at both of their ends,
+//! which yields sixteen basic relations.
+//!
+//! ```
+//! use allen_intervals::{
+//!     cyclic::{CyclicInterval, CyclicRelation},
+//!     Interval, NonEmpty,
+//! };
+//!
+//! let day_of_hours = NonEmpty::try_from(Interval { start: 0, end: 24 }).unwrap();
+//!
+//! let night = CyclicInterval::new(22, 6, &day_of_hours).unwrap();
+//! let day = CyclicInterval::new(6, 22, &day_of_hours).unwrap();
+//! let early = CyclicInterval::new(4, 12, &day_of_hours).unwrap();
+//!
+//! assert_eq!(
+//!     CyclicRelation::from_cyclic_intervals(&night, &day),
+//!     CyclicRelation::MeetsAndMetBy
+//! );
+//! assert_eq!(
+//!     CyclicRelation::from_cyclic_intervals(&night, &early),
+//!     CyclicRelation::Overlaps { is_inverted: false }
+//! );
+//! assert!(night.contains_value(&23) && night.contains_value(&2));
+//!
+//! let (late, early) = night.to_intervals();
+//! assert_eq!(late.into_inner(), Interval { start: 22, end: 24 });
+//! assert_eq!(early.unwrap().into_inner(), Interval { start: 0, end: 6 });
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+
+use crate::{Interval, IntervalError, NonEmpty};
+
+/// An interval on a cycle, which runs from its start forward to its end,
+/// wrapping around the end of the cycle if its end lies before its start.
+///
+/// Its endpoints are positions within the cycle (e.g. `0..24` for hours of the day),
+/// i.e. lie within `cycle.start <= x < cycle.end`.
+/// The interval contains all values `x` with `start <= x < end`,
+/// or with `start <= x || x < end` if it wraps around.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct CyclicInterval<T> {
+    start: T,
+    end: T,
+    cycle: NonEmpty<Interval<T>>,
+}
+
+impl<T> CyclicInterval<T> {
+    /// Creates an interval running from `start` forward to `end` within `cycle`,
+    /// or returns an error if they are equal (i.e. the interval is either empty or the full cycle),
+    /// if either of them lies outside of the cycle, or if they are not totally ordered.
+    ///
+    /// Endpoints at the cycle's end are reduced to its start
+    /// (e.g. `18..24` within `0..24` is the interval `18..0`).
+    pub fn new(start: T, end: T, cycle: &NonEmpty<Interval<T>>) -> Result<Self, IntervalError>
+    where
+        T: PartialOrd + Clone,
+    {
+        let Interval {
+            start: cycle_start,
+            end: cycle_end,
+        } = cycle.as_inner();
+
+        let reduce = |value: T| -> Result<T, IntervalError> {
+            let is_after_start = cycle_start
+                .partial_cmp(&value)
+                .ok_or(IntervalError::AmbiguousOrder)?
+                .is_le();
+
+            match value.partial_cmp(cycle_end) {
+                Some(Ordering::Less) if is_after_start => Ok(value),
+                Some(Ordering::Equal) => Ok(cycle_start.clone()),
+                Some(_) => Err(IntervalError::MismatchedBounds),
+                None => Err(IntervalError::AmbiguousOrder),
+            }
+        };
+
+        let (start, end) = (reduce(start)?, reduce(end)?);
+
+        match start.partial_cmp(&end) {
+            Some(Ordering::Equal) => Err(IntervalError::EmptyInterval),
+            Some(_) => Ok(Self {
+                start,
+                end,
+                cycle: cycle.clone(),
+            }),
+            None => Err(IntervalError::AmbiguousOrder),
+        }
+    }
+
+    /// Returns the interval's start.
+    #[inline]
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    /// Returns the interval's end.
+    #[inline]
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+
+    /// Returns the cycle the interval lies on.
+    #[inline]
+    pub fn cycle(&self) -> &NonEmpty<Interval<T>> {
+        &self.cycle
+    }
+
+    /// Returns `true` iff the interval wraps around the end of the cycle (i.e. its end lies before its start).
+    #[inline]
+    pub fn is_wrapping(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.end < self.start
+    }
+
+    /// Returns `true` iff `value` lies within the interval.
+    #[inline]
+    pub fn contains_value(&self, value: &T) -> bool
+    where
+        T: PartialOrd,
+    {
+        let cycle = self.cycle.as_inner();
+
+        if !(&cycle.start <= value && value < &cycle.end) {
+            return false;
+        }
+
+        if self.is_wrapping() {
+            &self.start <= value || value < &self.end
+        } else {
+            &self.start <= value && value < &self.end
+        }
+    }
+
+    /// Returns the linear interval(s) covered by the interval within its cycle
+    /// (i.e. a single one, unless it wraps around the end of the cycle).
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn to_intervals(&self) -> (NonEmpty<Interval<T>>, Option<NonEmpty<Interval<T>>>)
+    where
+        T: PartialOrd + Clone,
+    {
+        let cycle = self.cycle.as_inner();
+
+        if !self.is_wrapping() {
+            let interval = Interval {
+                start: self.start.clone(),
+                end: self.end.clone(),
+            };
+
+            return (NonEmpty(interval), None);
+        }
+
+        let late = Interval {
+            start: self.start.clone(),
+            end: cycle.end.clone(),
+        };
+        let early = NonEmpty::try_from(Interval {
+            start: cycle.start.clone(),
+            end: self.end.clone(),
+        });
+
+        (NonEmpty(late), early.ok())
+    }
+}
+
+/// A basic relation of the cyclic interval algebra between two cyclic intervals (e.g. `s` and `t`).
+///
+/// Besides Allen's relations (other than "precedes", which on a circle is indistinguishable from its converse),
+/// the intervals may be disjoint, or meet or overlap each other at both of their ends.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum CyclicRelation {
+    /// The intervals are disjoint (`ppi`).
+    Disjoint,
+    /// `s` meets `t` (`m`), or is met by it (`mi`), and they are disjoint otherwise.
+    Meets {
+        /// `true` iff the relation is inverted, otherwise `false`.
+        is_inverted: bool,
+    },
+    /// `s` meets `t`, and is met by it (`mmi`), i.e. they cover the cycle without overlapping.
+    MeetsAndMetBy,
+    /// `s` overlaps `t` (`o`), or is overlapped by it (`oi`).
+    Overlaps {
+        /// `true` iff the relation is inverted, otherwise `false`.
+        is_inverted: bool,
+    },
+    /// `s` meets `t` and is overlapped by it (`moi`),
+    /// or overlaps `t` and is met by it (`omi`).
+    MeetsAndOverlappedBy {
+        /// `true` iff the relation is inverted, otherwise `false`.
+        is_inverted: bool,
+    },
+    /// `s` overlaps `t` and is overlapped by it (`ooi`), i.e. each of their ends lies within the other.
+    OverlapsAndOverlappedBy,
+    /// `s` starts `t` (`s`), or is started by it (`si`).
+    Starts {
+        /// `true` iff the relation is inverted, otherwise `false`.
+        is_inverted: bool,
+    },
+    /// `s` contains `t` (`di`), or is contained by it (`d`).
+    Contains {
+        /// `true` iff the relation is inverted, otherwise `false`.
+        is_inverted: bool,
+    },
+    /// `s` finishes `t` (`f`), or is finished by it (`fi`).
+    Finishes {
+        /// `true` iff the relation is inverted, otherwise `false`.
+        is_inverted: bool,
+    },
+    /// The intervals are equal (`eq`).
+    Equals,
+}
+
+impl CyclicRelation {
+    /// All sixteen basic relations.
+    pub const ALL: [Self; 16] = [
+        Self::Disjoint,
+        Self::Meets { is_inverted: false },
+        Self::Meets { is_inverted: true },
+        Self::MeetsAndMetBy,
+        Self::Overlaps { is_inverted: false },
+        Self::Overlaps { is_inverted: true },
+        Self::MeetsAndOverlappedBy { is_inverted: false },
+        Self::MeetsAndOverlappedBy { is_inverted: true },
+        Self::OverlapsAndOverlappedBy,
+        Self::Starts { is_inverted: false },
+        Self::Starts { is_inverted: true },
+        Self::Contains { is_inverted: false },
+        Self::Contains { is_inverted: true },
+        Self::Finishes { is_inverted: false },
+        Self::Finishes { is_inverted: true },
+        Self::Equals,
+    ];
+
+    /// Returns the relation between the cyclic intervals `s` and `t`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the intervals lie on different cycles.
+    #[inline]
+    pub fn from_cyclic_intervals<T>(s: &CyclicInterval<T>, t: &CyclicInterval<T>) -> Self
+    where
+        T: Ord,
+    {
+        assert!(s.cycle == t.cycle, "intervals on different cycles");
+
+        // The positions of values travelling forward from `s`'s start,
+        // as their ordering to `s`'s start and the value itself:
+        let position = |value| (value < &s.start, value);
+
+        let (start, end) = (position(&t.start), position(&t.end));
+        let s_end = position(&s.end);
+
+        Self::from_positions(
+            t.start == s.start,
+            t.end == s.start,
+            start.cmp(&s_end),
+            end.cmp(&s_end),
+            start < end,
+        )
+    }
+
+    /// Returns the relation between the cyclic intervals `s` and `t`,
+    /// or an error if they lie on different cycles,
+    /// or if any of their endpoints are not totally ordered.
+    #[inline]
+    pub fn try_from_cyclic_intervals<T>(
+        s: &CyclicInterval<T>,
+        t: &CyclicInterval<T>,
+    ) -> Result<Self, IntervalError>
+    where
+        T: PartialOrd,
+    {
+        if s.cycle != t.cycle {
+            return Err(IntervalError::MismatchedBounds);
+        }
+
+        let cmp = |a: &T, b: &T| a.partial_cmp(b).ok_or(IntervalError::AmbiguousOrder);
+
+        // The positions of values travelling forward from `s`'s start,
+        // as their ordering to `s`'s start and the value itself:
+        let position = |value| -> Result<_, IntervalError> {
+            Ok((cmp(value, &s.start)? == Ordering::Less, value))
+        };
+        let cmp_positions = |a: (bool, &T), b: (bool, &T)| match a.0.cmp(&b.0) {
+            Ordering::Equal => cmp(a.1, b.1),
+            ordering => Ok(ordering),
+        };
+
+        let (start, end) = (position(&t.start)?, position(&t.end)?);
+        let s_end = position(&s.end)?;
+
+        Ok(Self::from_positions(
+            cmp(&t.start, &s.start)? == Ordering::Equal,
+            cmp(&t.end, &s.start)? == Ordering::Equal,
+            cmp_positions(start, s_end)?,
+            cmp_positions(end, s_end)?,
+            cmp_positions(start, end)? == Ordering::Less,
+        ))
+    }
+
+    /// Returns the relation, given where `t`'s endpoints lie when travelling forward from `s`'s start:
+    /// whether they coincide with `s`'s start, their orderings to `s`'s end,
+    /// and whether `t`'s start comes before its end (i.e. `t` does not contain `s`'s start).
+    #[inline]
+    fn from_positions(
+        is_head_to_head: bool,
+        is_ending_at_start: bool,
+        start: Ordering,
+        end: Ordering,
+        is_ordered: bool,
+    ) -> Self {
+        use Ordering::*;
+
+        if is_head_to_head {
+            return match end {
+                Less => Self::Starts { is_inverted: true },
+                Equal => Self::Equals,
+                Greater => Self::Starts { is_inverted: false },
+            };
+        }
+
+        if is_ordered {
+            return match (start, end) {
+                (Less, Less) => Self::Contains { is_inverted: false },
+                (Less, Equal) => Self::Finishes { is_inverted: true },
+                (Less, Greater) => Self::Overlaps { is_inverted: false },
+                (Equal, _) => Self::Meets { is_inverted: false },
+                (Greater, _) => Self::Disjoint,
+            };
+        }
+
+        if is_ending_at_start {
+            return match start {
+                Less => Self::MeetsAndOverlappedBy { is_inverted: true },
+                Equal => Self::MeetsAndMetBy,
+                Greater => Self::Meets { is_inverted: true },
+            };
+        }
+
+        match (start, end) {
+            (Less, _) => Self::OverlapsAndOverlappedBy,
+            (Equal, _) => Self::MeetsAndOverlappedBy { is_inverted: false },
+            (Greater, Less) => Self::Overlaps { is_inverted: true },
+            (Greater, Equal) => Self::Finishes { is_inverted: false },
+            (Greater, Greater) => Self::Contains { is_inverted: true },
+        }
+    }
+
+    /// Returns the relation's converse.
+    #[inline]
+    pub const fn as_converse(&self) -> Self {
+        match *self {
+            Self::Meets { is_inverted } => Self::Meets {
+                is_inverted: !is_inverted,
+            },
+            Self::Overlaps { is_inverted } => Self::Overlaps {
+                is_inverted: !is_inverted,
+            },
+            Self::MeetsAndOverlappedBy { is_inverted } => Self::MeetsAndOverlappedBy {
+                is_inverted: !is_inverted,
+            },
+            Self::Starts { is_inverted } => Self::Starts {
+                is_inverted: !is_inverted,
+            },
+            Self::Contains { is_inverted } => Self::Contains {
+                is_inverted: !is_inverted,
+            },
+            Self::Finishes { is_inverted } => Self::Finishes {
+                is_inverted: !is_inverted,
+            },
+            relation => relation,
+        }
+    }
+
+    /// Returns the relation's conventional abbreviation
+    /// (i.e. one of `ppi`, `m`, `mi`, `mmi`, `o`, `oi`, `moi`, `omi`, `ooi`, `s`, `si`, `di`, `d`, `f`, `fi`, `eq`).
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::Disjoint => "ppi",
+            Self::Meets { is_inverted: false } => "m",
+            Self::Meets { is_inverted: true } => "mi",
+            Self::MeetsAndMetBy => "mmi",
+            Self::Overlaps { is_inverted: false } => "o",
+            Self::Overlaps { is_inverted: true } => "oi",
+            Self::MeetsAndOverlappedBy { is_inverted: false } => "moi",
+            Self::MeetsAndOverlappedBy { is_inverted: true } => "omi",
+            Self::OverlapsAndOverlappedBy => "ooi",
+            Self::Starts { is_inverted: false } => "s",
+            Self::Starts { is_inverted: true } => "si",
+            Self::Contains { is_inverted: false } => "di",
+            Self::Contains { is_inverted: true } => "d",
+            Self::Finishes { is_inverted: false } => "f",
+            Self::Finishes { is_inverted: true } => "fi",
+            Self::Equals => "eq",
+        }
+    }
+
+    /// Returns the relation's full English name (e.g. `meets and is overlapped by`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Disjoint => "is disjoint from",
+            Self::Meets { is_inverted: false } => "meets",
+            Self::Meets { is_inverted: true } => "is met by",
+            Self::MeetsAndMetBy => "meets and is met by",
+            Self::Overlaps { is_inverted: false } => "overlaps",
+            Self::Overlaps { is_inverted: true } => "is overlapped by",
+            Self::MeetsAndOverlappedBy { is_inverted: false } => "meets and is overlapped by",
+            Self::MeetsAndOverlappedBy { is_inverted: true } => "overlaps and is met by",
+            Self::OverlapsAndOverlappedBy => "overlaps and is overlapped by",
+            Self::Starts { is_inverted: false } => "starts",
+            Self::Starts { is_inverted: true } => "is started by",
+            Self::Contains { is_inverted: false } => "contains",
+            Self::Contains { is_inverted: true } => "is contained by",
+            Self::Finishes { is_inverted: false } => "finishes",
+            Self::Finishes { is_inverted: true } => "is finished by",
+            Self::Equals => "equals",
+        }
+    }
+}
+
+/// Formats as the relation's abbreviation (e.g. `moi`),
+/// or as its full name (e.g. `meets and is overlapped by`) for the alternate (`{:#}`) format.
+impl Display for CyclicRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.pad(self.name())
+        } else {
+            f.pad(self.abbreviation())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FromIntervals, Relation};
+
+    fn cycle(period: i32) -> NonEmpty<Interval<i32>> {
+        NonEmpty::try_from(Interval {
+            start: 0,
+            end: period,
+        })
+        .unwrap()
+    }
+
+    fn interval(start: i32, end: i32) -> CyclicInterval<i32> {
+        CyclicInterval::new(start, end, &cycle(24)).unwrap()
+    }
+
+    #[test]
+    fn from_cyclic_intervals() {
+        let cases = [
+            ((0, 2), (4, 6), "ppi"),
+            ((0, 2), (2, 6), "m"),
+            ((2, 6), (0, 2), "mi"),
+            ((6, 2), (2, 6), "mmi"),
+            ((0, 4), (2, 6), "o"),
+            ((2, 6), (0, 4), "oi"),
+            ((2, 6), (6, 4), "moi"),
+            ((6, 4), (2, 6), "omi"),
+            ((0, 6), (4, 2), "ooi"),
+            ((0, 2), (0, 4), "s"),
+            ((0, 4), (0, 2), "si"),
+            ((0, 6), (2, 4), "di"),
+            ((2, 4), (0, 6), "d"),
+            ((2, 4), (0, 4), "f"),
+            ((0, 4), (2, 4), "fi"),
+            ((6, 2), (6, 2), "eq"),
+            ((7, 1), (6, 2), "d"),
+            ((7, 1), (0, 1), "fi"),
+            ((5, 1), (3, 6), "oi"),
+        ];
+
+        for ((s_start, s_end), (t_start, t_end), expected) in cases {
+            let (s, t) = (interval(s_start, s_end), interval(t_start, t_end));
+            let relation = CyclicRelation::from_cyclic_intervals(&s, &t);
+
+            assert_eq!(relation.to_string(), expected, "{s:?} {t:?}");
+            assert_eq!(
+                CyclicRelation::from_cyclic_intervals(&t, &s),
+                relation.as_converse()
+            );
+            assert_eq!(
+                CyclicRelation::try_from_cyclic_intervals(&s, &t),
+                Ok(relation)
+            );
+        }
+    }
+
+    #[test]
+    fn invariant_under_rotation() {
+        // Every relation is realized, regardless of where the cycle is cut:
+        const PERIOD: i32 = 8;
+
+        let intervals: Vec<_> = (0..PERIOD)
+            .flat_map(|start| (0..PERIOD).map(move |end| (start, end)))
+            .filter(|(start, end)| start != end)
+            .collect();
+
+        for rotation in 0..PERIOD {
+            let interval = |start, end| CyclicInterval::new(start, end, &cycle(PERIOD)).unwrap();
+            let rotate = |(start, end): (i32, i32)| {
+                interval((start + rotation) % PERIOD, (end + rotation) % PERIOD)
+            };
+
+            let mut realized = Vec::new();
+
+            for &s in &intervals {
+                for &t in &intervals {
+                    let relation = CyclicRelation::from_cyclic_intervals(
+                        &interval(s.0, s.1),
+                        &interval(t.0, t.1),
+                    );
+
+                    assert_eq!(
+                        CyclicRelation::from_cyclic_intervals(&rotate(s), &rotate(t)),
+                        relation
+                    );
+
+                    if !realized.contains(&relation) {
+                        realized.push(relation);
+                    }
+                }
+            }
+
+            assert_eq!(realized.len(), CyclicRelation::ALL.len());
+        }
+    }
+
+    #[test]
+    fn agrees_with_linear_relations() {
+        // Within a cycle, non-wrapping intervals relate as their linear counterparts
+        // (except for precedence, which is indistinguishable from its converse):
+        for (s_start, s_end, t_start, t_end) in
+            [(0, 2, 3, 5), (0, 3, 1, 5), (1, 4, 1, 4), (2, 5, 0, 5)]
+        {
+            let s = NonEmpty::try_from(Interval {
+                start: s_start,
+                end: s_end,
+            })
+            .unwrap();
+            let t = NonEmpty::try_from(Interval {
+                start: t_start,
+                end: t_end,
+            })
+            .unwrap();
+
+            let relation = Relation::from_intervals(&s, &t);
+            let cyclic = CyclicRelation::from_cyclic_intervals(
+                &interval(s_start, s_end),
+                &interval(t_start, t_end),
+            );
+
+            match relation {
+                Relation::Precedes { .. } => assert_eq!(cyclic, CyclicRelation::Disjoint),
+                _ => assert_eq!(cyclic.to_string(), relation.to_string()),
+            }
+        }
+    }
+
+    #[test]
+    fn contains_value() {
+        let night = interval(22, 6);
+
+        assert!(night.is_wrapping());
+        assert!(night.contains_value(&22));
+        assert!(night.contains_value(&0));
+        assert!(!night.contains_value(&6));
+        assert!(!night.contains_value(&12));
+
+        let day = interval(6, 22);
+
+        assert!(!day.is_wrapping());
+        assert!(day.contains_value(&6));
+        assert!(!day.contains_value(&22));
+    }
+
+    #[test]
+    fn to_intervals() {
+        let linear = |start, end| NonEmpty::try_from(Interval { start, end }).unwrap();
+
+        assert_eq!(interval(6, 22).to_intervals(), (linear(6, 22), None));
+        assert_eq!(
+            interval(22, 6).to_intervals(),
+            (linear(22, 24), Some(linear(0, 6)))
+        );
+        assert_eq!(interval(22, 0).to_intervals(), (linear(22, 24), None));
+        assert_eq!(interval(18, 24).to_intervals(), (linear(18, 24), None));
+    }
+
+    #[test]
+    fn cycle_bounds() {
+        // Endpoints at the cycle's end are reduced to its start:
+        assert_eq!(interval(18, 24), interval(18, 0));
+        assert_eq!(interval(24, 6), interval(0, 6));
+        assert_eq!(
+            CyclicRelation::from_cyclic_intervals(&interval(18, 24), &interval(0, 6)),
+            CyclicRelation::Meets { is_inverted: false }
+        );
+
+        assert_eq!(
+            CyclicInterval::new(0, 24, &cycle(24)),
+            Err(IntervalError::EmptyInterval)
+        );
+        assert_eq!(
+            CyclicInterval::new(22, 30, &cycle(24)),
+            Err(IntervalError::MismatchedBounds)
+        );
+        assert_eq!(
+            CyclicInterval::new(-1, 6, &cycle(24)),
+            Err(IntervalError::MismatchedBounds)
+        );
+        assert!(!interval(22, 6).contains_value(&30));
+
+        let other = CyclicInterval::new(1, 2, &cycle(12)).unwrap();
+        assert_eq!(
+            CyclicRelation::try_from_cyclic_intervals(&interval(1, 2), &other),
+            Err(IntervalError::MismatchedBounds)
+        );
+    }
+
+    #[test]
+    fn try_from_cyclic_intervals() {
+        let cycle = NonEmpty::try_from(Interval {
+            start: 0.0,
+            end: 24.0,
+        })
+        .unwrap();
+
+        let s = CyclicInterval::new(22.0, 6.0, &cycle).unwrap();
+        let t = CyclicInterval {
+            start: f64::NAN,
+            end: 1.0,
+            cycle,
+        };
+
+        assert_eq!(
+            CyclicRelation::try_from_cyclic_intervals(&s, &t),
+            Err(IntervalError::AmbiguousOrder)
+        );
+        assert_eq!(
+            CyclicInterval::new(1.0, 1.0, &cycle),
+            Err(IntervalError::EmptyInterval)
+        );
+        assert_eq!(
+            CyclicInterval::new(f64::NAN, 1.0, &cycle),
+            Err(IntervalError::AmbiguousOrder)
+        );
+    }
+}
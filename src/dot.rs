@@ -0,0 +1,305 @@
+//! Export of labeled intervals as [Graphviz DOT][dot] graphs of their relations.
+//!
+//! Each interval becomes a node, and each pair of intervals becomes an edge labeled with their [`Relation`].
+//! Converse relations are collapsed, so that each pair is drawn once,
+//! directed such that its label is the non-inverted relation (e.g. `p` rather than `pi`),
+//! and undirected for equal intervals.
+//!
+//! Edges can be restricted to certain relations (e.g. `{p, m}` for dependency chains):
+//!
+//! ```
+//! use allen_intervals::{dot::DotGraph, Interval, NonEmpty, RelationSet};
+//!
+//! let mut graph = DotGraph::new().with_relations("{p, m}".parse::<RelationSet>().unwrap());
+//!
+//! graph.push("build", &NonEmpty::try_from(Interval { start: 0, end: 3 }).unwrap());
+//! graph.push("test", &NonEmpty::try_from(Interval { start: 3, end: 5 }).unwrap());
+//! graph.push("lint", &NonEmpty::try_from(Interval { start: 2, end: 4 }).unwrap());
+//!
+//! assert_eq!(
+//!     graph.render(),
+//!     r#"digraph "intervals" {
+//!     n0 [label="build"];
+//!     n1 [label="test"];
+//!     n2 [label="lint"];
+//!     n0 -> n1 [label="m"];
+//! }
+//! "#
+//! );
+//! ```
+//!
+//! [dot]: https://graphviz.org/doc/info/lang.html
+
+use std::fmt::{self, Display, Write as _};
+
+use crate::{Bounds, IntervalBounds, NonEmpty, Relation, RelationSet};
+
+/// A graph of labeled intervals and the relations between them, rendered in Graphviz's DOT language.
+#[derive(Clone, Debug)]
+pub struct DotGraph<T> {
+    nodes: Vec<(String, Bounds<T>)>,
+    name: String,
+    relations: RelationSet,
+    use_names: bool,
+}
+
+impl<T> Default for DotGraph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> DotGraph<T> {
+    /// Creates an empty graph named `intervals`, with edges for all relations,
+    /// labeled by their abbreviations.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![],
+            name: "intervals".to_owned(),
+            relations: RelationSet::all(),
+            use_names: false,
+        }
+    }
+
+    /// Sets the graph's name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Restricts edges to pairs of intervals standing in any of `relations`
+    /// (in either direction).
+    ///
+    /// Edges are directed such that their label is contained in `relations`,
+    /// preferring the non-inverted relation if both it and its converse are.
+    pub fn with_relations(mut self, relations: RelationSet) -> Self {
+        self.relations = relations;
+        self
+    }
+
+    /// Sets whether edges are labeled by the relations' full names (e.g. `is overlapped by`),
+    /// rather than by their abbreviations (e.g. `oi`).
+    pub fn with_names(mut self, use_names: bool) -> Self {
+        self.use_names = use_names;
+        self
+    }
+
+    /// Adds a labeled interval to the graph, returning its index.
+    pub fn push<I>(&mut self, label: impl Into<String>, interval: &NonEmpty<I>) -> usize
+    where
+        NonEmpty<I>: IntervalBounds<T>,
+    {
+        self.nodes.push((label.into(), interval.bounds()));
+        self.nodes.len() - 1
+    }
+
+    /// Returns the number of intervals in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` iff the graph contains no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl<T> DotGraph<T>
+where
+    T: Ord,
+{
+    /// Renders the graph as a DOT `digraph`.
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the graph's edges as `(source, target, relation)` triples of node indices,
+    /// each pair of intervals at most once.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, Relation)> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .flat_map(move |(s, (_, s_bounds))| {
+                self.nodes
+                    .iter()
+                    .enumerate()
+                    .skip(s + 1)
+                    .filter_map(move |(t, (_, t_bounds))| {
+                        let relation = Relation::from_bounds(s_bounds, t_bounds);
+                        self.orient(s, t, relation)
+                    })
+            })
+    }
+
+    /// Returns the edge between `s` and `t` (given `s`'s relation to `t`),
+    /// if any of their relations is to be drawn.
+    fn orient(&self, s: usize, t: usize, relation: Relation) -> Option<(usize, usize, Relation)> {
+        let converse = relation.as_converse();
+
+        match (
+            self.relations.contains(relation),
+            self.relations.contains(converse),
+        ) {
+            (true, true) if is_inverted(relation) => Some((t, s, converse)),
+            (true, _) => Some((s, t, relation)),
+            (false, true) => Some((t, s, converse)),
+            (false, false) => None,
+        }
+    }
+}
+
+fn is_inverted(relation: Relation) -> bool {
+    match relation {
+        Relation::Precedes { is_inverted }
+        | Relation::Meets { is_inverted }
+        | Relation::Overlaps { is_inverted }
+        | Relation::Finishes { is_inverted }
+        | Relation::Contains { is_inverted }
+        | Relation::Starts { is_inverted } => is_inverted,
+        Relation::Equals => false,
+    }
+}
+
+/// Writes `s` as a quoted DOT string.
+fn write_quoted(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl<T> Display for DotGraph<T>
+where
+    T: Ord,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("digraph ")?;
+        write_quoted(f, &self.name)?;
+        writeln!(f, " {{")?;
+
+        for (index, (label, _)) in self.nodes.iter().enumerate() {
+            write!(f, "    n{index} [label=")?;
+            write_quoted(f, label)?;
+            writeln!(f, "];")?;
+        }
+
+        for (s, t, relation) in self.edges() {
+            let label = match self.use_names {
+                true => relation.name(),
+                false => relation.abbreviation(),
+            };
+
+            write!(f, "    n{s} -> n{t} [label=")?;
+            write_quoted(f, label)?;
+            if relation == Relation::Equals {
+                f.write_str(", dir=none")?;
+            }
+            writeln!(f, "];")?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_helpers::{interval, set},
+        IntervalFrom, IntervalTo,
+    };
+
+    use super::*;
+
+    #[test]
+    fn collapses_converse_relations() {
+        let mut graph = DotGraph::new().with_name("schedule");
+        graph.push("a", &interval(3, 6));
+        graph.push("b", &interval(1, 4));
+        graph.push("c", &interval(3, 6));
+
+        assert_eq!(
+            graph.render(),
+            r#"digraph "schedule" {
+    n0 [label="a"];
+    n1 [label="b"];
+    n2 [label="c"];
+    n1 -> n0 [label="o"];
+    n0 -> n2 [label="eq", dir=none];
+    n1 -> n2 [label="o"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn filters_relations() {
+        let mut graph = DotGraph::new();
+        graph.push("a", &NonEmpty::from(IntervalTo { end: 2 }));
+        graph.push("b", &interval(2, 5));
+        graph.push("c", &NonEmpty::from(IntervalFrom { start: 7 }));
+        graph.push("d", &interval(3, 4));
+
+        let edges = |relations| {
+            graph
+                .clone()
+                .with_relations(set(relations))
+                .edges()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            edges("{p, m}"),
+            [
+                (0, 1, Relation::Meets { is_inverted: false }),
+                (0, 2, Relation::Precedes { is_inverted: false }),
+                (0, 3, Relation::Precedes { is_inverted: false }),
+                (1, 2, Relation::Precedes { is_inverted: false }),
+                (3, 2, Relation::Precedes { is_inverted: false }),
+            ]
+        );
+        assert_eq!(
+            edges("{pi}"),
+            [
+                (2, 0, Relation::Precedes { is_inverted: true }),
+                (3, 0, Relation::Precedes { is_inverted: true }),
+                (2, 1, Relation::Precedes { is_inverted: true }),
+                (2, 3, Relation::Precedes { is_inverted: true }),
+            ]
+        );
+        assert_eq!(
+            edges("{d}"),
+            [(3, 1, Relation::Contains { is_inverted: true })]
+        );
+    }
+
+    #[test]
+    fn labels_with_names() {
+        let mut graph = DotGraph::new().with_names(true);
+        graph.push("say \"hi\"", &interval(1, 2));
+        graph.push("b\\c", &interval(2, 3));
+
+        assert_eq!(
+            graph.render(),
+            r#"digraph "intervals" {
+    n0 [label="say \"hi\""];
+    n1 [label="b\\c"];
+    n0 -> n1 [label="meets"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let graph = DotGraph::<i32>::default();
+
+        assert!(graph.is_empty());
+        assert_eq!(graph.render(), "digraph \"intervals\" {\n}\n");
+    }
+}
@@ -0,0 +1,639 @@
+//! Fuzzy Allen relations between intervals of gradual boundaries.
+//!
+//! > Schockaert, S., & De Cock, M. (2008).
+//! > Temporal reasoning about fuzzy intervals.
+//! > Artificial Intelligence, 172(8-9), 1158-1193.
+//!
+//! A fuzzy interval is a trapezoid, which fully contains the values of its core,
+//! and gradually (i.e. linearly) less of the values towards the ends of its support.
+//! Each of Allen's relations holds between fuzzy intervals to a degree within `0.0..=1.0`,
+//! defined by comparing their beginnings and endings
+//! (using the Łukasiewicz connectives, and the crisp orderings of time points, i.e. `α = β = 0`),
+//! and agreeing exactly with [`Relation::from_intervals`](crate::FromIntervals::from_intervals) for crisp intervals.
+//!
+//! Degrees are computed in `f64`, to which endpoints are converted via [`ToPrimitive`]
+//! (as is infallible, if possibly lossy, for all primitive numbers, e.g. `i64` or `u128`),
+//! panicking for endpoints not representable as `f64`.
+//!
+//! ```
+//! use allen_intervals::{fuzzy::{FuzzyInterval, FuzzyRelation}, Relation};
+//!
+//! // The morning rush (from about 7 to 9), and a meeting (from 9 to 10):
+//! let rush = FuzzyInterval::new(6.5, 7.5, 8.5, 9.5).unwrap();
+//! let meeting = FuzzyInterval::new(9.0, 9.0, 10.0, 10.0).unwrap();
+//!
+//! let relation = FuzzyRelation::from_fuzzy_intervals(&rush, &meeting);
+//! assert_eq!(relation.degree(Relation::Precedes { is_inverted: false }), 0.5);
+//! assert_eq!(relation.degree(Relation::Overlaps { is_inverted: false }), 0.5);
+//! assert_eq!(relation.degree(Relation::Contains { is_inverted: true }), 0.0);
+//! ```
+
+use num_traits::ToPrimitive;
+
+use crate::{Interval, IntervalError, NonEmpty, Relation};
+
+/// A fuzzy interval, of a trapezoidal membership function.
+///
+/// Its membership rises linearly from `0.0` at the start of its support to `1.0` at the start of its core,
+/// and falls linearly from `1.0` at the end of its core to `0.0` at the end of its support
+/// (with the core, as well as any crisp boundary, being closed).
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct FuzzyInterval<T> {
+    support_start: T,
+    core_start: T,
+    core_end: T,
+    support_end: T,
+}
+
+impl<T> FuzzyInterval<T> {
+    /// Creates a fuzzy interval from the four points of its trapezoid,
+    /// or returns an error if they are not in ascending order,
+    /// or if its support is empty, or if they are not totally ordered.
+    pub fn new(
+        support_start: T,
+        core_start: T,
+        core_end: T,
+        support_end: T,
+    ) -> Result<Self, IntervalError>
+    where
+        T: PartialOrd,
+    {
+        let points = [&support_start, &core_start, &core_end, &support_end];
+
+        for pair in points.windows(2) {
+            if pair[0].partial_cmp(pair[1]).is_none() {
+                return Err(IntervalError::AmbiguousOrder);
+            }
+        }
+
+        if core_start < support_start || core_end < core_start || support_end < core_end {
+            return Err(IntervalError::EmptyInterval);
+        }
+
+        if support_start >= support_end {
+            return Err(IntervalError::EmptyInterval);
+        }
+
+        Ok(Self {
+            support_start,
+            core_start,
+            core_end,
+            support_end,
+        })
+    }
+
+    /// Returns the interval's support (i.e. the values it contains to any degree).
+    #[inline]
+    pub fn support(&self) -> Interval<T>
+    where
+        T: Copy,
+    {
+        Interval {
+            start: self.support_start,
+            end: self.support_end,
+        }
+    }
+
+    /// Returns the interval's core (i.e. the values it fully contains),
+    /// which is empty for a triangular interval.
+    #[inline]
+    pub fn core(&self) -> Interval<T>
+    where
+        T: Copy,
+    {
+        Interval {
+            start: self.core_start,
+            end: self.core_end,
+        }
+    }
+
+    /// Returns `true` iff the interval's boundaries are crisp (i.e. its support equals its core).
+    #[inline]
+    pub fn is_crisp(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.support_start == self.core_start && self.core_end == self.support_end
+    }
+
+    /// Returns the degree (within `0.0..=1.0`) to which `value` lies within the interval.
+    #[inline]
+    pub fn membership(&self, value: T) -> f64
+    where
+        T: Copy + PartialOrd + ToPrimitive,
+    {
+        self.membership_at(value, Side::At)
+    }
+
+    #[inline]
+    fn rising(&self, value: T) -> f64
+    where
+        T: Copy + ToPrimitive,
+    {
+        (to_f64(value) - to_f64(self.support_start))
+            / (to_f64(self.core_start) - to_f64(self.support_start))
+    }
+
+    #[inline]
+    fn falling(&self, value: T) -> f64
+    where
+        T: Copy + ToPrimitive,
+    {
+        (to_f64(self.support_end) - to_f64(value))
+            / (to_f64(self.support_end) - to_f64(self.core_end))
+    }
+
+    /// Returns the membership of `value` (or its limit from either side).
+    fn membership_at(&self, value: T, side: Side) -> f64
+    where
+        T: Copy + PartialOrd + ToPrimitive,
+    {
+        if side.ge(value, self.core_start) && side.le(value, self.core_end) {
+            1.0
+        } else if side.gt(value, self.support_start) && side.lt(value, self.core_start) {
+            self.rising(value)
+        } else if side.gt(value, self.core_end) && side.lt(value, self.support_end) {
+            self.falling(value)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the supremum of the membership of all values before `value`
+    /// (or only of those strictly before it, iff `is_strict`).
+    fn membership_before(&self, value: T, side: Side, is_strict: bool) -> f64
+    where
+        T: Copy + PartialOrd + ToPrimitive,
+    {
+        let is_before_support = match is_strict {
+            true => side.le(value, self.support_start),
+            false => side.lt(value, self.support_start),
+        };
+
+        if is_before_support {
+            0.0
+        } else if side.ge(value, self.core_start) && (!is_strict || side.gt(value, self.core_start))
+            || self.support_start == self.core_start
+        {
+            1.0
+        } else {
+            self.rising(value).min(1.0)
+        }
+    }
+
+    /// Returns the supremum of the membership of all values after `value`
+    /// (or only of those strictly after it, iff `is_strict`).
+    fn membership_after(&self, value: T, side: Side, is_strict: bool) -> f64
+    where
+        T: Copy + PartialOrd + ToPrimitive,
+    {
+        let is_after_support = match is_strict {
+            true => side.ge(value, self.support_end),
+            false => side.gt(value, self.support_end),
+        };
+
+        if is_after_support {
+            0.0
+        } else if side.le(value, self.core_end) && (!is_strict || side.lt(value, self.core_end))
+            || self.core_end == self.support_end
+        {
+            1.0
+        } else {
+            self.falling(value).min(1.0)
+        }
+    }
+}
+
+impl<T> From<NonEmpty<Interval<T>>> for FuzzyInterval<T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn from(interval: NonEmpty<Interval<T>>) -> Self {
+        let Interval { start, end } = interval.into_inner();
+
+        Self {
+            support_start: start,
+            core_start: start,
+            core_end: end,
+            support_end: end,
+        }
+    }
+}
+
+/// The side from which a piecewise linear function is evaluated at a point
+/// (i.e. its limit from the left, its value, or its limit from the right).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Side {
+    Left,
+    At,
+    Right,
+}
+
+impl Side {
+    const ALL: [Self; 3] = [Self::Left, Self::At, Self::Right];
+
+    /// Returns `true` iff the point (approached from the side) lies before `bound`.
+    #[inline]
+    fn lt<T: PartialOrd>(self, value: T, bound: T) -> bool {
+        match self {
+            Self::Left => value <= bound,
+            Self::At | Self::Right => value < bound,
+        }
+    }
+
+    /// Returns `true` iff the point (approached from the side) lies before or at `bound`.
+    #[inline]
+    fn le<T: PartialOrd>(self, value: T, bound: T) -> bool {
+        match self {
+            Self::Left | Self::At => value <= bound,
+            Self::Right => value < bound,
+        }
+    }
+
+    #[inline]
+    fn gt<T: PartialOrd>(self, value: T, bound: T) -> bool {
+        !self.le(value, bound)
+    }
+
+    #[inline]
+    fn ge<T: PartialOrd>(self, value: T, bound: T) -> bool {
+        !self.lt(value, bound)
+    }
+}
+
+/// Converts an endpoint to `f64`, which is infallible for primitive numbers.
+#[inline]
+fn to_f64<T>(value: T) -> f64
+where
+    T: ToPrimitive,
+{
+    value.to_f64().expect("endpoint representable as f64")
+}
+
+/// The Łukasiewicz t-norm.
+#[inline]
+fn t_norm(degrees: &[f64]) -> f64 {
+    degrees.iter().fold(1.0, |conjunction, degree| {
+        (conjunction + degree - 1.0).max(0.0)
+    })
+}
+
+/// The fuzzy Allen relations between two fuzzy intervals (e.g. `s` and `t`),
+/// i.e. the degree to which each of Allen's relations holds between them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FuzzyRelation {
+    degrees: [f64; 13],
+}
+
+impl FuzzyRelation {
+    /// Returns the relations between the fuzzy intervals `s` and `t`.
+    pub fn from_fuzzy_intervals<T>(s: &FuzzyInterval<T>, t: &FuzzyInterval<T>) -> Self
+    where
+        T: Copy + PartialOrd + ToPrimitive,
+    {
+        let st = Endpoints::new(s, t);
+        let ts = Endpoints::new(t, s);
+
+        let mut degrees = [0.0; 13];
+
+        for relation in Relation::ALL {
+            let (st, ts) = match relation {
+                Relation::Precedes { is_inverted: false }
+                | Relation::Meets { is_inverted: false }
+                | Relation::Overlaps { is_inverted: false }
+                | Relation::Finishes { is_inverted: false }
+                | Relation::Contains { is_inverted: true }
+                | Relation::Starts { is_inverted: false }
+                | Relation::Equals => (&st, &ts),
+                _ => (&ts, &st),
+            };
+
+            let degree = match relation {
+                Relation::Precedes { .. } => st.ends_before_beginning(true),
+                Relation::Meets { .. } => t_norm(&[
+                    st.ends_before_beginning(false),
+                    ts.begins_before_ending(false),
+                ]),
+                Relation::Overlaps { .. } => t_norm(&[
+                    st.begins_before_beginning(true),
+                    ts.begins_before_ending(true),
+                    st.ends_before_ending(true),
+                ]),
+                Relation::Contains { .. } => t_norm(&[
+                    ts.begins_before_beginning(true),
+                    st.ends_before_ending(true),
+                ]),
+                Relation::Starts { .. } => t_norm(&[
+                    st.begins_before_beginning(false),
+                    ts.begins_before_beginning(false),
+                    st.ends_before_ending(true),
+                ]),
+                Relation::Finishes { .. } => t_norm(&[
+                    ts.begins_before_beginning(true),
+                    st.ends_before_ending(false),
+                    ts.ends_before_ending(false),
+                ]),
+                Relation::Equals => t_norm(&[
+                    st.begins_before_beginning(false),
+                    ts.begins_before_beginning(false),
+                    st.ends_before_ending(false),
+                    ts.ends_before_ending(false),
+                ]),
+            };
+
+            degrees[relation.code() as usize] = degree;
+        }
+
+        Self { degrees }
+    }
+
+    /// Returns the degree (within `0.0..=1.0`) to which `relation` holds.
+    #[inline]
+    pub fn degree(&self, relation: Relation) -> f64 {
+        self.degrees[relation.code() as usize]
+    }
+
+    /// Returns the degree to which each relation holds, in ascending order of the relations.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Relation, f64)> + '_ {
+        Relation::ALL
+            .into_iter()
+            .map(|relation| (relation, self.degree(relation)))
+    }
+
+    /// Returns the relation holding to a degree of `1.0`, iff all others hold to a degree of `0.0`
+    /// (e.g. as between crisp intervals).
+    pub fn as_crisp(&self) -> Option<Relation> {
+        let mut crisp = None;
+
+        for (relation, degree) in self.iter() {
+            match degree {
+                1.0 if crisp.is_none() => crisp = Some(relation),
+                0.0 => {}
+                _ => return None,
+            }
+        }
+
+        crisp
+    }
+
+    /// Returns the relations' converse.
+    #[inline]
+    pub fn as_converse(&self) -> Self {
+        let mut degrees = [0.0; 13];
+
+        for (relation, degree) in self.iter() {
+            degrees[relation.as_converse().code() as usize] = degree;
+        }
+
+        Self { degrees }
+    }
+}
+
+/// The comparisons of the beginning and ending of `s` to those of `t`.
+struct Endpoints<'a, T> {
+    s: &'a FuzzyInterval<T>,
+    t: &'a FuzzyInterval<T>,
+    points: [T; 8],
+}
+
+impl<'a, T> Endpoints<'a, T>
+where
+    T: Copy + PartialOrd + ToPrimitive,
+{
+    fn new(s: &'a FuzzyInterval<T>, t: &'a FuzzyInterval<T>) -> Self {
+        let points = [
+            s.support_start,
+            s.core_start,
+            s.core_end,
+            s.support_end,
+            t.support_start,
+            t.core_start,
+            t.core_end,
+            t.support_end,
+        ];
+
+        Self { s, t, points }
+    }
+
+    /// Returns the infimum of the piecewise linear function `f`,
+    /// whose pieces are delimited by the intervals' points.
+    fn infimum<F>(&self, f: F) -> f64
+    where
+        F: Fn(T, Side) -> f64,
+    {
+        self.points
+            .iter()
+            .flat_map(|point| Side::ALL.map(|side| f(*point, side)))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns the supremum of the piecewise linear function `f`,
+    /// whose pieces are delimited by the intervals' points.
+    fn supremum<F>(&self, f: F) -> f64
+    where
+        F: Fn(T, Side) -> f64,
+    {
+        self.points
+            .iter()
+            .flat_map(|point| Side::ALL.map(|side| f(*point, side)))
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// Returns the degree to which `s` begins before (or, unless `is_strict`, with) `t`
+    /// (i.e. `bb≪` or `bb≼`).
+    fn begins_before_beginning(&self, is_strict: bool) -> f64 {
+        let (s, t) = (self.s, self.t);
+
+        // Each beginning of `t` is preceded by (or, unless strict, coincides with) a beginning of `s`:
+        self.infimum(|q, side| {
+            (1.0 - t.membership_at(q, side) + s.membership_before(q, side, is_strict)).min(1.0)
+        })
+    }
+
+    /// Returns the degree to which `s` ends before (or, unless `is_strict`, with) `t`
+    /// (i.e. `ee≪` or `ee≼`).
+    fn ends_before_ending(&self, is_strict: bool) -> f64 {
+        let (s, t) = (self.s, self.t);
+
+        // Each ending of `s` is succeeded by (or, unless strict, coincides with) an ending of `t`:
+        self.infimum(|p, side| {
+            (1.0 - s.membership_at(p, side) + t.membership_after(p, side, is_strict)).min(1.0)
+        })
+    }
+
+    /// Returns the degree to which `s` ends before (or, unless `is_strict`, as) `t` begins
+    /// (i.e. `eb≪` or `eb≼`).
+    fn ends_before_beginning(&self, is_strict: bool) -> f64 {
+        let (s, t) = (self.s, self.t);
+
+        // No ending of `s` is preceded by (or, if strict, coincides with) a beginning of `t`:
+        let supremum = self.supremum(|p, side| {
+            s.membership_at(p, side) + t.membership_before(p, side, !is_strict)
+        });
+
+        (2.0 - supremum).min(1.0)
+    }
+
+    /// Returns the degree to which `s` begins before (or, unless `is_strict`, as) `t` ends
+    /// (i.e. `be≪` or `be≼`).
+    fn begins_before_ending(&self, is_strict: bool) -> f64 {
+        let (s, t) = (self.s, self.t);
+
+        // Some beginning of `s` precedes (or, unless strict, coincides with) an ending of `t`:
+        let supremum = self
+            .supremum(|q, side| t.membership_at(q, side) + s.membership_before(q, side, is_strict));
+
+        (supremum - 1.0).max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_helpers::{interval, intervals},
+        FromIntervals,
+    };
+
+    #[test]
+    fn new() {
+        assert!(FuzzyInterval::new(1, 2, 2, 3).is_ok());
+        assert!(FuzzyInterval::new(1, 1, 3, 3).is_ok());
+        assert_eq!(
+            FuzzyInterval::new(1, 1, 1, 1),
+            Err(IntervalError::EmptyInterval)
+        );
+        assert_eq!(
+            FuzzyInterval::new(1, 3, 2, 4),
+            Err(IntervalError::EmptyInterval)
+        );
+        assert_eq!(
+            FuzzyInterval::new(0.0, f64::NAN, 1.0, 2.0),
+            Err(IntervalError::AmbiguousOrder)
+        );
+    }
+
+    #[test]
+    fn membership() {
+        let fuzzy = FuzzyInterval::new(0.0, 2.0, 4.0, 5.0).unwrap();
+
+        assert_eq!(fuzzy.membership(-1.0), 0.0);
+        assert_eq!(fuzzy.membership(0.0), 0.0);
+        assert_eq!(fuzzy.membership(1.0), 0.5);
+        assert_eq!(fuzzy.membership(3.0), 1.0);
+        assert_eq!(fuzzy.membership(4.5), 0.5);
+        assert_eq!(fuzzy.membership(5.0), 0.0);
+
+        let fuzzy = FuzzyInterval::from(interval(1, 3));
+
+        assert!(fuzzy.is_crisp());
+        assert_eq!(fuzzy.membership(1), 1.0);
+        assert_eq!(fuzzy.membership(3), 1.0);
+        assert_eq!(fuzzy.membership(4), 0.0);
+
+        let fuzzy = FuzzyInterval::new(i64::MIN, 0, 0, i64::MAX).unwrap();
+
+        assert_eq!(fuzzy.membership(i64::MIN / 2), 0.5);
+        assert_eq!(fuzzy.membership(0), 1.0);
+
+        let fuzzy = FuzzyInterval::new(0, 0, u64::MAX / 2, u64::MAX).unwrap();
+
+        assert_eq!(fuzzy.membership(u64::MAX), 0.0);
+        assert_eq!(
+            FuzzyRelation::from_fuzzy_intervals(&fuzzy, &fuzzy).degree(Relation::Equals),
+            1.0
+        );
+    }
+
+    #[test]
+    fn crisp_intervals() {
+        let intervals = intervals(6);
+
+        for s in &intervals {
+            for t in &intervals {
+                let relation = Relation::from_intervals(s, t);
+                let fuzzy = FuzzyRelation::from_fuzzy_intervals(&(*s).into(), &(*t).into());
+
+                for (other, degree) in fuzzy.iter() {
+                    let expected = if other == relation { 1.0 } else { 0.0 };
+
+                    assert_eq!(degree, expected, "{s:?} {t:?} {other}");
+                }
+
+                assert_eq!(fuzzy.as_crisp(), Some(relation));
+            }
+        }
+    }
+
+    #[test]
+    fn fuzzy_intervals() {
+        let intervals: Vec<_> = [
+            (0, 1, 2, 3),
+            (0, 2, 2, 4),
+            (1, 1, 3, 5),
+            (1, 2, 4, 4),
+            (2, 3, 4, 6),
+            (3, 3, 3, 6),
+            (4, 5, 6, 7),
+        ]
+        .into_iter()
+        .map(|(a, b, c, d)| FuzzyInterval::new(a, b, c, d).unwrap())
+        .collect();
+
+        for s in &intervals {
+            for t in &intervals {
+                let relation = FuzzyRelation::from_fuzzy_intervals(s, t);
+
+                for (_, degree) in relation.iter() {
+                    assert!((0.0..=1.0).contains(&degree), "{s:?} {t:?}");
+                }
+
+                assert_eq!(
+                    FuzzyRelation::from_fuzzy_intervals(t, s),
+                    relation.as_converse()
+                );
+            }
+
+            assert_eq!(
+                FuzzyRelation::from_fuzzy_intervals(s, s).degree(Relation::Equals),
+                1.0
+            );
+        }
+    }
+
+    #[test]
+    fn gradual_boundaries() {
+        let s = FuzzyInterval::new(0.0, 1.0, 3.0, 5.0).unwrap();
+        let t = FuzzyInterval::new(2.0, 4.0, 5.0, 6.0).unwrap();
+
+        let relation = FuzzyRelation::from_fuzzy_intervals(&s, &t);
+
+        // `s` ends about as `t` begins, and thus partially precedes or overlaps it:
+        assert_eq!(
+            relation.degree(Relation::Precedes { is_inverted: false }),
+            0.5
+        );
+        assert_eq!(
+            relation.degree(Relation::Overlaps { is_inverted: false }),
+            0.5
+        );
+        assert_eq!(
+            relation.degree(Relation::Contains { is_inverted: true }),
+            0.0
+        );
+        assert_eq!(relation.as_crisp(), None);
+
+        // An interval whose falling boundary is complementary to the rising boundary of another
+        // fully precedes it:
+        let s = FuzzyInterval::new(0.0, 1.0, 2.0, 4.0).unwrap();
+
+        let relation = FuzzyRelation::from_fuzzy_intervals(&s, &t);
+
+        assert_eq!(
+            relation.degree(Relation::Precedes { is_inverted: false }),
+            1.0
+        );
+    }
+}
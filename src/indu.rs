@@ -0,0 +1,320 @@
+//! The INDU calculus, refining Allen's relations by the intervals' relative durations.
+//!
+//! > Pujari, A. K., Kumari, G. V., & Sattar, A. (1999).
+//! > INDU: An interval & duration network.
+//! > In Advanced Topics in Artificial Intelligence (pp. 291-303). Springer.
+//!
+//! Each of Allen's thirteen relations is annotated with whether `s` is shorter than (`<`),
+//! equal in length to (`=`), or longer than (`>`) `t`.
+//! Seven of the relations already determine the intervals' relative durations
+//! (e.g. an interval `d`uring another one is necessarily shorter),
+//! leaving 25 consistent relations.
+//!
+//! ```
+//! use core::cmp::Ordering;
+//!
+//! use allen_intervals::{indu::InduRelation, FromIntervals, Interval, NonEmpty, Relation};
+//!
+//! let standup: NonEmpty<_> = Interval { start: 9, end: 10 }.try_into().unwrap();
+//! let workshop: NonEmpty<_> = Interval { start: 9, end: 12 }.try_into().unwrap();
+//! let review: NonEmpty<_> = Interval { start: 11, end: 14 }.try_into().unwrap();
+//!
+//! let relation = InduRelation::from_intervals(&workshop, &review);
+//! assert_eq!(relation.relation(), Relation::Overlaps { is_inverted: false });
+//! assert_eq!(relation.duration(), Ordering::Equal);
+//! assert_eq!(relation.to_string(), "o=");
+//!
+//! let relation = InduRelation::from_intervals(&standup, &review);
+//! assert_eq!(relation.to_string(), "p<");
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::Sub,
+};
+
+use crate::{
+    FromIntervals, Interval, IntervalBounds, IntervalError, NonEmpty, Relation, TryFromIntervals,
+};
+
+/// An INDU relation between two intervals (e.g. `s` and `t`),
+/// i.e. their Allen relation and the ordering of their durations.
+///
+/// The relations are comparable (via `Ord`) by their Allen relation
+/// and then within that by their ordering of durations.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct InduRelation {
+    relation: Relation,
+    duration: Ordering,
+}
+
+impl InduRelation {
+    /// All 25 consistent relations, in ascending order.
+    pub const ALL: [Self; 25] = {
+        use Ordering::*;
+
+        const fn indu(relation: Relation, duration: Ordering) -> InduRelation {
+            InduRelation { relation, duration }
+        }
+
+        [
+            indu(Relation::Precedes { is_inverted: false }, Less),
+            indu(Relation::Precedes { is_inverted: false }, Equal),
+            indu(Relation::Precedes { is_inverted: false }, Greater),
+            indu(Relation::Meets { is_inverted: false }, Less),
+            indu(Relation::Meets { is_inverted: false }, Equal),
+            indu(Relation::Meets { is_inverted: false }, Greater),
+            indu(Relation::Overlaps { is_inverted: false }, Less),
+            indu(Relation::Overlaps { is_inverted: false }, Equal),
+            indu(Relation::Overlaps { is_inverted: false }, Greater),
+            indu(Relation::Finishes { is_inverted: true }, Greater),
+            indu(Relation::Contains { is_inverted: false }, Greater),
+            indu(Relation::Starts { is_inverted: false }, Less),
+            indu(Relation::Equals, Equal),
+            indu(Relation::Starts { is_inverted: true }, Greater),
+            indu(Relation::Contains { is_inverted: true }, Less),
+            indu(Relation::Finishes { is_inverted: false }, Less),
+            indu(Relation::Overlaps { is_inverted: true }, Less),
+            indu(Relation::Overlaps { is_inverted: true }, Equal),
+            indu(Relation::Overlaps { is_inverted: true }, Greater),
+            indu(Relation::Meets { is_inverted: true }, Less),
+            indu(Relation::Meets { is_inverted: true }, Equal),
+            indu(Relation::Meets { is_inverted: true }, Greater),
+            indu(Relation::Precedes { is_inverted: true }, Less),
+            indu(Relation::Precedes { is_inverted: true }, Equal),
+            indu(Relation::Precedes { is_inverted: true }, Greater),
+        ]
+    };
+
+    /// Creates the relation of `relation` and the ordering of the durations of `s` and `t`,
+    /// or `None` if they are inconsistent (e.g. `s` being longer than `t`, while `s` is during `t`).
+    #[inline]
+    pub fn new(relation: Relation, duration: Ordering) -> Option<Self> {
+        match Self::implied_duration(relation) {
+            Some(implied) if implied != duration => None,
+            _ => Some(Self { relation, duration }),
+        }
+    }
+
+    /// Returns the Allen relation.
+    #[inline]
+    pub fn relation(&self) -> Relation {
+        self.relation
+    }
+
+    /// Returns the ordering of the duration of `s` to the duration of `t`.
+    #[inline]
+    pub fn duration(&self) -> Ordering {
+        self.duration
+    }
+
+    /// Returns the relation's converse.
+    #[inline]
+    pub fn as_converse(&self) -> Self {
+        Self {
+            relation: self.relation.as_converse(),
+            duration: self.duration.reverse(),
+        }
+    }
+
+    /// Returns the ordering of durations implied by `relation`, if any.
+    #[inline]
+    fn implied_duration(relation: Relation) -> Option<Ordering> {
+        match relation {
+            Relation::Precedes { .. } | Relation::Meets { .. } | Relation::Overlaps { .. } => None,
+            Relation::Finishes { is_inverted: false }
+            | Relation::Contains { is_inverted: true }
+            | Relation::Starts { is_inverted: false } => Some(Ordering::Less),
+            Relation::Finishes { is_inverted: true }
+            | Relation::Contains { is_inverted: false }
+            | Relation::Starts { is_inverted: true } => Some(Ordering::Greater),
+            Relation::Equals => Some(Ordering::Equal),
+        }
+    }
+
+    #[inline]
+    fn from_relation<T, D>(relation: Relation, s: &Interval<T>, t: &Interval<T>) -> Self
+    where
+        T: Copy + Sub<Output = D>,
+        D: Ord,
+    {
+        let duration = Self::implied_duration(relation)
+            .unwrap_or_else(|| s.length::<D>().cmp(&t.length::<D>()));
+
+        Self { relation, duration }
+    }
+
+    #[inline]
+    fn try_from_relation<T, D>(
+        relation: Relation,
+        s: &Interval<T>,
+        t: &Interval<T>,
+    ) -> Result<Self, IntervalError>
+    where
+        T: Copy + Sub<Output = D>,
+        D: PartialOrd,
+    {
+        let duration = match Self::implied_duration(relation) {
+            Some(duration) => duration,
+            None => s
+                .length::<D>()
+                .partial_cmp(&t.length::<D>())
+                .ok_or(IntervalError::AmbiguousOrder)?,
+        };
+
+        Ok(Self { relation, duration })
+    }
+}
+
+impl<T, D> FromIntervals<Interval<T>, Interval<T>> for InduRelation
+where
+    T: Ord + Copy + Sub<Output = D>,
+    D: Ord,
+{
+    #[inline]
+    fn from_intervals(s: &NonEmpty<Interval<T>>, t: &NonEmpty<Interval<T>>) -> Self {
+        let relation = Relation::from_bounds(&s.bounds(), &t.bounds());
+
+        Self::from_relation(relation, &s.0, &t.0)
+    }
+}
+
+impl<T, D> TryFromIntervals<Interval<T>, Interval<T>> for InduRelation
+where
+    T: PartialOrd + Copy + Sub<Output = D>,
+    D: PartialOrd,
+{
+    #[inline]
+    fn try_from_intervals(
+        s: &NonEmpty<Interval<T>>,
+        t: &NonEmpty<Interval<T>>,
+    ) -> Result<Self, IntervalError> {
+        let relation = Relation::try_from_bounds(&s.bounds(), &t.bounds())?;
+
+        Self::try_from_relation(relation, &s.0, &t.0)
+    }
+}
+
+/// Formats as the Allen relation's abbreviation followed by `<`, `=` or `>` (e.g. `o<`),
+/// or as its full name followed by the ordering of durations (e.g. `overlaps (shorter)`)
+/// for the alternate (`{:#}`) format.
+impl Display for InduRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (f.alternate(), self.duration) {
+            (false, Ordering::Less) => write!(f, "{}<", self.relation),
+            (false, Ordering::Equal) => write!(f, "{}=", self.relation),
+            (false, Ordering::Greater) => write!(f, "{}>", self.relation),
+            (true, Ordering::Less) => write!(f, "{:#} (shorter)", self.relation),
+            (true, Ordering::Equal) => write!(f, "{:#} (equal length)", self.relation),
+            (true, Ordering::Greater) => write!(f, "{:#} (longer)", self.relation),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::interval;
+
+    #[test]
+    fn all() {
+        let mut relations = InduRelation::ALL;
+        relations.sort();
+        assert_eq!(relations, InduRelation::ALL);
+
+        let consistent: Vec<_> = Relation::ALL
+            .into_iter()
+            .flat_map(|relation| {
+                [Ordering::Less, Ordering::Equal, Ordering::Greater]
+                    .into_iter()
+                    .filter_map(move |duration| InduRelation::new(relation, duration))
+            })
+            .collect();
+        assert_eq!(consistent, InduRelation::ALL);
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            InduRelation::new(Relation::Contains { is_inverted: true }, Ordering::Greater),
+            None
+        );
+        assert_eq!(InduRelation::new(Relation::Equals, Ordering::Less), None);
+        assert!(InduRelation::new(Relation::Meets { is_inverted: true }, Ordering::Less).is_some());
+    }
+
+    #[test]
+    fn from_intervals() {
+        // Every consistent relation is realized by some pair of intervals:
+        let intervals: Vec<_> = (0..6)
+            .flat_map(|start| (start + 1..7).map(move |end| interval(start, end)))
+            .collect();
+
+        let mut realized: Vec<_> = intervals
+            .iter()
+            .flat_map(|s| {
+                intervals
+                    .iter()
+                    .map(move |t| InduRelation::from_intervals(s, t))
+            })
+            .collect();
+        realized.sort();
+        realized.dedup();
+
+        assert_eq!(realized, InduRelation::ALL);
+
+        for s in &intervals {
+            for t in &intervals {
+                let relation = InduRelation::from_intervals(s, t);
+
+                assert_eq!(relation.relation(), Relation::from_intervals(s, t));
+                assert_eq!(relation.duration(), s.0.length::<i32>().cmp(&t.0.length()));
+                assert_eq!(InduRelation::from_intervals(t, s), relation.as_converse());
+                assert_eq!(InduRelation::try_from_intervals(s, t), Ok(relation));
+            }
+        }
+    }
+
+    #[test]
+    fn try_from_intervals() {
+        let s: NonEmpty<_> = Interval {
+            start: 0.0,
+            end: 1.0,
+        }
+        .try_into()
+        .unwrap();
+        let t: NonEmpty<_> = Interval {
+            start: 2.0,
+            end: f64::INFINITY,
+        }
+        .try_into()
+        .unwrap();
+        let u: NonEmpty<_> = Interval {
+            start: 3.0,
+            end: 3.5,
+        }
+        .try_into()
+        .unwrap();
+
+        assert_eq!(
+            InduRelation::try_from_intervals(&s, &u).map(|relation| relation.to_string()),
+            Ok("p>".to_owned())
+        );
+        assert_eq!(
+            InduRelation::try_from_intervals(&s, &t).map(|relation| relation.duration()),
+            Ok(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let relation = InduRelation::new(Relation::Overlaps { is_inverted: true }, Ordering::Less);
+
+        assert_eq!(relation.unwrap().to_string(), "oi<");
+        assert_eq!(
+            format!("{:#}", relation.unwrap()),
+            "is overlapped by (shorter)"
+        );
+    }
+}
@@ -3,17 +3,28 @@ mod interval;
 mod interval_from;
 mod interval_full;
 mod interval_to;
+mod notation;
 
-pub use self::{interval::*, interval_from::*, interval_full::*, interval_to::*};
+pub use self::{interval::*, interval_from::*, interval_full::*, interval_to::*, notation::*};
 
 /// Error type specific to Allen's interval algebra.
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[non_exhaustive]
 pub enum IntervalError {
     /// Empty intervals are invalid with respect to Allen's interval algebra.
-    #[error("empty interval, which is not supported by Allen's interval algebra")]
+    #[cfg_attr(
+        feature = "std",
+        error("empty interval, which is not supported by Allen's interval algebra")
+    )]
     EmptyInterval,
     /// Could not obtain total order.
-    #[error("could not obtain total order")]
+    #[cfg_attr(feature = "std", error("could not obtain total order"))]
     AmbiguousOrder,
+    /// The bounds do not match the shape of the interval type.
+    #[cfg_attr(
+        feature = "std",
+        error("bounds do not match the shape of the interval type")
+    )]
+    MismatchedBounds,
 }
@@ -1,4 +1,8 @@
-use core::ops::{Range, RangeInclusive};
+use core::ops::{Add, Mul, Range, RangeInclusive, Sub};
+
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, Num, SaturatingAdd, SaturatingMul, SaturatingSub,
+};
 
 use crate::{
     Contains, Equals, Finishes, IntervalFrom, IntervalFull, IntervalTo, Meets, NonEmpty, Overlaps,
@@ -24,6 +28,283 @@ pub struct Interval<T> {
     pub end: T,
 }
 
+impl<T> Interval<T> {
+    /// Returns the length of the interval (i.e. `end - start`).
+    #[inline]
+    pub fn length<D>(&self) -> D
+    where
+        T: Copy + Sub<Output = D>,
+    {
+        self.end - self.start
+    }
+
+    /// Returns the length of the interval,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_length(&self) -> Option<T>
+    where
+        T: CheckedSub,
+    {
+        self.end.checked_sub(&self.start)
+    }
+
+    /// Returns the length of the interval,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_length(&self) -> T
+    where
+        T: SaturatingSub,
+    {
+        self.end.saturating_sub(&self.start)
+    }
+
+    /// Returns the point halfway between the interval's start and end.
+    ///
+    /// The midpoint is computed as `start + (end - start) / 2`,
+    /// which overflows if the interval's length is not representable
+    /// (e.g. for `i8::MIN..i8::MAX`; see [`Self::checked_midpoint`]).
+    #[inline]
+    pub fn midpoint<D>(&self) -> T
+    where
+        T: Copy + Add<D, Output = T> + Sub<Output = D>,
+        D: Num,
+    {
+        self.start + (self.end - self.start) / (D::one() + D::one())
+    }
+
+    /// Returns the point halfway between the interval's start and end,
+    /// or `None` if the computation overflowed.
+    ///
+    /// The midpoint is computed by halving each endpoint
+    /// (as `start / 2 + end / 2`, corrected by their remainders),
+    /// which never overflows for primitive integers, even if the interval's length does,
+    /// and rounds down (as does [`Self::midpoint`]).
+    #[inline]
+    pub fn checked_midpoint(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd + CheckedAdd + Num,
+    {
+        let two = T::one() + T::one();
+        let Self { start, end } = *self;
+
+        let halves = (start / two).checked_add(&(end / two))?;
+
+        // The sum of the remainders lies within `-2..=2`, and is halved rounding down:
+        let remainders = (start % two).checked_add(&(end % two))?;
+        let correction = match remainders < T::zero() {
+            true => (remainders - T::one()) / two,
+            false => remainders / two,
+        };
+
+        halves.checked_add(&correction)
+    }
+
+    /// Returns the interval shifted by `offset`.
+    #[inline]
+    pub fn shift<D>(&self, offset: D) -> Self
+    where
+        T: Copy + Add<D, Output = T>,
+        D: Copy,
+    {
+        Self {
+            start: self.start + offset,
+            end: self.end + offset,
+        }
+    }
+
+    /// Returns the interval shifted by `offset`,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_shift(&self, offset: T) -> Option<Self>
+    where
+        T: CheckedAdd,
+    {
+        Some(Self {
+            start: self.start.checked_add(&offset)?,
+            end: self.end.checked_add(&offset)?,
+        })
+    }
+
+    /// Returns the interval shifted by `offset`,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_shift(&self, offset: T) -> Self
+    where
+        T: SaturatingAdd,
+    {
+        Self {
+            start: self.start.saturating_add(&offset),
+            end: self.end.saturating_add(&offset),
+        }
+    }
+
+    /// Returns the interval scaled by `factor` around `anchor`.
+    ///
+    /// Each endpoint `x` is mapped to `anchor + (x - anchor) * factor`.
+    #[inline]
+    pub fn scale<D, F>(&self, anchor: T, factor: F) -> Self
+    where
+        T: Copy + PartialOrd + Add<D, Output = T> + Sub<D, Output = T> + Sub<Output = D>,
+        D: Mul<F, Output = D>,
+        F: Copy,
+    {
+        Self {
+            start: scale_point(self.start, anchor, factor),
+            end: scale_point(self.end, anchor, factor),
+        }
+    }
+
+    /// Returns the interval scaled by `factor` around `anchor`,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_scale(&self, anchor: T, factor: T) -> Option<Self>
+    where
+        T: PartialOrd + CheckedAdd + CheckedSub + CheckedMul,
+    {
+        Some(Self {
+            start: checked_scale_point(&self.start, &anchor, &factor)?,
+            end: checked_scale_point(&self.end, &anchor, &factor)?,
+        })
+    }
+
+    /// Returns the interval scaled by `factor` around `anchor`,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_scale(&self, anchor: T, factor: T) -> Self
+    where
+        T: PartialOrd + SaturatingAdd + SaturatingSub + SaturatingMul,
+    {
+        Self {
+            start: saturating_scale_point(&self.start, &anchor, &factor),
+            end: saturating_scale_point(&self.end, &anchor, &factor),
+        }
+    }
+
+    /// Returns the interval expanded by `margin` on both ends.
+    #[inline]
+    pub fn expand<D>(&self, margin: D) -> Self
+    where
+        T: Copy + Add<D, Output = T> + Sub<D, Output = T>,
+        D: Copy,
+    {
+        Self {
+            start: self.start - margin,
+            end: self.end + margin,
+        }
+    }
+
+    /// Returns the interval expanded by `margin` on both ends,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_expand(&self, margin: T) -> Option<Self>
+    where
+        T: CheckedAdd + CheckedSub,
+    {
+        Some(Self {
+            start: self.start.checked_sub(&margin)?,
+            end: self.end.checked_add(&margin)?,
+        })
+    }
+
+    /// Returns the interval expanded by `margin` on both ends,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_expand(&self, margin: T) -> Self
+    where
+        T: SaturatingAdd + SaturatingSub,
+    {
+        Self {
+            start: self.start.saturating_sub(&margin),
+            end: self.end.saturating_add(&margin),
+        }
+    }
+
+    /// Returns the interval shrunk by `margin` on both ends.
+    ///
+    /// The resulting interval may be empty.
+    #[inline]
+    pub fn shrink<D>(&self, margin: D) -> Self
+    where
+        T: Copy + Add<D, Output = T> + Sub<D, Output = T>,
+        D: Copy,
+    {
+        Self {
+            start: self.start + margin,
+            end: self.end - margin,
+        }
+    }
+
+    /// Returns the interval shrunk by `margin` on both ends,
+    /// or `None` if the computation overflowed.
+    ///
+    /// The resulting interval may be empty.
+    #[inline]
+    pub fn checked_shrink(&self, margin: T) -> Option<Self>
+    where
+        T: CheckedAdd + CheckedSub,
+    {
+        Some(Self {
+            start: self.start.checked_add(&margin)?,
+            end: self.end.checked_sub(&margin)?,
+        })
+    }
+
+    /// Returns the interval shrunk by `margin` on both ends,
+    /// saturating at the numeric bounds instead of overflowing.
+    ///
+    /// The resulting interval may be empty.
+    #[inline]
+    pub fn saturating_shrink(&self, margin: T) -> Self
+    where
+        T: SaturatingAdd + SaturatingSub,
+    {
+        Self {
+            start: self.start.saturating_add(&margin),
+            end: self.end.saturating_sub(&margin),
+        }
+    }
+}
+
+// Scaling is performed on the absolute distance to the anchor,
+// which keeps it well-defined for unsigned types and point/duration pairs.
+
+#[inline]
+fn scale_point<T, D, F>(point: T, anchor: T, factor: F) -> T
+where
+    T: Copy + PartialOrd + Add<D, Output = T> + Sub<D, Output = T> + Sub<Output = D>,
+    D: Mul<F, Output = D>,
+{
+    if point >= anchor {
+        anchor + (point - anchor) * factor
+    } else {
+        anchor - (anchor - point) * factor
+    }
+}
+
+#[inline]
+fn checked_scale_point<T>(point: &T, anchor: &T, factor: &T) -> Option<T>
+where
+    T: PartialOrd + CheckedAdd + CheckedSub + CheckedMul,
+{
+    if point >= anchor {
+        anchor.checked_add(&point.checked_sub(anchor)?.checked_mul(factor)?)
+    } else {
+        anchor.checked_sub(&anchor.checked_sub(point)?.checked_mul(factor)?)
+    }
+}
+
+#[inline]
+fn saturating_scale_point<T>(point: &T, anchor: &T, factor: &T) -> T
+where
+    T: PartialOrd + SaturatingAdd + SaturatingSub + SaturatingMul,
+{
+    if point >= anchor {
+        anchor.saturating_add(&point.saturating_sub(anchor).saturating_mul(factor))
+    } else {
+        anchor.saturating_sub(&anchor.saturating_sub(point).saturating_mul(factor))
+    }
+}
+
 impl<T> From<Range<T>> for Interval<T> {
     fn from(value: Range<T>) -> Self {
         let Range { start, end } = value;
@@ -232,3 +513,181 @@ where
         (self.0.start == other.0.start) && (self.0.end == other.0.end)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length() {
+        let interval = Interval { start: 2, end: 7 };
+
+        assert_eq!(interval.length(), 5);
+        assert_eq!(interval.checked_length(), Some(5));
+        assert_eq!(interval.saturating_length(), 5);
+
+        let interval = Interval {
+            start: i8::MIN,
+            end: i8::MAX,
+        };
+
+        assert_eq!(interval.checked_length(), None);
+        assert_eq!(interval.saturating_length(), i8::MAX);
+
+        let interval = Interval {
+            start: 1.5_f64,
+            end: 4.0,
+        };
+
+        assert_eq!(interval.length(), 2.5);
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(Interval { start: 2, end: 8 }.midpoint(), 5);
+        assert_eq!(
+            Interval {
+                start: 2.0,
+                end: 3.0
+            }
+            .midpoint(),
+            2.5
+        );
+
+        let interval = Interval {
+            start: u8::MAX - 2,
+            end: u8::MAX,
+        };
+
+        assert_eq!(interval.checked_midpoint(), Some(u8::MAX - 1));
+
+        let interval = Interval {
+            start: i8::MIN,
+            end: i8::MAX,
+        };
+
+        assert_eq!(interval.checked_midpoint(), Some(-1));
+
+        let interval = Interval {
+            start: i32::MAX - 3,
+            end: i32::MAX,
+        };
+
+        assert_eq!(interval.checked_midpoint(), Some(i32::MAX - 2));
+        assert_eq!(Interval { start: -3, end: 4 }.checked_midpoint(), Some(0));
+
+        for start in i8::MIN..i8::MAX {
+            for end in start + 1..=i8::MAX {
+                let midpoint = (i16::from(start) + i16::from(end)).div_euclid(2);
+                let interval = Interval { start, end };
+
+                assert_eq!(interval.checked_midpoint().map(i16::from), Some(midpoint));
+            }
+        }
+    }
+
+    #[test]
+    fn shift() {
+        let interval = Interval { start: 2, end: 7 };
+
+        assert_eq!(interval.shift(3), Interval { start: 5, end: 10 });
+        assert_eq!(interval.shift(-3), Interval { start: -1, end: 4 });
+
+        let interval = Interval {
+            start: 250_u8,
+            end: 254,
+        };
+
+        assert_eq!(
+            interval.checked_shift(1),
+            Some(Interval {
+                start: 251,
+                end: 255
+            })
+        );
+        assert_eq!(interval.checked_shift(2), None);
+        assert_eq!(
+            interval.saturating_shift(3),
+            Interval {
+                start: 253,
+                end: 255
+            }
+        );
+    }
+
+    #[test]
+    fn shift_instant() {
+        use std::time::{Duration, Instant};
+
+        let now = Instant::now();
+        let interval = Interval {
+            start: now,
+            end: now + Duration::from_secs(5),
+        };
+
+        let shifted = interval.shift(Duration::from_secs(2));
+
+        assert_eq!(shifted.start, now + Duration::from_secs(2));
+        assert_eq!(shifted.length(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn scale() {
+        let interval = Interval { start: 2, end: 6 };
+
+        assert_eq!(interval.scale(0, 2), Interval { start: 4, end: 12 });
+        assert_eq!(interval.scale(4, 2), Interval { start: 0, end: 8 });
+        assert_eq!(interval.scale(4, -1), Interval { start: 6, end: 2 });
+
+        let interval = Interval {
+            start: 2_u8,
+            end: 6,
+        };
+
+        assert_eq!(interval.scale(4, 2), Interval { start: 0, end: 8 });
+        assert_eq!(
+            interval.checked_scale(4, 2),
+            Some(Interval { start: 0, end: 8 })
+        );
+        assert_eq!(interval.checked_scale(4, 3), None);
+        assert_eq!(
+            interval.saturating_scale(4, 3),
+            Interval { start: 0, end: 10 }
+        );
+    }
+
+    #[test]
+    fn expand_and_shrink() {
+        let interval = Interval { start: 2, end: 8 };
+
+        assert_eq!(interval.expand(1), Interval { start: 1, end: 9 });
+        assert_eq!(interval.shrink(1), Interval { start: 3, end: 7 });
+        assert_eq!(interval.shrink(4), Interval { start: 6, end: 4 });
+
+        let interval = Interval {
+            start: 2_u8,
+            end: 254,
+        };
+
+        assert_eq!(interval.checked_expand(2), None);
+        assert_eq!(
+            interval.checked_expand(1),
+            Some(Interval { start: 1, end: 255 })
+        );
+        assert_eq!(
+            interval.saturating_expand(3),
+            Interval { start: 0, end: 255 }
+        );
+        assert_eq!(
+            interval.checked_shrink(200),
+            Some(Interval {
+                start: 202,
+                end: 54
+            })
+        );
+        assert_eq!(
+            interval.saturating_shrink(255),
+            Interval { start: 255, end: 0 }
+        );
+    }
+}
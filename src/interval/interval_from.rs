@@ -1,4 +1,6 @@
-use core::ops::RangeFrom;
+use core::ops::{Add, RangeFrom, Sub};
+
+use num_traits::{CheckedAdd, CheckedSub, SaturatingAdd, SaturatingSub};
 
 use crate::{
     Contains, Equals, Finishes, Interval, IntervalFull, IntervalTo, Meets, NonEmpty, Overlaps,
@@ -14,6 +16,113 @@ pub struct IntervalFrom<T> {
     pub start: T,
 }
 
+impl<T> IntervalFrom<T> {
+    /// Returns the interval shifted by `offset`.
+    #[inline]
+    pub fn shift<D>(&self, offset: D) -> Self
+    where
+        T: Copy + Add<D, Output = T>,
+    {
+        Self {
+            start: self.start + offset,
+        }
+    }
+
+    /// Returns the interval shifted by `offset`,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_shift(&self, offset: T) -> Option<Self>
+    where
+        T: CheckedAdd,
+    {
+        Some(Self {
+            start: self.start.checked_add(&offset)?,
+        })
+    }
+
+    /// Returns the interval shifted by `offset`,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_shift(&self, offset: T) -> Self
+    where
+        T: SaturatingAdd,
+    {
+        Self {
+            start: self.start.saturating_add(&offset),
+        }
+    }
+
+    /// Returns the interval expanded by `margin` on its bounded end.
+    #[inline]
+    pub fn expand<D>(&self, margin: D) -> Self
+    where
+        T: Copy + Add<D, Output = T> + Sub<D, Output = T>,
+    {
+        Self {
+            start: self.start - margin,
+        }
+    }
+
+    /// Returns the interval expanded by `margin` on its bounded end,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_expand(&self, margin: T) -> Option<Self>
+    where
+        T: CheckedAdd + CheckedSub,
+    {
+        Some(Self {
+            start: self.start.checked_sub(&margin)?,
+        })
+    }
+
+    /// Returns the interval expanded by `margin` on its bounded end,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_expand(&self, margin: T) -> Self
+    where
+        T: SaturatingAdd + SaturatingSub,
+    {
+        Self {
+            start: self.start.saturating_sub(&margin),
+        }
+    }
+
+    /// Returns the interval shrunk by `margin` on its bounded end.
+    #[inline]
+    pub fn shrink<D>(&self, margin: D) -> Self
+    where
+        T: Copy + Add<D, Output = T> + Sub<D, Output = T>,
+    {
+        Self {
+            start: self.start + margin,
+        }
+    }
+
+    /// Returns the interval shrunk by `margin` on its bounded end,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_shrink(&self, margin: T) -> Option<Self>
+    where
+        T: CheckedAdd + CheckedSub,
+    {
+        Some(Self {
+            start: self.start.checked_add(&margin)?,
+        })
+    }
+
+    /// Returns the interval shrunk by `margin` on its bounded end,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_shrink(&self, margin: T) -> Self
+    where
+        T: SaturatingAdd + SaturatingSub,
+    {
+        Self {
+            start: self.start.saturating_add(&margin),
+        }
+    }
+}
+
 impl<T> From<RangeFrom<T>> for IntervalFrom<T> {
     fn from(value: RangeFrom<T>) -> Self {
         let RangeFrom { start } = value;
@@ -1,4 +1,6 @@
-use core::ops::{RangeTo, RangeToInclusive};
+use core::ops::{Add, RangeTo, RangeToInclusive, Sub};
+
+use num_traits::{CheckedAdd, CheckedSub, SaturatingAdd, SaturatingSub};
 
 use crate::{
     Contains, Equals, Finishes, Interval, IntervalFrom, IntervalFull, Meets, NonEmpty, Overlaps,
@@ -15,6 +17,113 @@ pub struct IntervalTo<T> {
     pub end: T,
 }
 
+impl<T> IntervalTo<T> {
+    /// Returns the interval shifted by `offset`.
+    #[inline]
+    pub fn shift<D>(&self, offset: D) -> Self
+    where
+        T: Copy + Add<D, Output = T>,
+    {
+        Self {
+            end: self.end + offset,
+        }
+    }
+
+    /// Returns the interval shifted by `offset`,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_shift(&self, offset: T) -> Option<Self>
+    where
+        T: CheckedAdd,
+    {
+        Some(Self {
+            end: self.end.checked_add(&offset)?,
+        })
+    }
+
+    /// Returns the interval shifted by `offset`,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_shift(&self, offset: T) -> Self
+    where
+        T: SaturatingAdd,
+    {
+        Self {
+            end: self.end.saturating_add(&offset),
+        }
+    }
+
+    /// Returns the interval expanded by `margin` on its bounded end.
+    #[inline]
+    pub fn expand<D>(&self, margin: D) -> Self
+    where
+        T: Copy + Add<D, Output = T> + Sub<D, Output = T>,
+    {
+        Self {
+            end: self.end + margin,
+        }
+    }
+
+    /// Returns the interval expanded by `margin` on its bounded end,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_expand(&self, margin: T) -> Option<Self>
+    where
+        T: CheckedAdd + CheckedSub,
+    {
+        Some(Self {
+            end: self.end.checked_add(&margin)?,
+        })
+    }
+
+    /// Returns the interval expanded by `margin` on its bounded end,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_expand(&self, margin: T) -> Self
+    where
+        T: SaturatingAdd + SaturatingSub,
+    {
+        Self {
+            end: self.end.saturating_add(&margin),
+        }
+    }
+
+    /// Returns the interval shrunk by `margin` on its bounded end.
+    #[inline]
+    pub fn shrink<D>(&self, margin: D) -> Self
+    where
+        T: Copy + Add<D, Output = T> + Sub<D, Output = T>,
+    {
+        Self {
+            end: self.end - margin,
+        }
+    }
+
+    /// Returns the interval shrunk by `margin` on its bounded end,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_shrink(&self, margin: T) -> Option<Self>
+    where
+        T: CheckedAdd + CheckedSub,
+    {
+        Some(Self {
+            end: self.end.checked_sub(&margin)?,
+        })
+    }
+
+    /// Returns the interval shrunk by `margin` on its bounded end,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_shrink(&self, margin: T) -> Self
+    where
+        T: SaturatingAdd + SaturatingSub,
+    {
+        Self {
+            end: self.end.saturating_sub(&margin),
+        }
+    }
+}
+
 impl<T> From<RangeTo<T>> for IntervalTo<T> {
     fn from(value: RangeTo<T>) -> Self {
         Self { end: value.end }
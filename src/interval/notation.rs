@@ -0,0 +1,441 @@
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{
+    Bound, Bounds, Interval, IntervalError, IntervalFrom, IntervalFull, IntervalTo, NonEmpty,
+};
+
+/// Error type for parsing intervals from their textual notation.
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ParseIntervalError {
+    /// The input is neither in interval notation, nor in range syntax.
+    #[cfg_attr(
+        feature = "std",
+        error("expected interval notation (e.g. `[1, 5)`), or range syntax (e.g. `1..5`)")
+    )]
+    InvalidSyntax,
+    /// The input uses brackets that do not match its bounds
+    /// (or an inclusive range end, e.g. `1..=5`, as intervals are half-open).
+    #[cfg_attr(
+        feature = "std",
+        error(
+            "expected `[` before a bounded start, `(` before `-∞` and `)` (or `..`) before the end"
+        )
+    )]
+    InvalidBrackets,
+    /// The start bound could not be parsed.
+    #[cfg_attr(feature = "std", error("invalid start bound"))]
+    InvalidStart,
+    /// The end bound could not be parsed.
+    #[cfg_attr(feature = "std", error("invalid end bound"))]
+    InvalidEnd,
+    /// The parsed bounds do not form a valid interval of the requested type.
+    #[cfg_attr(feature = "std", error(transparent))]
+    Interval(IntervalError),
+}
+
+impl From<IntervalError> for ParseIntervalError {
+    #[inline]
+    fn from(error: IntervalError) -> Self {
+        Self::Interval(error)
+    }
+}
+
+const INFINITY: &str = "∞";
+const ASCII_INFINITY: &str = "inf";
+
+/// Writes an unbounded endpoint, using `inf` instead of `∞` for the alternate (`{:#}`) format.
+fn write_infinity(f: &mut fmt::Formatter<'_>, sign: &str) -> fmt::Result {
+    let infinity = if f.alternate() {
+        ASCII_INFINITY
+    } else {
+        INFINITY
+    };
+
+    write!(f, "{sign}{infinity}")
+}
+
+/// Formats as `[start, end)`.
+impl<T> Display for Interval<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        self.start.fmt(f)?;
+        f.write_str(", ")?;
+        self.end.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+/// Formats as `[start, ∞)`, or `[start, inf)` for the alternate (`{:#}`) format.
+impl<T> Display for IntervalFrom<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        self.start.fmt(f)?;
+        f.write_str(", ")?;
+        write_infinity(f, "")?;
+        f.write_str(")")
+    }
+}
+
+/// Formats as `(-∞, end)`, or `(-inf, end)` for the alternate (`{:#}`) format.
+impl<T> Display for IntervalTo<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        write_infinity(f, "-")?;
+        f.write_str(", ")?;
+        self.end.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+/// Formats as `(-∞, ∞)`, or `(-inf, inf)` for the alternate (`{:#}`) format.
+impl Display for IntervalFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        write_infinity(f, "-")?;
+        f.write_str(", ")?;
+        write_infinity(f, "")?;
+        f.write_str(")")
+    }
+}
+
+impl<T> Display for NonEmpty<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Parses the bounds of an interval in either interval notation (e.g. `[1, 5)`, `[3, ∞)`),
+/// or range syntax (e.g. `1..5`, `3..`).
+fn parse_bounds<T>(s: &str) -> Result<Bounds<T>, ParseIntervalError>
+where
+    T: FromStr,
+{
+    let s = s.trim();
+
+    if s.starts_with(['[', '(']) {
+        parse_notation(s)
+    } else if s.contains("..") {
+        parse_range(s)
+    } else {
+        Err(ParseIntervalError::InvalidSyntax)
+    }
+}
+
+fn parse_notation<T>(s: &str) -> Result<Bounds<T>, ParseIntervalError>
+where
+    T: FromStr,
+{
+    let (opening, rest) = s.split_at(1);
+
+    let Some(rest) = rest.strip_suffix([')', ']']) else {
+        return Err(ParseIntervalError::InvalidSyntax);
+    };
+
+    if !s.ends_with(')') {
+        return Err(ParseIntervalError::InvalidBrackets);
+    }
+
+    let Some((start, end)) = rest.split_once(',') else {
+        return Err(ParseIntervalError::InvalidSyntax);
+    };
+
+    let start = parse_bound(start, &["-∞", "-inf"], ParseIntervalError::InvalidStart)?;
+    let end = parse_bound(
+        end,
+        &["∞", "+∞", "inf", "+inf"],
+        ParseIntervalError::InvalidEnd,
+    )?;
+
+    let is_valid_opening = match start {
+        Bound::Bounded(_) => opening == "[",
+        Bound::Unbounded => opening == "(",
+    };
+
+    if !is_valid_opening {
+        return Err(ParseIntervalError::InvalidBrackets);
+    }
+
+    Ok(Bounds { start, end })
+}
+
+fn parse_range<T>(s: &str) -> Result<Bounds<T>, ParseIntervalError>
+where
+    T: FromStr,
+{
+    let Some((start, end)) = s.split_once("..") else {
+        return Err(ParseIntervalError::InvalidSyntax);
+    };
+
+    // Intervals are half-open, so (as with a closing `]`) an inclusive end is not supported:
+    if end.starts_with('=') {
+        return Err(ParseIntervalError::InvalidBrackets);
+    }
+
+    let start = parse_bound(start, &[], ParseIntervalError::InvalidStart)?;
+    let end = parse_bound(end, &[], ParseIntervalError::InvalidEnd)?;
+
+    Ok(Bounds { start, end })
+}
+
+fn parse_bound<T>(
+    s: &str,
+    infinities: &[&str],
+    error: ParseIntervalError,
+) -> Result<Bound<T>, ParseIntervalError>
+where
+    T: FromStr,
+{
+    let s = s.trim();
+
+    if s.is_empty() {
+        return match infinities.is_empty() {
+            true => Ok(Bound::Unbounded),
+            false => Err(error),
+        };
+    }
+
+    if infinities
+        .iter()
+        .any(|infinity| s.eq_ignore_ascii_case(infinity))
+    {
+        return Ok(Bound::Unbounded);
+    }
+
+    s.parse().map(Bound::Bounded).map_err(|_| error)
+}
+
+/// Parses `[start, end)` or `start..end`.
+impl<T> FromStr for Interval<T>
+where
+    T: FromStr,
+{
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::try_from(parse_bounds(s)?)?)
+    }
+}
+
+/// Parses `[start, ∞)` or `start..`.
+impl<T> FromStr for IntervalFrom<T>
+where
+    T: FromStr,
+{
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::try_from(parse_bounds(s)?)?)
+    }
+}
+
+/// Parses `(-∞, end)` or `..end`.
+impl<T> FromStr for IntervalTo<T>
+where
+    T: FromStr,
+{
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::try_from(parse_bounds(s)?)?)
+    }
+}
+
+/// Parses `(-∞, ∞)` or `..`.
+impl FromStr for IntervalFull {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The type of the (non-existent) bound values is irrelevant:
+        Ok(Self::try_from(parse_bounds::<u8>(s)?)?)
+    }
+}
+
+impl<T> FromStr for NonEmpty<Interval<T>>
+where
+    T: FromStr + PartialOrd,
+{
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::try_from(s.parse::<Interval<T>>()?)?)
+    }
+}
+
+impl<T> FromStr for NonEmpty<IntervalFrom<T>>
+where
+    T: FromStr,
+{
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<IntervalFrom<T>>().map(Self::from)
+    }
+}
+
+impl<T> FromStr for NonEmpty<IntervalTo<T>>
+where
+    T: FromStr,
+{
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<IntervalTo<T>>().map(Self::from)
+    }
+}
+
+impl FromStr for NonEmpty<IntervalFull> {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<IntervalFull>().map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Interval { start: 1, end: 5 }.to_string(), "[1, 5)");
+        assert_eq!(IntervalFrom { start: 3 }.to_string(), "[3, ∞)");
+        assert_eq!(IntervalTo { end: 7 }.to_string(), "(-∞, 7)");
+        assert_eq!(IntervalFull.to_string(), "(-∞, ∞)");
+
+        assert_eq!(format!("{:#}", IntervalFrom { start: 3 }), "[3, inf)");
+        assert_eq!(format!("{:#}", IntervalTo { end: 7 }), "(-inf, 7)");
+        assert_eq!(format!("{:#}", IntervalFull), "(-inf, inf)");
+
+        let interval = NonEmpty(Interval {
+            start: 1.0,
+            end: 2.5,
+        });
+        assert_eq!(format!("{interval:.2}"), "[1.00, 2.50)");
+    }
+
+    #[test]
+    fn from_str_notation() {
+        assert_eq!("[1, 5)".parse(), Ok(Interval { start: 1, end: 5 }));
+        assert_eq!(" [1,5) ".parse(), Ok(Interval { start: 1, end: 5 }));
+        assert_eq!("[3, ∞)".parse(), Ok(IntervalFrom { start: 3 }));
+        assert_eq!("[3, +inf)".parse(), Ok(IntervalFrom { start: 3 }));
+        assert_eq!("(-∞, 7)".parse(), Ok(IntervalTo { end: 7 }));
+        assert_eq!("(-INF, 7)".parse(), Ok(IntervalTo { end: 7 }));
+        assert_eq!("(-∞, ∞)".parse(), Ok(IntervalFull));
+        assert_eq!("(-inf, inf)".parse(), Ok(IntervalFull));
+    }
+
+    #[test]
+    fn from_str_range() {
+        assert_eq!("1..5".parse(), Ok(Interval { start: 1, end: 5 }));
+        assert_eq!(
+            "1..=5".parse::<Interval<i32>>(),
+            Err(ParseIntervalError::InvalidBrackets)
+        );
+        assert_eq!(
+            "-1.5..2.5".parse(),
+            Ok(Interval {
+                start: -1.5,
+                end: 2.5
+            })
+        );
+        assert_eq!("3..".parse(), Ok(IntervalFrom { start: 3 }));
+        assert_eq!("..7".parse(), Ok(IntervalTo { end: 7 }));
+        assert_eq!(
+            "..=7".parse::<IntervalTo<i32>>(),
+            Err(ParseIntervalError::InvalidBrackets)
+        );
+        assert_eq!("..".parse(), Ok(IntervalFull));
+    }
+
+    #[test]
+    fn from_str_errors() {
+        type Error = ParseIntervalError;
+
+        assert_eq!("1, 5".parse::<Interval<i32>>(), Err(Error::InvalidSyntax));
+        assert_eq!("[1 5)".parse::<Interval<i32>>(), Err(Error::InvalidSyntax));
+        assert_eq!(
+            "[1, 5]".parse::<Interval<i32>>(),
+            Err(Error::InvalidBrackets)
+        );
+        assert_eq!(
+            "(1, 5)".parse::<Interval<i32>>(),
+            Err(Error::InvalidBrackets)
+        );
+        assert_eq!(
+            "[-∞, 5)".parse::<IntervalTo<i32>>(),
+            Err(Error::InvalidBrackets)
+        );
+        assert_eq!("[x, 5)".parse::<Interval<i32>>(), Err(Error::InvalidStart));
+        assert_eq!("1..x".parse::<Interval<i32>>(), Err(Error::InvalidEnd));
+        assert_eq!(
+            "1..=".parse::<IntervalFrom<i32>>(),
+            Err(Error::InvalidBrackets)
+        );
+        assert_eq!("[, 5)".parse::<Interval<i32>>(), Err(Error::InvalidStart));
+        assert_eq!(
+            "3..".parse::<Interval<i32>>(),
+            Err(Error::Interval(IntervalError::MismatchedBounds))
+        );
+        assert_eq!(
+            "[1, 5)".parse::<IntervalFull>(),
+            Err(Error::Interval(IntervalError::MismatchedBounds))
+        );
+    }
+
+    #[test]
+    fn non_empty() {
+        assert_eq!(
+            "[1, 5)".parse::<NonEmpty<Interval<i32>>>(),
+            Ok(NonEmpty(Interval { start: 1, end: 5 }))
+        );
+        assert_eq!(
+            "5..5".parse::<NonEmpty<Interval<i32>>>(),
+            Err(ParseIntervalError::Interval(IntervalError::EmptyInterval))
+        );
+        assert_eq!(
+            "NaN..1".parse::<NonEmpty<Interval<f64>>>(),
+            Err(ParseIntervalError::Interval(IntervalError::AmbiguousOrder))
+        );
+        assert_eq!(
+            "3..".parse::<NonEmpty<IntervalFrom<i32>>>(),
+            Ok(NonEmpty(IntervalFrom { start: 3 }))
+        );
+        assert_eq!(
+            "(-∞, 7)".parse::<NonEmpty<IntervalTo<i32>>>(),
+            Ok(NonEmpty(IntervalTo { end: 7 }))
+        );
+        assert_eq!(
+            "..".parse::<NonEmpty<IntervalFull>>(),
+            Ok(NonEmpty(IntervalFull))
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let interval = NonEmpty(Interval { start: -3, end: 4 });
+        assert_eq!(interval.to_string().parse(), Ok(interval));
+
+        let interval = NonEmpty(IntervalFrom { start: 3 });
+        assert_eq!(format!("{interval:#}").parse(), Ok(interval));
+
+        let interval = NonEmpty(IntervalTo { end: 7 });
+        assert_eq!(interval.to_string().parse(), Ok(interval));
+    }
+}
@@ -0,0 +1,850 @@
+//! Parsing and formatting of [ISO 8601 time intervals][iso8601-intervals].
+//!
+//! [`Iso8601<I>`] parses the interval expressions `start/end`, `start/duration`
+//! and `duration/end`, as well as the open-ended forms `start/..`, `../end` and `../..`
+//! of the ISO 8601-2 extension, into the crate's interval types.
+//! Intervals are always formatted as `start/end` (or its open-ended forms).
+//!
+//! [`RepeatingInterval<T>`] parses recurring time intervals (e.g. `R5/2024-01-01T00:00Z/P1D`)
+//! and iterates over their occurrences as [`NonEmpty<Interval<T>>`].
+//!
+//! Endpoints are abstracted over by the [`Iso8601Point`] trait,
+//! which (with the `chrono` feature enabled) is implemented for
+//! `chrono`'s `NaiveDate`, `NaiveDateTime`, `DateTime<Utc>` and `DateTime<FixedOffset>`.
+//!
+//! ```
+//! # #[cfg(feature = "chrono")] {
+//! use allen_intervals::{iso8601::{Iso8601, RepeatingInterval}, Interval, IntervalFrom, NonEmpty};
+//! use chrono::NaiveDate;
+//!
+//! let date = |day| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+//!
+//! let Iso8601(interval) = "2024-01-01/P1W".parse::<Iso8601<NonEmpty<Interval<_>>>>().unwrap();
+//! assert_eq!(interval.into_inner(), Interval { start: date(1), end: date(8) });
+//!
+//! let Iso8601(interval) = "2024-01-01/..".parse::<Iso8601<IntervalFrom<NaiveDate>>>().unwrap();
+//! assert_eq!(Iso8601(interval).to_string(), "2024-01-01/..");
+//!
+//! let repeating: RepeatingInterval<NaiveDate> = "R3/2024-01-01/P1D".parse().unwrap();
+//! let starts: Vec<_> = repeating.into_iter().map(|interval| interval.into_inner().start).collect();
+//! assert_eq!(starts, [date(1), date(2), date(3)]);
+//! # }
+//! ```
+//!
+//! [iso8601-intervals]: https://en.wikipedia.org/wiki/ISO_8601#Time_intervals
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{
+    Bound, Bounds, Interval, IntervalError, IntervalFrom, IntervalFull, IntervalTo, NonEmpty,
+};
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+/// Error type for parsing ISO 8601 time intervals.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, thiserror::Error)]
+pub enum Iso8601Error {
+    /// The input is not of the form `start/end`, `start/duration`, `duration/end`
+    /// (or one of their open-ended forms).
+    #[error(
+        "expected `start/end`, `start/duration`, `duration/end`, or an open-ended form using `..`"
+    )]
+    InvalidSyntax,
+    /// The number of repetitions of a repeating interval could not be parsed.
+    #[error("invalid number of repetitions")]
+    InvalidRepetitions,
+    /// The duration could not be parsed.
+    #[error("invalid duration")]
+    InvalidDuration,
+    /// The start could not be parsed.
+    #[error("invalid start")]
+    InvalidStart,
+    /// The end could not be parsed.
+    #[error("invalid end")]
+    InvalidEnd,
+    /// The duration could not be applied to the endpoint.
+    #[error("duration is out of range of (or not applicable to) the endpoint")]
+    OutOfRange,
+    /// The parsed interval is not a valid interval of the requested type.
+    #[error(transparent)]
+    Interval(#[from] IntervalError),
+}
+
+/// An ISO 8601 duration (e.g. `P1Y2M3DT4H5M6.5S`, or `P2W`).
+///
+/// Fractions (e.g. `PT0.5S` or `PT0,5S`) are only supported for seconds.
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Duration {
+    /// The number of years.
+    pub years: u64,
+    /// The number of months.
+    pub months: u64,
+    /// The number of weeks.
+    pub weeks: u64,
+    /// The number of days.
+    pub days: u64,
+    /// The number of hours.
+    pub hours: u64,
+    /// The number of minutes.
+    pub minutes: u64,
+    /// The number of whole seconds.
+    pub seconds: u64,
+    /// The fractional part of the seconds, in nanoseconds (less than `1_000_000_000`).
+    pub nanoseconds: u32,
+}
+
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
+impl Duration {
+    /// Returns `true` iff all of the duration's components are zero.
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Multiplies each of the duration's components by `factor`,
+    /// returning `None` on overflow.
+    pub fn checked_mul(&self, factor: u64) -> Option<Self> {
+        let nanoseconds = u64::from(self.nanoseconds).checked_mul(factor)?;
+        let carry = nanoseconds / u64::from(NANOSECONDS_PER_SECOND);
+
+        Some(Self {
+            years: self.years.checked_mul(factor)?,
+            months: self.months.checked_mul(factor)?,
+            weeks: self.weeks.checked_mul(factor)?,
+            days: self.days.checked_mul(factor)?,
+            hours: self.hours.checked_mul(factor)?,
+            minutes: self.minutes.checked_mul(factor)?,
+            seconds: self.seconds.checked_mul(factor)?.checked_add(carry)?,
+            nanoseconds: (nanoseconds % u64::from(NANOSECONDS_PER_SECOND)) as u32,
+        })
+    }
+}
+
+/// Formats as `P[nY][nM][nW][nD][T[nH][nM][nS]]`, or `PT0S` for a zero duration.
+impl Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("PT0S");
+        }
+
+        f.write_str("P")?;
+
+        for (value, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if value != 0 {
+                write!(f, "{value}{designator}")?;
+            }
+        }
+
+        if self.hours == 0 && self.minutes == 0 && self.seconds == 0 && self.nanoseconds == 0 {
+            return Ok(());
+        }
+
+        f.write_str("T")?;
+
+        for (value, designator) in [(self.hours, 'H'), (self.minutes, 'M')] {
+            if value != 0 {
+                write!(f, "{value}{designator}")?;
+            }
+        }
+
+        if self.seconds != 0 || self.nanoseconds != 0 {
+            write!(f, "{}", self.seconds)?;
+
+            if self.nanoseconds != 0 {
+                let fraction = format!("{:09}", self.nanoseconds);
+                write!(f, ".{}", fraction.trim_end_matches('0'))?;
+            }
+
+            f.write_str("S")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Duration {
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = Iso8601Error::InvalidDuration;
+
+        let s = s.strip_prefix('P').ok_or(error)?;
+
+        let (date, time) = match s.split_once('T') {
+            Some((_, "")) => return Err(error),
+            Some((date, time)) => (date, time),
+            None if s.is_empty() => return Err(error),
+            None => (s, ""),
+        };
+
+        let mut duration = Self::default();
+
+        parse_components(date, "YMWD", |designator, value| {
+            let value = value.parse().map_err(|_| error)?;
+
+            match designator {
+                'Y' => duration.years = value,
+                'M' => duration.months = value,
+                'W' => duration.weeks = value,
+                _ => duration.days = value,
+            }
+
+            Ok(())
+        })?;
+
+        parse_components(time, "HMS", |designator, value| {
+            if designator == 'S' {
+                (duration.seconds, duration.nanoseconds) = parse_seconds(value).ok_or(error)?;
+                return Ok(());
+            }
+
+            let value = value.parse().map_err(|_| error)?;
+
+            match designator {
+                'H' => duration.hours = value,
+                _ => duration.minutes = value,
+            }
+
+            Ok(())
+        })?;
+
+        Ok(duration)
+    }
+}
+
+/// Parses a sequence of `<value><designator>` components,
+/// whose designators must appear in the order given by `designators`.
+fn parse_components(
+    mut s: &str,
+    designators: &str,
+    mut f: impl FnMut(char, &str) -> Result<(), Iso8601Error>,
+) -> Result<(), Iso8601Error> {
+    let mut designators = designators.chars();
+
+    while !s.is_empty() {
+        let index = s
+            .find(|c: char| !matches!(c, '0'..='9' | '.' | ','))
+            .ok_or(Iso8601Error::InvalidDuration)?;
+
+        let (value, rest) = s.split_at(index);
+        let mut rest = rest.chars();
+        let designator = rest.next().ok_or(Iso8601Error::InvalidDuration)?;
+
+        if value.is_empty() || !designators.any(|expected| expected == designator) {
+            return Err(Iso8601Error::InvalidDuration);
+        }
+
+        f(designator, value)?;
+
+        s = rest.as_str();
+    }
+
+    Ok(())
+}
+
+/// Parses (possibly fractional) seconds into whole seconds and nanoseconds.
+fn parse_seconds(s: &str) -> Option<(u64, u32)> {
+    let Some((seconds, fraction)) = s.split_once(['.', ',']) else {
+        return Some((s.parse().ok()?, 0));
+    };
+
+    if seconds.is_empty() || fraction.is_empty() || fraction.len() > 9 {
+        return None;
+    }
+
+    if !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let nanoseconds = format!("{fraction:0<9}").parse().ok()?;
+
+    Some((seconds.parse().ok()?, nanoseconds))
+}
+
+/// A point in time that can be used as the endpoint of an ISO 8601 time interval.
+pub trait Iso8601Point: Sized {
+    /// Parses the point from its ISO 8601 representation.
+    fn parse_iso8601(s: &str) -> Option<Self>;
+
+    /// Formats the point in its ISO 8601 representation.
+    fn fmt_iso8601(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Returns the point `duration` after `self`,
+    /// or `None` if out of range (or if `duration` is not applicable to the point).
+    fn checked_add_duration(&self, duration: &Duration) -> Option<Self>;
+
+    /// Returns the point `duration` before `self`,
+    /// or `None` if out of range (or if `duration` is not applicable to the point).
+    fn checked_sub_duration(&self, duration: &Duration) -> Option<Self>;
+
+    /// Returns the exact duration from `earlier` to `self`,
+    /// or `None` if `earlier` is after `self`.
+    fn duration_since(&self, earlier: &Self) -> Option<Duration>;
+}
+
+struct Point<'a, T>(&'a T);
+
+impl<T> Display for Point<'_, T>
+where
+    T: Iso8601Point,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_iso8601(f)
+    }
+}
+
+/// A bounded ISO 8601 time interval expression.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum Expression<T> {
+    /// An interval expressed by its start and end (e.g. `2024-01-01/2024-02-01`).
+    StartEnd {
+        /// The interval's start.
+        start: T,
+        /// The interval's end.
+        end: T,
+    },
+    /// An interval expressed by its start and duration (e.g. `2024-01-01/P1M`).
+    StartDuration {
+        /// The interval's start.
+        start: T,
+        /// The interval's duration.
+        duration: Duration,
+    },
+    /// An interval expressed by its duration and end (e.g. `P1M/2024-02-01`).
+    DurationEnd {
+        /// The interval's duration.
+        duration: Duration,
+        /// The interval's end.
+        end: T,
+    },
+}
+
+impl<T> Expression<T>
+where
+    T: Iso8601Point,
+{
+    /// Resolves the expression into an interval.
+    pub fn into_interval(self) -> Result<Interval<T>, Iso8601Error> {
+        match self {
+            Self::StartEnd { start, end } => Ok(Interval { start, end }),
+            Self::StartDuration { start, duration } => {
+                let end = start
+                    .checked_add_duration(&duration)
+                    .ok_or(Iso8601Error::OutOfRange)?;
+                Ok(Interval { start, end })
+            }
+            Self::DurationEnd { duration, end } => {
+                let start = end
+                    .checked_sub_duration(&duration)
+                    .ok_or(Iso8601Error::OutOfRange)?;
+                Ok(Interval { start, end })
+            }
+        }
+    }
+}
+
+impl<T> Display for Expression<T>
+where
+    T: Iso8601Point,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StartEnd { start, end } => write!(f, "{}/{}", Point(start), Point(end)),
+            Self::StartDuration { start, duration } => write!(f, "{}/{duration}", Point(start)),
+            Self::DurationEnd { duration, end } => write!(f, "{duration}/{}", Point(end)),
+        }
+    }
+}
+
+impl<T> FromStr for Expression<T>
+where
+    T: Iso8601Point,
+{
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .trim()
+            .split_once('/')
+            .ok_or(Iso8601Error::InvalidSyntax)?;
+
+        match (start.starts_with('P'), end.starts_with('P')) {
+            (false, false) => Ok(Self::StartEnd {
+                start: parse_point(start, Iso8601Error::InvalidStart)?,
+                end: parse_point(end, Iso8601Error::InvalidEnd)?,
+            }),
+            (false, true) => Ok(Self::StartDuration {
+                start: parse_point(start, Iso8601Error::InvalidStart)?,
+                duration: end.parse()?,
+            }),
+            (true, false) => Ok(Self::DurationEnd {
+                duration: start.parse()?,
+                end: parse_point(end, Iso8601Error::InvalidEnd)?,
+            }),
+            (true, true) => Err(Iso8601Error::InvalidSyntax),
+        }
+    }
+}
+
+fn parse_point<T>(s: &str, error: Iso8601Error) -> Result<T, Iso8601Error>
+where
+    T: Iso8601Point,
+{
+    T::parse_iso8601(s).ok_or(error)
+}
+
+const OPEN: &str = "..";
+
+fn parse_bounds<T>(s: &str) -> Result<Bounds<T>, Iso8601Error>
+where
+    T: Iso8601Point,
+{
+    let s = s.trim();
+    let (start, end) = s.split_once('/').ok_or(Iso8601Error::InvalidSyntax)?;
+
+    match (start, end) {
+        (OPEN, OPEN) => Ok(Bounds {
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+        }),
+        (OPEN, end) => Ok(Bounds {
+            start: Bound::Unbounded,
+            end: Bound::Bounded(parse_point(end, Iso8601Error::InvalidEnd)?),
+        }),
+        (start, OPEN) => Ok(Bounds {
+            start: Bound::Bounded(parse_point(start, Iso8601Error::InvalidStart)?),
+            end: Bound::Unbounded,
+        }),
+        _ => {
+            let Interval { start, end } = s.parse::<Expression<T>>()?.into_interval()?;
+
+            Ok(Bounds {
+                start: Bound::Bounded(start),
+                end: Bound::Bounded(end),
+            })
+        }
+    }
+}
+
+fn fmt_bounds<T>(f: &mut fmt::Formatter<'_>, start: Option<&T>, end: Option<&T>) -> fmt::Result
+where
+    T: Iso8601Point,
+{
+    match start {
+        Some(start) => start.fmt_iso8601(f)?,
+        None => f.write_str(OPEN)?,
+    }
+
+    f.write_str("/")?;
+
+    match end {
+        Some(end) => end.fmt_iso8601(f),
+        None => f.write_str(OPEN),
+    }
+}
+
+/// A wrapper for parsing and formatting intervals as ISO 8601 time intervals.
+///
+/// | Type              | Formats as  | Parses                                                |
+/// | ----------------- | ----------- | ----------------------------------------------------- |
+/// | `Interval<T>`     | `start/end` | `start/end`, `start/duration` and `duration/end`      |
+/// | `IntervalFrom<T>` | `start/..`  | `start/..`                                            |
+/// | `IntervalTo<T>`   | `../end`    | `../end`                                              |
+/// | `IntervalFull`    | `../..`     | `../..`                                               |
+///
+/// The same applies to their `NonEmpty<_>` counterparts.
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Iso8601<I>(pub I);
+
+impl<T> Display for Iso8601<Interval<T>>
+where
+    T: Iso8601Point,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_bounds(f, Some(&self.0.start), Some(&self.0.end))
+    }
+}
+
+impl<T> Display for Iso8601<IntervalFrom<T>>
+where
+    T: Iso8601Point,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_bounds(f, Some(&self.0.start), None)
+    }
+}
+
+impl<T> Display for Iso8601<IntervalTo<T>>
+where
+    T: Iso8601Point,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_bounds(f, None, Some(&self.0.end))
+    }
+}
+
+impl Display for Iso8601<IntervalFull> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{OPEN}/{OPEN}")
+    }
+}
+
+impl<T> Display for Iso8601<NonEmpty<Interval<T>>>
+where
+    T: Iso8601Point,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let interval = self.0.as_inner();
+        fmt_bounds(f, Some(&interval.start), Some(&interval.end))
+    }
+}
+
+impl<T> Display for Iso8601<NonEmpty<IntervalFrom<T>>>
+where
+    T: Iso8601Point,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_bounds(f, Some(&self.0.as_inner().start), None)
+    }
+}
+
+impl<T> Display for Iso8601<NonEmpty<IntervalTo<T>>>
+where
+    T: Iso8601Point,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_bounds(f, None, Some(&self.0.as_inner().end))
+    }
+}
+
+impl Display for Iso8601<NonEmpty<IntervalFull>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{OPEN}/{OPEN}")
+    }
+}
+
+impl<T> FromStr for Iso8601<Interval<T>>
+where
+    T: Iso8601Point,
+{
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(Interval::try_from(parse_bounds(s)?)?))
+    }
+}
+
+impl<T> FromStr for Iso8601<IntervalFrom<T>>
+where
+    T: Iso8601Point,
+{
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(IntervalFrom::try_from(parse_bounds(s)?)?))
+    }
+}
+
+impl<T> FromStr for Iso8601<IntervalTo<T>>
+where
+    T: Iso8601Point,
+{
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(IntervalTo::try_from(parse_bounds(s)?)?))
+    }
+}
+
+impl FromStr for Iso8601<IntervalFull> {
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().split_once('/') {
+            Some((OPEN, OPEN)) => Ok(Self(IntervalFull)),
+            Some(_) => Err(IntervalError::MismatchedBounds.into()),
+            None => Err(Iso8601Error::InvalidSyntax),
+        }
+    }
+}
+
+impl<T> FromStr for Iso8601<NonEmpty<Interval<T>>>
+where
+    T: Iso8601Point + PartialOrd,
+{
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Iso8601(interval) = s.parse::<Iso8601<Interval<T>>>()?;
+        Ok(Self(NonEmpty::try_from(interval)?))
+    }
+}
+
+impl<T> FromStr for Iso8601<NonEmpty<IntervalFrom<T>>>
+where
+    T: Iso8601Point,
+{
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Iso8601(interval) = s.parse::<Iso8601<IntervalFrom<T>>>()?;
+        Ok(Self(NonEmpty::from(interval)))
+    }
+}
+
+impl<T> FromStr for Iso8601<NonEmpty<IntervalTo<T>>>
+where
+    T: Iso8601Point,
+{
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Iso8601(interval) = s.parse::<Iso8601<IntervalTo<T>>>()?;
+        Ok(Self(NonEmpty::from(interval)))
+    }
+}
+
+impl FromStr for Iso8601<NonEmpty<IntervalFull>> {
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Iso8601(interval) = s.parse::<Iso8601<IntervalFull>>()?;
+        Ok(Self(NonEmpty::from(interval)))
+    }
+}
+
+/// An ISO 8601 repeating time interval (e.g. `R5/2024-01-01T00:00Z/P1D`).
+///
+/// Occurrences are consecutive, each lasting the duration of the first occurrence.
+/// For intervals expressed by their duration and end (e.g. `R3/P1D/2024-01-31`)
+/// the occurrences are counted (and iterated) backwards from the end.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct RepeatingInterval<T> {
+    /// The number of occurrences, or `None` if unbounded (i.e. `R/…`).
+    pub repetitions: Option<u64>,
+    /// The expression of the first occurrence.
+    pub expression: Expression<T>,
+}
+
+/// Formats as `Rn/expression`, or `R/expression` if unbounded.
+impl<T> Display for RepeatingInterval<T>
+where
+    T: Iso8601Point,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("R")?;
+
+        if let Some(repetitions) = self.repetitions {
+            write!(f, "{repetitions}")?;
+        }
+
+        write!(f, "/{}", self.expression)
+    }
+}
+
+impl<T> FromStr for RepeatingInterval<T>
+where
+    T: Iso8601Point + PartialOrd + Clone,
+{
+    type Err = Iso8601Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix('R').ok_or(Iso8601Error::InvalidSyntax)?;
+        let (repetitions, expression) = s.split_once('/').ok_or(Iso8601Error::InvalidSyntax)?;
+
+        let repetitions = match repetitions {
+            "" => None,
+            repetitions if repetitions.bytes().all(|byte| byte.is_ascii_digit()) => Some(
+                repetitions
+                    .parse()
+                    .map_err(|_| Iso8601Error::InvalidRepetitions)?,
+            ),
+            _ => return Err(Iso8601Error::InvalidRepetitions),
+        };
+
+        let expression: Expression<T> = expression.parse()?;
+
+        // Reject expressions whose occurrences would be empty:
+        NonEmpty::try_from(expression.clone().into_interval()?)?;
+
+        Ok(Self {
+            repetitions,
+            expression,
+        })
+    }
+}
+
+impl<T> IntoIterator for RepeatingInterval<T>
+where
+    T: Iso8601Point + PartialOrd,
+{
+    type Item = NonEmpty<Interval<T>>;
+    type IntoIter = Occurrences<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (origin, step, is_backwards) = match self.expression {
+            Expression::StartEnd { start, end } => {
+                let step = end.duration_since(&start);
+                (start, step, false)
+            }
+            Expression::StartDuration { start, duration } => (start, Some(duration), false),
+            Expression::DurationEnd { duration, end } => (end, Some(duration), true),
+        };
+
+        Occurrences {
+            origin,
+            step: step.unwrap_or_default(),
+            is_backwards,
+            index: 0,
+            remaining: if step.is_some() {
+                self.repetitions
+            } else {
+                Some(0)
+            },
+        }
+    }
+}
+
+/// An iterator over the occurrences of a [`RepeatingInterval<T>`].
+///
+/// The iterator ends early if an occurrence is out of range of the endpoint type.
+#[derive(Clone, Debug)]
+pub struct Occurrences<T> {
+    origin: T,
+    step: Duration,
+    is_backwards: bool,
+    index: u64,
+    remaining: Option<u64>,
+}
+
+impl<T> Occurrences<T>
+where
+    T: Iso8601Point + PartialOrd,
+{
+    fn occurrence(&self) -> Option<NonEmpty<Interval<T>>> {
+        let near = self.step.checked_mul(self.index)?;
+        let far = self.step.checked_mul(self.index.checked_add(1)?)?;
+
+        let interval = if self.is_backwards {
+            Interval {
+                start: self.origin.checked_sub_duration(&far)?,
+                end: self.origin.checked_sub_duration(&near)?,
+            }
+        } else {
+            Interval {
+                start: self.origin.checked_add_duration(&near)?,
+                end: self.origin.checked_add_duration(&far)?,
+            }
+        };
+
+        NonEmpty::try_from(interval).ok()
+    }
+}
+
+impl<T> Iterator for Occurrences<T>
+where
+    T: Iso8601Point + PartialOrd,
+{
+    type Item = NonEmpty<Interval<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+
+        let Some(occurrence) = self.occurrence() else {
+            self.remaining = Some(0);
+            return None;
+        };
+
+        self.index += 1;
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+
+        Some(occurrence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_from_str() {
+        assert_eq!(
+            "P1Y2M3W4DT5H6M7.5S".parse(),
+            Ok(Duration {
+                years: 1,
+                months: 2,
+                weeks: 3,
+                days: 4,
+                hours: 5,
+                minutes: 6,
+                seconds: 7,
+                nanoseconds: 500_000_000,
+            })
+        );
+        assert_eq!(
+            "PT0,25S".parse(),
+            Ok(Duration {
+                nanoseconds: 250_000_000,
+                ..Duration::default()
+            })
+        );
+        assert_eq!(
+            "PT1M".parse(),
+            Ok(Duration {
+                minutes: 1,
+                ..Duration::default()
+            })
+        );
+
+        for invalid in [
+            "", "P", "PT", "1D", "P1", "PD", "P1D1Y", "P1H", "P1.5D", "PT.5S", "PT1.S",
+        ] {
+            assert_eq!(
+                invalid.parse::<Duration>(),
+                Err(Iso8601Error::InvalidDuration),
+                "{invalid:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn duration_display() {
+        for duration in [
+            "P1Y2M3W4DT5H6M7.5S",
+            "P1D",
+            "PT1M",
+            "PT0.000000001S",
+            "PT0S",
+        ] {
+            assert_eq!(duration.parse::<Duration>().unwrap().to_string(), duration);
+        }
+    }
+
+    #[test]
+    fn duration_checked_mul() {
+        let duration: Duration = "P1MT0.6S".parse().unwrap();
+
+        assert_eq!(
+            duration.checked_mul(3),
+            Some(Duration {
+                months: 3,
+                seconds: 1,
+                nanoseconds: 800_000_000,
+                ..Duration::default()
+            })
+        );
+        assert_eq!(duration.checked_mul(u64::MAX), None);
+    }
+}
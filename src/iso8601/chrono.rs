@@ -0,0 +1,383 @@
+use std::fmt;
+
+use chrono::{DateTime, Days, FixedOffset, Months, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+
+use super::{Duration, Iso8601Point};
+
+const DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%Y%m%d"];
+
+const DATE_TIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y%m%dT%H%M%S%.f",
+    "%Y%m%dT%H%M",
+];
+
+const ZONED_DATE_TIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f%#z",
+    "%Y-%m-%dT%H:%M%#z",
+    "%Y%m%dT%H%M%S%.f%#z",
+    "%Y%m%dT%H%M%#z",
+];
+
+fn months(duration: &Duration) -> Option<Months> {
+    let months = duration
+        .years
+        .checked_mul(12)?
+        .checked_add(duration.months)?;
+    Some(Months::new(u32::try_from(months).ok()?))
+}
+
+fn days(duration: &Duration) -> Option<Days> {
+    let days = duration.weeks.checked_mul(7)?.checked_add(duration.days)?;
+    Some(Days::new(days))
+}
+
+fn has_time(duration: &Duration) -> bool {
+    duration.hours != 0
+        || duration.minutes != 0
+        || duration.seconds != 0
+        || duration.nanoseconds != 0
+}
+
+fn time_delta(duration: &Duration) -> Option<TimeDelta> {
+    let seconds = duration
+        .hours
+        .checked_mul(60 * 60)?
+        .checked_add(duration.minutes.checked_mul(60)?)?
+        .checked_add(duration.seconds)?;
+
+    TimeDelta::new(i64::try_from(seconds).ok()?, duration.nanoseconds)
+}
+
+fn from_time_delta(delta: TimeDelta) -> Option<Duration> {
+    if delta < TimeDelta::zero() {
+        return None;
+    }
+
+    Some(Duration {
+        seconds: u64::try_from(delta.num_seconds()).ok()?,
+        nanoseconds: u32::try_from(delta.subsec_nanos()).ok()?,
+        ..Duration::default()
+    })
+}
+
+fn parse_zoned(s: &str) -> Option<DateTime<FixedOffset>> {
+    ZONED_DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(s, format).ok())
+}
+
+/// Only durations without a time component (e.g. `P1M2D`, but not `PT12H`) apply to dates.
+impl Iso8601Point for NaiveDate {
+    fn parse_iso8601(s: &str) -> Option<Self> {
+        DATE_FORMATS
+            .iter()
+            .find_map(|format| Self::parse_from_str(s, format).ok())
+    }
+
+    fn fmt_iso8601(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format("%Y-%m-%d"))
+    }
+
+    fn checked_add_duration(&self, duration: &Duration) -> Option<Self> {
+        if has_time(duration) {
+            return None;
+        }
+
+        self.checked_add_months(months(duration)?)?
+            .checked_add_days(days(duration)?)
+    }
+
+    fn checked_sub_duration(&self, duration: &Duration) -> Option<Self> {
+        if has_time(duration) {
+            return None;
+        }
+
+        self.checked_sub_months(months(duration)?)?
+            .checked_sub_days(days(duration)?)
+    }
+
+    fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+        Some(Duration {
+            days: u64::try_from(self.signed_duration_since(*earlier).num_days()).ok()?,
+            ..Duration::default()
+        })
+    }
+}
+
+macro_rules! date_time_impl {
+    ($t:ty, $format:literal, |$s:ident| $parse:expr) => {
+        impl Iso8601Point for $t {
+            fn parse_iso8601($s: &str) -> Option<Self> {
+                $parse
+            }
+
+            fn fmt_iso8601(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.format($format))
+            }
+
+            fn checked_add_duration(&self, duration: &Duration) -> Option<Self> {
+                self.checked_add_months(months(duration)?)?
+                    .checked_add_days(days(duration)?)?
+                    .checked_add_signed(time_delta(duration)?)
+            }
+
+            fn checked_sub_duration(&self, duration: &Duration) -> Option<Self> {
+                self.checked_sub_months(months(duration)?)?
+                    .checked_sub_days(days(duration)?)?
+                    .checked_sub_signed(time_delta(duration)?)
+            }
+
+            fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+                from_time_delta(self.signed_duration_since(*earlier))
+            }
+        }
+    };
+}
+
+date_time_impl!(NaiveDateTime, "%Y-%m-%dT%H:%M:%S%.f", |s| {
+    DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+});
+
+date_time_impl!(DateTime<Utc>, "%Y-%m-%dT%H:%M:%S%.fZ", |s| {
+    parse_zoned(s).map(|date_time| date_time.with_timezone(&Utc))
+});
+
+date_time_impl!(DateTime<FixedOffset>, "%Y-%m-%dT%H:%M:%S%.f%:z", |s| {
+    parse_zoned(s)
+});
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveTime, TimeZone};
+
+    use crate::{
+        iso8601::{Iso8601, Iso8601Error, RepeatingInterval},
+        Interval, IntervalError, IntervalFrom, IntervalFull, IntervalTo, NonEmpty,
+    };
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn utc(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, day, hour, minute, 0).unwrap()
+    }
+
+    fn parse<I>(s: &str) -> Result<I, Iso8601Error>
+    where
+        Iso8601<I>: std::str::FromStr<Err = Iso8601Error>,
+    {
+        s.parse::<Iso8601<I>>().map(|Iso8601(interval)| interval)
+    }
+
+    #[test]
+    fn points() {
+        assert_eq!(
+            NaiveDate::parse_iso8601("20240131"),
+            Some(date(2024, 1, 31))
+        );
+        assert_eq!(
+            NaiveDateTime::parse_iso8601("2024-01-31T12:30"),
+            Some(date(2024, 1, 31).and_time(NaiveTime::from_hms_opt(12, 30, 0).unwrap()))
+        );
+        assert_eq!(
+            DateTime::<Utc>::parse_iso8601("2024-01-02T00:00Z"),
+            Some(utc(2, 0, 0))
+        );
+        assert_eq!(
+            DateTime::<Utc>::parse_iso8601("20240102T013000+01:00"),
+            Some(utc(2, 0, 30))
+        );
+        assert_eq!(
+            DateTime::<Utc>::parse_iso8601("2024-01-02T00:00:00.5+00:00")
+                .unwrap()
+                .timestamp_subsec_millis(),
+            500
+        );
+        assert_eq!(DateTime::<Utc>::parse_iso8601("2024-01-02T00:00"), None);
+    }
+
+    #[test]
+    fn interval() {
+        let expected = Interval {
+            start: date(2024, 1, 31),
+            end: date(2024, 2, 29),
+        };
+
+        assert_eq!(parse("2024-01-31/2024-02-29"), Ok(expected));
+        assert_eq!(parse("2024-01-31/P1M"), Ok(expected));
+        assert_eq!(parse("P29D/2024-02-29"), Ok(expected));
+        assert_eq!(parse("P4W1D/2024-02-29"), Ok(expected));
+
+        assert_eq!(
+            parse("2024-01-01T00:00Z/PT1H30M"),
+            Ok(Interval {
+                start: utc(1, 0, 0),
+                end: utc(1, 1, 30),
+            })
+        );
+
+        assert_eq!(
+            parse::<Interval<NaiveDate>>("2024-01-01/PT1H"),
+            Err(Iso8601Error::OutOfRange)
+        );
+        assert_eq!(
+            parse::<Interval<NaiveDate>>("P1D/P1D"),
+            Err(Iso8601Error::InvalidSyntax)
+        );
+        assert_eq!(
+            parse::<Interval<NaiveDate>>("2024-01-01"),
+            Err(Iso8601Error::InvalidSyntax)
+        );
+        assert_eq!(
+            parse::<Interval<NaiveDate>>("2024-13-01/P1D"),
+            Err(Iso8601Error::InvalidStart)
+        );
+        assert_eq!(
+            parse::<Interval<NaiveDate>>("2024-01-01/.."),
+            Err(IntervalError::MismatchedBounds.into())
+        );
+    }
+
+    #[test]
+    fn open_ended() {
+        assert_eq!(
+            parse("2024-01-01/.."),
+            Ok(IntervalFrom {
+                start: date(2024, 1, 1)
+            })
+        );
+        assert_eq!(
+            parse("../2024-01-01"),
+            Ok(IntervalTo {
+                end: date(2024, 1, 1)
+            })
+        );
+        assert_eq!(parse("../.."), Ok(IntervalFull));
+        assert_eq!(
+            parse::<IntervalFrom<NaiveDate>>("../2024-01-01"),
+            Err(IntervalError::MismatchedBounds.into())
+        );
+        assert_eq!(
+            parse::<IntervalFull>("2024-01-01/.."),
+            Err(IntervalError::MismatchedBounds.into())
+        );
+    }
+
+    #[test]
+    fn non_empty() {
+        assert_eq!(
+            parse::<NonEmpty<Interval<NaiveDate>>>("2024-01-01/P1D").map(NonEmpty::into_inner),
+            Ok(Interval {
+                start: date(2024, 1, 1),
+                end: date(2024, 1, 2),
+            })
+        );
+        assert_eq!(
+            parse::<NonEmpty<Interval<NaiveDate>>>("2024-01-01/P0D"),
+            Err(IntervalError::EmptyInterval.into())
+        );
+        assert_eq!(
+            parse::<NonEmpty<IntervalFull>>("../..").map(NonEmpty::into_inner),
+            Ok(IntervalFull)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let interval = Interval {
+            start: utc(1, 0, 0),
+            end: utc(1, 1, 30),
+        };
+
+        assert_eq!(
+            Iso8601(interval).to_string(),
+            "2024-01-01T00:00:00Z/2024-01-01T01:30:00Z"
+        );
+        assert_eq!(
+            Iso8601(NonEmpty::try_from(interval).unwrap()).to_string(),
+            "2024-01-01T00:00:00Z/2024-01-01T01:30:00Z"
+        );
+        assert_eq!(
+            Iso8601(IntervalTo {
+                end: interval.end.fixed_offset()
+            })
+            .to_string(),
+            "../2024-01-01T01:30:00+00:00"
+        );
+        assert_eq!(Iso8601(IntervalFull).to_string(), "../..");
+    }
+
+    #[test]
+    fn repeating() {
+        let repeating: RepeatingInterval<DateTime<Utc>> =
+            "R5/2024-01-01T00:00Z/P1D".parse().unwrap();
+
+        assert_eq!(repeating.repetitions, Some(5));
+        assert_eq!(repeating.to_string(), "R5/2024-01-01T00:00:00Z/P1D");
+
+        let occurrences: Vec<_> = repeating.into_iter().map(NonEmpty::into_inner).collect();
+        let expected: Vec<_> = (1..=5)
+            .map(|day| Interval {
+                start: utc(day, 0, 0),
+                end: utc(day + 1, 0, 0),
+            })
+            .collect();
+
+        assert_eq!(occurrences, expected);
+    }
+
+    #[test]
+    fn repeating_forms() {
+        let starts = |s: &str| -> Vec<NaiveDate> {
+            s.parse::<RepeatingInterval<NaiveDate>>()
+                .unwrap()
+                .into_iter()
+                .take(4)
+                .map(|interval| interval.into_inner().start)
+                .collect()
+        };
+
+        // Months are added to the start, rather than to the previous occurrence:
+        assert_eq!(
+            starts("R3/2024-01-31/P1M"),
+            [date(2024, 1, 31), date(2024, 2, 29), date(2024, 3, 31)]
+        );
+        assert_eq!(
+            starts("R2/2024-01-01/2024-01-03"),
+            [date(2024, 1, 1), date(2024, 1, 3)]
+        );
+        assert_eq!(
+            starts("R2/P1D/2024-01-10"),
+            [date(2024, 1, 9), date(2024, 1, 8)]
+        );
+        assert_eq!(starts("R/2024-01-01/P1W").len(), 4);
+        assert_eq!(starts("R0/2024-01-01/P1W").len(), 0);
+
+        assert_eq!(
+            "R2/2024-01-01/P0D".parse::<RepeatingInterval<NaiveDate>>(),
+            Err(IntervalError::EmptyInterval.into())
+        );
+        assert_eq!(
+            "R-1/2024-01-01/P1D".parse::<RepeatingInterval<NaiveDate>>(),
+            Err(Iso8601Error::InvalidRepetitions)
+        );
+        assert_eq!(
+            "R/2024-01-01/..".parse::<RepeatingInterval<NaiveDate>>(),
+            Err(Iso8601Error::InvalidEnd)
+        );
+    }
+
+    #[test]
+    fn repeating_overflow() {
+        let repeating: RepeatingInterval<NaiveDate> = "R/+262000-01-01/P1Y".parse().unwrap();
+
+        assert_eq!(repeating.into_iter().count(), 142);
+    }
+}
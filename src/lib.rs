@@ -123,12 +123,47 @@ extern crate core as std;
 #[cfg(all(test, feature = "std"))]
 extern crate std;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrow")]
+pub mod arrow;
 mod atomic;
+pub mod block;
 mod bounds;
+mod const_fn;
+pub mod cyclic;
+#[cfg(feature = "std")]
+pub mod dot;
 mod from_intervals;
+pub mod fuzzy;
+pub mod indu;
 mod interval;
+#[cfg(feature = "std")]
+pub mod iso8601;
+#[cfg(feature = "std")]
+pub mod non_convex;
 mod non_empty;
+#[cfg(feature = "std")]
+pub mod postgres;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "std")]
+pub mod qcn;
+pub mod rectangle;
 mod relation;
+pub mod semi_interval;
+#[cfg(feature = "std")]
+pub mod stn;
+#[cfg(feature = "svg")]
+pub mod svg;
+#[cfg(test)]
+mod test_helpers;
+#[cfg(feature = "std")]
+pub mod timeline;
+pub mod tolerance;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+pub mod uncertain;
 
 pub use self::{bounds::*, from_intervals::*, interval::*, non_empty::*, relation::*};
 
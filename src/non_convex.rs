@@ -0,0 +1,423 @@
+//! Relations between non-convex intervals (i.e. unions of disjoint intervals, such as recurring events).
+//!
+//! > Ladkin, P. B. (1986).
+//! > Time representation: A taxonomy of interval relations.
+//! > In AAAI-86 (pp. 360-366).
+//!
+//! The relation between two non-convex intervals is the matrix of the Allen relations
+//! between each of their components, which is summarized by quantifying over the components
+//! (e.g. "`s` is *always* before `t`", or "`s` is *mostly* during `t`").
+//!
+//! ```
+//! use allen_intervals::{
+//!     non_convex::{NonConvexInterval, NonConvexRelation},
+//!     Interval, NonEmpty, Relation,
+//! };
+//!
+//! let intervals = |intervals: &[(i32, i32)]| {
+//!     let components = intervals
+//!         .iter()
+//!         .map(|&(start, end)| NonEmpty::try_from(Interval { start, end }).unwrap())
+//!         .collect();
+//!
+//!     NonConvexInterval::new(components).unwrap()
+//! };
+//!
+//! // Monday and Tuesday meetings, during working days:
+//! let meetings = intervals(&[(10, 11), (34, 36)]);
+//! let working_days = intervals(&[(8, 17), (32, 41), (56, 65)]);
+//!
+//! let relation = NonConvexRelation::from_non_convex_intervals(&meetings, &working_days);
+//! assert_eq!(relation.get(1, 1), Some(Relation::Contains { is_inverted: true }));
+//!
+//! assert!(relation.mostly(Relation::Contains { is_inverted: true }));
+//! assert!(!relation.always(Relation::Contains { is_inverted: true }));
+//! assert_eq!(relation.to_string(), "mostly p, mostly d, sometimes pi");
+//! ```
+
+use std::fmt::{self, Display};
+
+use crate::{FromIntervals, IntervalError, NonEmpty, Relation, RelationSet, TryFromIntervals};
+
+/// Error type for non-convex intervals.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, thiserror::Error)]
+pub enum NonConvexError {
+    /// The interval has no components.
+    #[error("non-convex interval without components")]
+    NoComponents,
+    /// The component does not precede its successor
+    /// (i.e. the components are not in ascending order, or are not separated by gaps).
+    #[error("component {index} does not precede its successor")]
+    Unordered {
+        /// The (zero-based) index of the component.
+        index: usize,
+    },
+    /// The components' endpoints are not totally ordered.
+    #[error(transparent)]
+    Interval(#[from] IntervalError),
+}
+
+/// A non-convex interval, i.e. the union of disjoint (non-empty) intervals.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct NonConvexInterval<T> {
+    components: Vec<NonEmpty<T>>,
+}
+
+impl<T> NonConvexInterval<T> {
+    /// Creates a non-convex interval from its components,
+    /// or returns an error if there are none, or if they do not each precede their successor.
+    pub fn new(components: Vec<NonEmpty<T>>) -> Result<Self, NonConvexError>
+    where
+        Relation: TryFromIntervals<T, T>,
+    {
+        if components.is_empty() {
+            return Err(NonConvexError::NoComponents);
+        }
+
+        for (index, pair) in components.windows(2).enumerate() {
+            let relation = Relation::try_from_intervals(&pair[0], &pair[1])?;
+
+            if relation != (Relation::Precedes { is_inverted: false }) {
+                return Err(NonConvexError::Unordered { index });
+            }
+        }
+
+        Ok(Self { components })
+    }
+
+    /// Returns the interval's components, in ascending order.
+    #[inline]
+    pub fn components(&self) -> &[NonEmpty<T>] {
+        &self.components
+    }
+
+    /// Returns the interval's components, in ascending order.
+    #[inline]
+    pub fn into_components(self) -> Vec<NonEmpty<T>> {
+        self.components
+    }
+}
+
+/// A quantifier over the components of two non-convex intervals (e.g. `s` and `t`),
+/// stating for which of their pairs a relation holds.
+///
+/// The quantifiers are comparable (via `Ord`) by their strength,
+/// with a stronger one implying the weaker ones.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Quantifier {
+    /// The relation holds between some component of `s` and some component of `t`.
+    Sometimes,
+    /// The relation holds between each component of `s` and some component of `t`.
+    Mostly,
+    /// The relation holds between each component of `s` and each component of `t`.
+    Always,
+}
+
+impl Quantifier {
+    /// All quantifiers, in ascending order.
+    pub const ALL: [Self; 3] = [Self::Sometimes, Self::Mostly, Self::Always];
+}
+
+/// Formats as the quantifier's lowercase name (e.g. `mostly`).
+impl Display for Quantifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Sometimes => "sometimes",
+            Self::Mostly => "mostly",
+            Self::Always => "always",
+        })
+    }
+}
+
+/// The relation between two non-convex intervals (e.g. `s` and `t`),
+/// i.e. the matrix of Allen relations between each component of `s` (by row)
+/// and each component of `t` (by column).
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct NonConvexRelation {
+    relations: Vec<Relation>,
+    columns: usize,
+}
+
+impl NonConvexRelation {
+    /// Returns the relation between the non-convex intervals `s` and `t`.
+    pub fn from_non_convex_intervals<S, T>(
+        s: &NonConvexInterval<S>,
+        t: &NonConvexInterval<T>,
+    ) -> Self
+    where
+        Relation: FromIntervals<S, T>,
+    {
+        let relations = s
+            .components
+            .iter()
+            .flat_map(|s| {
+                t.components
+                    .iter()
+                    .map(move |t| Relation::from_intervals(s, t))
+            })
+            .collect();
+
+        Self {
+            relations,
+            columns: t.components.len(),
+        }
+    }
+
+    /// Returns the relation between the non-convex intervals `s` and `t`,
+    /// or an error if any of their endpoints are not totally ordered.
+    pub fn try_from_non_convex_intervals<S, T>(
+        s: &NonConvexInterval<S>,
+        t: &NonConvexInterval<T>,
+    ) -> Result<Self, IntervalError>
+    where
+        Relation: TryFromIntervals<S, T>,
+    {
+        let mut relations = Vec::with_capacity(s.components.len() * t.components.len());
+
+        for s in &s.components {
+            for t in &t.components {
+                relations.push(Relation::try_from_intervals(s, t)?);
+            }
+        }
+
+        Ok(Self {
+            relations,
+            columns: t.components.len(),
+        })
+    }
+
+    /// Returns the number of rows (i.e. of components of `s`).
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.relations.len() / self.columns
+    }
+
+    /// Returns the number of columns (i.e. of components of `t`).
+    #[inline]
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the relation between the `row`-th component of `s` and the `column`-th component of `t`,
+    /// or `None` if either is out of bounds.
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> Option<Relation> {
+        if column >= self.columns {
+            return None;
+        }
+
+        self.relations.get(row * self.columns + column).copied()
+    }
+
+    /// Returns the relations between the `row`-th component of `s` and each component of `t`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn row(&self, row: usize) -> Option<&[Relation]> {
+        self.relations.chunks(self.columns).nth(row)
+    }
+
+    /// Returns the relation's converse (i.e. the transposed matrix of the components' converse relations).
+    pub fn as_converse(&self) -> Self {
+        let rows = self.rows();
+        let relations = (0..self.columns)
+            .flat_map(|column| {
+                (0..rows).map(move |row| self.relations[row * self.columns + column].as_converse())
+            })
+            .collect();
+
+        Self {
+            relations,
+            columns: rows,
+        }
+    }
+
+    /// Returns the Allen relations between any of the components.
+    pub fn relations(&self) -> RelationSet {
+        self.relations.iter().copied().collect()
+    }
+
+    /// Returns `true` iff any of `relations` holds between the components as quantified by `quantifier`.
+    pub fn holds(&self, quantifier: Quantifier, relations: impl Into<RelationSet>) -> bool {
+        let relations = relations.into();
+
+        match quantifier {
+            Quantifier::Sometimes => self.relations.iter().any(|r| relations.contains(*r)),
+            Quantifier::Mostly => self
+                .relations
+                .chunks(self.columns)
+                .all(|row| row.iter().any(|r| relations.contains(*r))),
+            Quantifier::Always => self.relations.iter().all(|r| relations.contains(*r)),
+        }
+    }
+
+    /// Returns `true` iff any of `relations` holds between some component of `s` and some component of `t`.
+    #[inline]
+    pub fn sometimes(&self, relations: impl Into<RelationSet>) -> bool {
+        self.holds(Quantifier::Sometimes, relations)
+    }
+
+    /// Returns `true` iff any of `relations` holds between each component of `s` and some component of `t`.
+    #[inline]
+    pub fn mostly(&self, relations: impl Into<RelationSet>) -> bool {
+        self.holds(Quantifier::Mostly, relations)
+    }
+
+    /// Returns `true` iff any of `relations` holds between each component of `s` and each component of `t`.
+    #[inline]
+    pub fn always(&self, relations: impl Into<RelationSet>) -> bool {
+        self.holds(Quantifier::Always, relations)
+    }
+
+    /// Returns the strongest quantifier by which `relation` holds between the components, if any.
+    pub fn quantifier(&self, relation: Relation) -> Option<Quantifier> {
+        Quantifier::ALL
+            .into_iter()
+            .rev()
+            .find(|quantifier| self.holds(*quantifier, relation))
+    }
+
+    /// Returns each Allen relation holding between any of the components,
+    /// along with the strongest quantifier by which it holds, ordered by the relations.
+    pub fn summary(&self) -> impl Iterator<Item = (Quantifier, Relation)> + '_ {
+        self.relations()
+            .into_iter()
+            .filter_map(|relation| Some((self.quantifier(relation)?, relation)))
+    }
+}
+
+/// Formats as the summary's comma-separated quantified relations
+/// in their abbreviations (e.g. `mostly d, sometimes p`),
+/// or in their full names (e.g. `mostly is contained by, sometimes precedes`)
+/// for the alternate (`{:#}`) format.
+impl Display for NonConvexRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (quantifier, relation)) in self.summary().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            if f.alternate() {
+                write!(f, "{quantifier} {relation:#}")?;
+            } else {
+                write!(f, "{quantifier} {relation}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::set;
+    use crate::{Interval, IntervalFrom};
+
+    fn intervals(intervals: &[(i32, i32)]) -> NonConvexInterval<Interval<i32>> {
+        let components = intervals
+            .iter()
+            .map(|&(start, end)| NonEmpty::try_from(Interval { start, end }).unwrap())
+            .collect();
+
+        NonConvexInterval::new(components).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let interval = |start, end| NonEmpty::try_from(Interval { start, end }).unwrap();
+
+        assert_eq!(
+            NonConvexInterval::<Interval<i32>>::new(vec![]),
+            Err(NonConvexError::NoComponents)
+        );
+        assert_eq!(
+            NonConvexInterval::new(vec![interval(0, 2), interval(2, 4)]),
+            Err(NonConvexError::Unordered { index: 0 })
+        );
+        assert_eq!(
+            NonConvexInterval::new(vec![interval(0, 2), interval(6, 8), interval(4, 5)]),
+            Err(NonConvexError::Unordered { index: 1 })
+        );
+        assert_eq!(
+            NonConvexInterval::new(vec![
+                NonEmpty::from(IntervalFrom { start: 0.0 }),
+                NonEmpty::from(IntervalFrom { start: f64::NAN }),
+            ]),
+            Err(NonConvexError::Interval(IntervalError::AmbiguousOrder))
+        );
+    }
+
+    #[test]
+    fn matrix() {
+        let s = intervals(&[(0, 2), (4, 6)]);
+        let t = intervals(&[(1, 3), (4, 6), (8, 9)]);
+
+        let relation = NonConvexRelation::from_non_convex_intervals(&s, &t);
+
+        assert_eq!((relation.rows(), relation.columns()), (2, 3));
+        assert_eq!(
+            relation.row(0).unwrap(),
+            ["o", "p", "p"].map(|r| r.parse::<Relation>().unwrap())
+        );
+        assert_eq!(relation.get(1, 1), Some(Relation::Equals));
+        assert_eq!(relation.get(1, 3), None);
+        assert_eq!(relation.get(2, 0), None);
+        assert_eq!(relation.row(2), None);
+        assert_eq!(relation.relations(), set("{p, o, eq, pi}"));
+
+        let converse = NonConvexRelation::from_non_convex_intervals(&t, &s);
+
+        assert_eq!(relation.as_converse(), converse);
+        assert_eq!(converse.get(0, 1), Some("p".parse().unwrap()));
+        assert_eq!(
+            NonConvexRelation::try_from_non_convex_intervals(&s, &t),
+            Ok(relation)
+        );
+    }
+
+    #[test]
+    fn quantifiers() {
+        let s = intervals(&[(0, 2), (4, 6)]);
+        let t = intervals(&[(1, 3), (4, 6), (8, 9)]);
+        let u = intervals(&[(10, 12)]);
+
+        let relation = NonConvexRelation::from_non_convex_intervals(&s, &t);
+
+        assert!(relation.sometimes(Relation::Equals));
+        assert!(!relation.mostly(Relation::Equals));
+        assert!(relation.mostly(set("{o, eq}")));
+        assert!(relation.mostly(Relation::Precedes { is_inverted: false }));
+        assert!(!relation.always(Relation::Precedes { is_inverted: false }));
+        assert!(relation.always(set("{p, o, eq, pi}")));
+        assert!(!relation.sometimes(set("{}")));
+
+        assert_eq!(
+            relation.quantifier(Relation::Precedes { is_inverted: false }),
+            Some(Quantifier::Mostly)
+        );
+        assert_eq!(
+            relation.quantifier(Relation::Equals),
+            Some(Quantifier::Sometimes)
+        );
+        assert_eq!(
+            relation.quantifier(Relation::Meets { is_inverted: false }),
+            None
+        );
+
+        let relation = NonConvexRelation::from_non_convex_intervals(&s, &u);
+
+        assert!(relation.always(Relation::Precedes { is_inverted: false }));
+        assert_eq!(relation.to_string(), "always p");
+        assert_eq!(format!("{relation:#}"), "always precedes");
+    }
+
+    #[test]
+    fn mixed_types() {
+        let s = intervals(&[(0, 2), (4, 6)]);
+        let t = NonConvexInterval::new(vec![NonEmpty::from(IntervalFrom { start: 5 })]).unwrap();
+
+        let relation = NonConvexRelation::from_non_convex_intervals(&s, &t);
+
+        assert_eq!(relation.to_string(), "sometimes p, sometimes o");
+    }
+}
@@ -1,9 +1,42 @@
-use core::cmp::Ordering;
+use core::{
+    cmp::Ordering,
+    ops::{Add, Mul, Sub},
+};
 
-use crate::{Interval, IntervalError, IntervalFrom, IntervalFull, IntervalTo};
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, Num, SaturatingAdd, SaturatingMul, SaturatingSub, Zero,
+};
+
+use crate::{
+    Bound, Interval, IntervalBounds, IntervalError, IntervalFrom, IntervalFull, IntervalTo,
+};
 
 /// An interval that is known not to be empty.
 ///
+/// # Constants
+///
+/// For primitive integer endpoints non-empty intervals can be created, and classified, in `const` contexts,
+/// with [`NonEmpty::<Interval<T>>::from_literal`](NonEmpty::<Interval<i32>>::from_literal)
+/// failing to compile for empty intervals:
+///
+/// ```
+/// use allen_intervals::{Interval, NonEmpty, Relation};
+///
+/// const MORNING: NonEmpty<Interval<u16>> = NonEmpty::<Interval<u16>>::from_literal::<480, 720>();
+/// const AFTERNOON: NonEmpty<Interval<u16>> = NonEmpty::<Interval<u16>>::from_literal::<720, 1020>();
+///
+/// const _: () = assert!(MORNING.const_meets(&AFTERNOON));
+/// const RELATION: Relation = AFTERNOON.relation(&MORNING);
+///
+/// assert_eq!(RELATION, Relation::Meets { is_inverted: true });
+/// ```
+///
+/// ```compile_fail
+/// use allen_intervals::{Interval, NonEmpty};
+///
+/// const EMPTY: NonEmpty<Interval<u16>> = NonEmpty::<Interval<u16>>::from_literal::<720, 720>();
+/// ```
+///
 /// # Layout
 ///
 /// `NonEmpty<T>` is guaranteed to have the same layout and bit validity as `T`
@@ -20,11 +53,396 @@ impl<T> NonEmpty<T> {
     ///
     /// The value must not be empty.
     #[inline]
-    pub unsafe fn new_unchecked(value: T) -> Self {
+    pub const unsafe fn new_unchecked(value: T) -> Self {
         Self(value)
     }
+
+    /// Returns a reference to the wrapped interval.
+    #[inline]
+    pub const fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the wrapped interval.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Returns the gap between `self` and `other`, if they are disjoint.
+    ///
+    /// Returns `Some(zero)` if the intervals meet,
+    /// and `None` if they overlap, or are otherwise not disjoint.
+    #[inline]
+    pub fn distance<U, P, D>(&self, other: &NonEmpty<U>) -> Option<D>
+    where
+        T: IntervalBounds<P>,
+        U: IntervalBounds<P>,
+        P: PartialOrd + Sub<Output = D>,
+    {
+        let (end, start) = self.gap_bounds(other)?;
+        Some(start - end)
+    }
+
+    /// Returns the gap between `self` and `other`, if they are disjoint,
+    /// or `None` if the computation overflowed.
+    ///
+    /// Returns `Some(zero)` if the intervals meet,
+    /// and `None` if they overlap, or are otherwise not disjoint.
+    #[inline]
+    pub fn checked_distance<U, P>(&self, other: &NonEmpty<U>) -> Option<P>
+    where
+        T: IntervalBounds<P>,
+        U: IntervalBounds<P>,
+        P: PartialOrd + CheckedSub,
+    {
+        let (end, start) = self.gap_bounds(other)?;
+        start.checked_sub(&end)
+    }
+
+    /// Returns the gap between `self` and `other`, if they are disjoint,
+    /// saturating at the numeric bounds instead of overflowing.
+    ///
+    /// Returns `Some(zero)` if the intervals meet,
+    /// and `None` if they overlap, or are otherwise not disjoint.
+    #[inline]
+    pub fn saturating_distance<U, P>(&self, other: &NonEmpty<U>) -> Option<P>
+    where
+        T: IntervalBounds<P>,
+        U: IntervalBounds<P>,
+        P: PartialOrd + SaturatingSub,
+    {
+        let (end, start) = self.gap_bounds(other)?;
+        Some(start.saturating_sub(&end))
+    }
+
+    /// Returns the end of the earlier and the start of the later interval,
+    /// if the intervals are disjoint.
+    #[inline]
+    fn gap_bounds<U, P>(&self, other: &NonEmpty<U>) -> Option<(P, P)>
+    where
+        T: IntervalBounds<P>,
+        U: IntervalBounds<P>,
+        P: PartialOrd,
+    {
+        if let (Bound::Bounded(end), Bound::Bounded(start)) =
+            (self.end_bound(), other.start_bound())
+        {
+            if end <= start {
+                return Some((end, start));
+            }
+        }
+
+        if let (Bound::Bounded(end), Bound::Bounded(start)) =
+            (other.end_bound(), self.start_bound())
+        {
+            if end <= start {
+                return Some((end, start));
+            }
+        }
+
+        None
+    }
 }
 
+impl<T> NonEmpty<Interval<T>> {
+    /// Returns the length of the interval (i.e. `end - start`).
+    #[inline]
+    pub fn length<D>(&self) -> D
+    where
+        T: Copy + Sub<Output = D>,
+    {
+        self.0.length()
+    }
+
+    /// Returns the length of the interval,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_length(&self) -> Option<T>
+    where
+        T: CheckedSub,
+    {
+        self.0.checked_length()
+    }
+
+    /// Returns the length of the interval,
+    /// saturating at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_length(&self) -> T
+    where
+        T: SaturatingSub,
+    {
+        self.0.saturating_length()
+    }
+
+    /// Returns the point halfway between the interval's start and end.
+    #[inline]
+    pub fn midpoint<D>(&self) -> T
+    where
+        T: Copy + Add<D, Output = T> + Sub<Output = D>,
+        D: Num,
+    {
+        self.0.midpoint()
+    }
+
+    /// Returns the point halfway between the interval's start and end,
+    /// or `None` if the computation overflowed.
+    #[inline]
+    pub fn checked_midpoint(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd + CheckedAdd + Num,
+    {
+        self.0.checked_midpoint()
+    }
+
+    /// Returns the interval shifted by `offset`,
+    /// or `None` if the result is empty (e.g. due to rounding).
+    #[inline]
+    pub fn shift<D>(&self, offset: D) -> Option<Self>
+    where
+        T: Copy + PartialOrd + Add<D, Output = T>,
+        D: Copy,
+    {
+        Self::try_from(self.0.shift(offset)).ok()
+    }
+
+    /// Returns the interval shifted by `offset`,
+    /// or `None` if the computation overflowed.
+    ///
+    /// Shifting a non-empty interval by an exact (i.e. checked) offset
+    /// always results in a non-empty interval.
+    #[inline]
+    pub fn checked_shift(&self, offset: T) -> Option<Self>
+    where
+        T: CheckedAdd,
+    {
+        self.0.checked_shift(offset).map(Self)
+    }
+
+    /// Returns the interval shifted by `offset`,
+    /// saturating at the numeric bounds instead of overflowing,
+    /// or `None` if the result is empty (i.e. if both endpoints saturated).
+    #[inline]
+    pub fn saturating_shift(&self, offset: T) -> Option<Self>
+    where
+        T: PartialOrd + SaturatingAdd,
+    {
+        Self::try_from(self.0.saturating_shift(offset)).ok()
+    }
+
+    /// Returns the interval scaled by `factor` around `anchor`,
+    /// or `None` if the result is empty (e.g. for a non-positive `factor`).
+    #[inline]
+    pub fn scale<D, F>(&self, anchor: T, factor: F) -> Option<Self>
+    where
+        T: Copy + PartialOrd + Add<D, Output = T> + Sub<D, Output = T> + Sub<Output = D>,
+        D: Mul<F, Output = D>,
+        F: Copy,
+    {
+        Self::try_from(self.0.scale(anchor, factor)).ok()
+    }
+
+    /// Returns the interval scaled by a positive `factor` around `anchor`,
+    /// or `None` if the computation overflowed, or `factor` is not positive.
+    ///
+    /// Scaling a non-empty interval by an exact (i.e. checked) positive factor
+    /// always results in a non-empty interval.
+    #[inline]
+    pub fn checked_scale(&self, anchor: T, factor: T) -> Option<Self>
+    where
+        T: PartialOrd + CheckedAdd + CheckedSub + CheckedMul + Zero,
+    {
+        if factor <= T::zero() {
+            return None;
+        }
+
+        self.0.checked_scale(anchor, factor).map(Self)
+    }
+
+    /// Returns the interval scaled by `factor` around `anchor`,
+    /// saturating at the numeric bounds instead of overflowing,
+    /// or `None` if the result is empty (e.g. for a non-positive `factor`).
+    #[inline]
+    pub fn saturating_scale(&self, anchor: T, factor: T) -> Option<Self>
+    where
+        T: PartialOrd + SaturatingAdd + SaturatingSub + SaturatingMul,
+    {
+        Self::try_from(self.0.saturating_scale(anchor, factor)).ok()
+    }
+
+    /// Returns the interval expanded by `margin` on both ends,
+    /// or `None` if the result is empty (e.g. for a negative `margin`).
+    #[inline]
+    pub fn expand<D>(&self, margin: D) -> Option<Self>
+    where
+        T: Copy + PartialOrd + Add<D, Output = T> + Sub<D, Output = T>,
+        D: Copy,
+    {
+        Self::try_from(self.0.expand(margin)).ok()
+    }
+
+    /// Returns the interval expanded by a non-negative `margin` on both ends,
+    /// or `None` if the computation overflowed, or `margin` is negative.
+    ///
+    /// Expanding a non-empty interval by an exact (i.e. checked) non-negative margin
+    /// always results in a non-empty interval.
+    #[inline]
+    pub fn checked_expand(&self, margin: T) -> Option<Self>
+    where
+        T: PartialOrd + CheckedAdd + CheckedSub + Zero,
+    {
+        if margin < T::zero() {
+            return None;
+        }
+
+        self.0.checked_expand(margin).map(Self)
+    }
+
+    /// Returns the interval expanded by `margin` on both ends,
+    /// saturating at the numeric bounds instead of overflowing,
+    /// or `None` if the result is empty (e.g. for a negative `margin`).
+    #[inline]
+    pub fn saturating_expand(&self, margin: T) -> Option<Self>
+    where
+        T: PartialOrd + SaturatingAdd + SaturatingSub,
+    {
+        Self::try_from(self.0.saturating_expand(margin)).ok()
+    }
+
+    /// Returns the interval shrunk by `margin` on both ends,
+    /// or `None` if the result is empty.
+    #[inline]
+    pub fn shrink<D>(&self, margin: D) -> Option<Self>
+    where
+        T: Copy + PartialOrd + Add<D, Output = T> + Sub<D, Output = T>,
+        D: Copy,
+    {
+        Self::try_from(self.0.shrink(margin)).ok()
+    }
+
+    /// Returns the interval shrunk by `margin` on both ends,
+    /// or `None` if the computation overflowed, or the result is empty.
+    #[inline]
+    pub fn checked_shrink(&self, margin: T) -> Option<Self>
+    where
+        T: PartialOrd + CheckedAdd + CheckedSub,
+    {
+        self.0
+            .checked_shrink(margin)
+            .and_then(|interval| Self::try_from(interval).ok())
+    }
+
+    /// Returns the interval shrunk by `margin` on both ends,
+    /// saturating at the numeric bounds instead of overflowing,
+    /// or `None` if the result is empty.
+    #[inline]
+    pub fn saturating_shrink(&self, margin: T) -> Option<Self>
+    where
+        T: PartialOrd + SaturatingAdd + SaturatingSub,
+    {
+        Self::try_from(self.0.saturating_shrink(margin)).ok()
+    }
+}
+
+// Half-bounded intervals are never empty,
+// so all of their measures preserve non-emptiness.
+
+macro_rules! half_bounded_measures_impl {
+    ($t:ty) => {
+        impl<T> NonEmpty<$t> {
+            /// Returns the interval shifted by `offset`.
+            #[inline]
+            pub fn shift<D>(&self, offset: D) -> Self
+            where
+                T: Copy + Add<D, Output = T>,
+            {
+                Self(self.0.shift(offset))
+            }
+
+            /// Returns the interval shifted by `offset`,
+            /// or `None` if the computation overflowed.
+            #[inline]
+            pub fn checked_shift(&self, offset: T) -> Option<Self>
+            where
+                T: CheckedAdd,
+            {
+                self.0.checked_shift(offset).map(Self)
+            }
+
+            /// Returns the interval shifted by `offset`,
+            /// saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub fn saturating_shift(&self, offset: T) -> Self
+            where
+                T: SaturatingAdd,
+            {
+                Self(self.0.saturating_shift(offset))
+            }
+
+            /// Returns the interval expanded by `margin` on its bounded end.
+            #[inline]
+            pub fn expand<D>(&self, margin: D) -> Self
+            where
+                T: Copy + Add<D, Output = T> + Sub<D, Output = T>,
+            {
+                Self(self.0.expand(margin))
+            }
+
+            /// Returns the interval expanded by `margin` on its bounded end,
+            /// or `None` if the computation overflowed.
+            #[inline]
+            pub fn checked_expand(&self, margin: T) -> Option<Self>
+            where
+                T: CheckedAdd + CheckedSub,
+            {
+                self.0.checked_expand(margin).map(Self)
+            }
+
+            /// Returns the interval expanded by `margin` on its bounded end,
+            /// saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub fn saturating_expand(&self, margin: T) -> Self
+            where
+                T: SaturatingAdd + SaturatingSub,
+            {
+                Self(self.0.saturating_expand(margin))
+            }
+
+            /// Returns the interval shrunk by `margin` on its bounded end.
+            #[inline]
+            pub fn shrink<D>(&self, margin: D) -> Self
+            where
+                T: Copy + Add<D, Output = T> + Sub<D, Output = T>,
+            {
+                Self(self.0.shrink(margin))
+            }
+
+            /// Returns the interval shrunk by `margin` on its bounded end,
+            /// or `None` if the computation overflowed.
+            #[inline]
+            pub fn checked_shrink(&self, margin: T) -> Option<Self>
+            where
+                T: CheckedAdd + CheckedSub,
+            {
+                self.0.checked_shrink(margin).map(Self)
+            }
+
+            /// Returns the interval shrunk by `margin` on its bounded end,
+            /// saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub fn saturating_shrink(&self, margin: T) -> Self
+            where
+                T: SaturatingAdd + SaturatingSub,
+            {
+                Self(self.0.saturating_shrink(margin))
+            }
+        }
+    };
+}
+
+half_bounded_measures_impl!(IntervalFrom<T>);
+half_bounded_measures_impl!(IntervalTo<T>);
+
 impl<T> TryFrom<Interval<T>> for NonEmpty<Interval<T>>
 where
     T: PartialOrd,
@@ -68,9 +486,9 @@ mod tests {
 
     #[test]
     fn non_empty() {
-        let min = isize::min_value();
+        let min = isize::MIN;
         let mid = 0;
-        let max = isize::max_value();
+        let max = isize::MAX;
 
         assert_eq!(
             NonEmpty::try_from(Interval {
@@ -130,4 +548,138 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn measures() {
+        let interval: NonEmpty<_> = Interval { start: 2, end: 8 }.try_into().unwrap();
+
+        assert_eq!(interval.length(), 6);
+        assert_eq!(interval.midpoint(), 5);
+
+        assert_eq!(
+            interval.checked_shift(2),
+            Some(NonEmpty(Interval { start: 4, end: 10 }))
+        );
+        assert_eq!(
+            interval.checked_scale(2, 2),
+            Some(NonEmpty(Interval { start: 2, end: 14 }))
+        );
+        assert_eq!(interval.checked_scale(2, 0), None);
+        assert_eq!(
+            interval.checked_expand(1),
+            Some(NonEmpty(Interval { start: 1, end: 9 }))
+        );
+        assert_eq!(interval.checked_expand(-1), None);
+        assert_eq!(
+            interval.checked_shrink(2),
+            Some(NonEmpty(Interval { start: 4, end: 6 }))
+        );
+        assert_eq!(interval.checked_shrink(3), None);
+
+        assert_eq!(
+            interval.shift(-2),
+            Some(NonEmpty(Interval { start: 0, end: 6 }))
+        );
+        assert_eq!(
+            interval.scale(2, 2),
+            Some(NonEmpty(Interval { start: 2, end: 14 }))
+        );
+        assert_eq!(interval.scale(2, -1), None);
+        assert_eq!(
+            interval.expand(1),
+            Some(NonEmpty(Interval { start: 1, end: 9 }))
+        );
+        assert_eq!(interval.expand(-3), None);
+        assert_eq!(
+            interval.shrink(2),
+            Some(NonEmpty(Interval { start: 4, end: 6 }))
+        );
+        assert_eq!(interval.shrink(3), None);
+
+        let interval: NonEmpty<_> = Interval {
+            start: 250_u8,
+            end: 254,
+        }
+        .try_into()
+        .unwrap();
+
+        assert_eq!(
+            interval.saturating_shift(3),
+            Some(NonEmpty(Interval {
+                start: 253,
+                end: 255
+            }))
+        );
+        assert_eq!(interval.saturating_shift(6), None);
+        assert_eq!(
+            interval.saturating_scale(250, 2),
+            Some(NonEmpty(Interval {
+                start: 250,
+                end: 255
+            }))
+        );
+        assert_eq!(
+            interval.saturating_expand(10),
+            Some(NonEmpty(Interval {
+                start: 240,
+                end: 255
+            }))
+        );
+        assert_eq!(interval.saturating_shrink(2), None);
+
+        let interval: NonEmpty<_> = Interval {
+            start: 1e16,
+            end: 1e16 + 2.0,
+        }
+        .try_into()
+        .unwrap();
+
+        // Shifting by a huge offset rounds both endpoints to the same value:
+        assert_eq!(interval.shift(1e32), None);
+
+        let interval = NonEmpty::from(IntervalFrom { start: 250_u8 });
+
+        assert_eq!(interval.shift(2), NonEmpty(IntervalFrom { start: 252 }));
+        assert_eq!(interval.checked_shift(6), None);
+        assert_eq!(
+            interval.saturating_shift(6),
+            NonEmpty(IntervalFrom { start: 255 })
+        );
+        assert_eq!(interval.expand(50), NonEmpty(IntervalFrom { start: 200 }));
+
+        let interval = NonEmpty::from(IntervalTo { end: 5_u8 });
+
+        assert_eq!(interval.expand(2), NonEmpty(IntervalTo { end: 7 }));
+        assert_eq!(interval.checked_shrink(6), None);
+        assert_eq!(
+            interval.saturating_shrink(6),
+            NonEmpty(IntervalTo { end: 0 })
+        );
+    }
+
+    #[test]
+    fn distance() {
+        let s: NonEmpty<_> = Interval { start: 1, end: 4 }.try_into().unwrap();
+        let t: NonEmpty<_> = Interval { start: 6, end: 9 }.try_into().unwrap();
+        let u: NonEmpty<_> = Interval { start: 3, end: 7 }.try_into().unwrap();
+
+        assert_eq!(s.distance(&t), Some(2));
+        assert_eq!(t.distance(&s), Some(2));
+        assert_eq!(s.distance(&u), None);
+
+        let from = NonEmpty::from(IntervalFrom { start: 4 });
+        let to = NonEmpty::from(IntervalTo { end: 1 });
+
+        assert_eq!(s.distance(&from), Some(0));
+        assert_eq!(from.distance(&to), Some(3));
+        assert_eq!(to.distance(&from), Some(3));
+        assert_eq!(s.distance(&NonEmpty::from(IntervalFull)), None);
+
+        let to = NonEmpty::from(IntervalTo { end: i8::MIN });
+        let from = NonEmpty::from(IntervalFrom { start: i8::MAX });
+
+        assert_eq!(to.saturating_distance(&from), Some(i8::MAX));
+        assert_eq!(to.checked_distance(&from), None);
+        assert_eq!(s.checked_distance(&t), Some(2));
+    }
 }
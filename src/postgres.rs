@@ -0,0 +1,809 @@
+//! Interoperability with [PostgreSQL's range types][range-types]
+//! (e.g. `int4range`, `int8range`, `tsrange`, `tstzrange` and `daterange`).
+//!
+//! [`PgRange<T>`] parses and formats PostgreSQL's range text literals
+//! (e.g. `[1,5)`, `(,10]` or `empty`) and converts them to and from [`Bounds<T>`].
+//!
+//! With the `postgres-types` feature enabled [`PgRange<T>`] also implements
+//! [`FromSql`](postgres_types::FromSql) and [`ToSql`](postgres_types::ToSql)
+//! for PostgreSQL's binary wire format
+//! (including `daterange`, `tsrange` and `tstzrange`, if the `chrono` feature is enabled as well).
+//!
+//! ```
+//! use allen_intervals::{postgres::PgRange, Interval, NonEmpty};
+//!
+//! let range: PgRange<i32> = "[1,5]".parse().unwrap();
+//! let bounds = range.into_discrete_bounds().unwrap().unwrap();
+//!
+//! let interval: NonEmpty<_> = Interval::try_from(bounds).unwrap().try_into().unwrap();
+//! assert_eq!(interval.into_inner(), Interval { start: 1, end: 6 });
+//! ```
+//!
+//! [range-types]: https://www.postgresql.org/docs/current/rangetypes.html
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Write as _},
+    str::FromStr,
+};
+
+use crate::{Bound, Bounds};
+
+/// Error type for parsing and converting PostgreSQL range values.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, thiserror::Error)]
+pub enum PgRangeError {
+    /// The range literal is missing its opening `[` or `(`.
+    #[error("missing left parenthesis or bracket")]
+    MissingLowerBracket,
+    /// The range literal is missing its closing `]` or `)`.
+    #[error("missing right parenthesis or bracket")]
+    MissingUpperBracket,
+    /// The range literal is missing the comma separating its bounds.
+    #[error("missing comma after lower bound")]
+    MissingComma,
+    /// The range literal contains more than one comma outside of quotes.
+    #[error("too many commas")]
+    TooManyCommas,
+    /// The range literal contains an unterminated quoted bound.
+    #[error("unterminated quoted bound")]
+    UnterminatedQuote,
+    /// A bound value could not be parsed.
+    #[error("invalid bound value")]
+    InvalidValue,
+    /// The range's lower bound is greater than its upper bound.
+    #[error("range lower bound must be less than or equal to range upper bound")]
+    InvertedBounds,
+    /// Could not obtain total order.
+    #[error("could not obtain total order")]
+    AmbiguousOrder,
+    /// Canonicalizing a bound overflowed the element type.
+    #[error("bound out of range")]
+    Overflow,
+    /// A bound value was `NULL`.
+    #[error("unexpected null bound")]
+    NullValue,
+}
+
+/// An endpoint of a PostgreSQL range.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum PgBound<T> {
+    /// An inclusive bound (i.e. `[` or `]`).
+    Inclusive(T),
+    /// An exclusive bound (i.e. `(` or `)`).
+    Exclusive(T),
+    /// An infinite bound.
+    Unbounded,
+}
+
+impl<T> PgBound<T> {
+    fn value(&self) -> Option<&T> {
+        match self {
+            Self::Inclusive(value) | Self::Exclusive(value) => Some(value),
+            Self::Unbounded => None,
+        }
+    }
+
+    fn into_bound(self) -> Bound<T> {
+        match self {
+            Self::Inclusive(value) | Self::Exclusive(value) => Bound::Bounded(value),
+            Self::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
+/// A PostgreSQL range value.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum PgRange<T> {
+    /// The empty range (i.e. `empty`).
+    Empty,
+    /// A non-empty range.
+    NonEmpty {
+        /// The lower bound of the range.
+        lower: PgBound<T>,
+        /// The upper bound of the range.
+        upper: PgBound<T>,
+    },
+}
+
+/// Element types of discrete PostgreSQL ranges (e.g. `int4range`, `int8range` or `daterange`).
+pub trait Discrete: Sized {
+    /// Returns the value immediately following `self`,
+    /// or `None` if there is no such value.
+    fn successor(&self) -> Option<Self>;
+}
+
+macro_rules! discrete_impl {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                #[inline]
+                fn successor(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+            }
+        )*
+    };
+}
+
+discrete_impl!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "chrono")]
+impl Discrete for chrono::NaiveDate {
+    #[inline]
+    fn successor(&self) -> Option<Self> {
+        self.succ_opt()
+    }
+}
+
+impl<T> PgRange<T> {
+    /// Creates a range from its bounds, normalizing it the way PostgreSQL does.
+    ///
+    /// Returns an empty range if both bounds are equal and at least one of them is exclusive.
+    pub fn new(lower: PgBound<T>, upper: PgBound<T>) -> Result<Self, PgRangeError>
+    where
+        T: PartialOrd,
+    {
+        if let (Some(lower_value), Some(upper_value)) = (lower.value(), upper.value()) {
+            match lower_value.partial_cmp(upper_value) {
+                Some(Ordering::Less) => {}
+                Some(Ordering::Equal) => {
+                    if !matches!(
+                        (&lower, &upper),
+                        (PgBound::Inclusive(_), PgBound::Inclusive(_))
+                    ) {
+                        return Ok(Self::Empty);
+                    }
+                }
+                Some(Ordering::Greater) => return Err(PgRangeError::InvertedBounds),
+                None => return Err(PgRangeError::AmbiguousOrder),
+            }
+        }
+
+        Ok(Self::NonEmpty { lower, upper })
+    }
+
+    /// Returns `true` iff the range is empty.
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    /// Returns the range in its canonical form (i.e. `[lower,upper)`),
+    /// as PostgreSQL does for discrete range types.
+    pub fn canonicalize(self) -> Result<Self, PgRangeError>
+    where
+        T: Discrete + PartialOrd,
+    {
+        let Self::NonEmpty { lower, upper } = self else {
+            return Ok(Self::Empty);
+        };
+
+        let lower = match lower {
+            PgBound::Exclusive(value) => {
+                PgBound::Inclusive(value.successor().ok_or(PgRangeError::Overflow)?)
+            }
+            bound => bound,
+        };
+
+        let upper = match upper {
+            PgBound::Inclusive(value) => {
+                PgBound::Exclusive(value.successor().ok_or(PgRangeError::Overflow)?)
+            }
+            bound => bound,
+        };
+
+        Self::new(lower, upper)
+    }
+
+    /// Returns the bounds of a range over a discrete domain,
+    /// or `None` if the range is empty.
+    ///
+    /// The range gets canonicalized first, matching the crate's
+    /// treatment of discrete intervals as having an exclusive end.
+    pub fn into_discrete_bounds(self) -> Result<Option<Bounds<T>>, PgRangeError>
+    where
+        T: Discrete + PartialOrd,
+    {
+        Ok(self.canonicalize()?.into_bounds())
+    }
+
+    /// Returns the bounds of a range over a continuous domain,
+    /// or `None` if the range is empty.
+    ///
+    /// The bounds' inclusivity gets dropped, matching the crate's
+    /// treatment of continuous endpoints as infinitesimally short.
+    pub fn into_continuous_bounds(self) -> Option<Bounds<T>> {
+        self.into_bounds()
+    }
+
+    /// Creates a range (i.e. `[start,end)`) from the bounds of an interval over a discrete domain.
+    pub fn from_discrete_bounds(bounds: Bounds<T>) -> Self {
+        Self::NonEmpty {
+            lower: Self::bound(bounds.start, PgBound::Inclusive),
+            upper: Self::bound(bounds.end, PgBound::Exclusive),
+        }
+    }
+
+    /// Creates a range (i.e. `[start,end]`) from the bounds of an interval over a continuous domain.
+    pub fn from_continuous_bounds(bounds: Bounds<T>) -> Self {
+        Self::NonEmpty {
+            lower: Self::bound(bounds.start, PgBound::Inclusive),
+            upper: Self::bound(bounds.end, PgBound::Inclusive),
+        }
+    }
+
+    fn into_bounds(self) -> Option<Bounds<T>> {
+        match self {
+            Self::Empty => None,
+            Self::NonEmpty { lower, upper } => Some(Bounds {
+                start: lower.into_bound(),
+                end: upper.into_bound(),
+            }),
+        }
+    }
+
+    fn bound(bound: Bound<T>, bounded: fn(T) -> PgBound<T>) -> PgBound<T> {
+        match bound {
+            Bound::Bounded(value) => bounded(value),
+            Bound::Unbounded => PgBound::Unbounded,
+        }
+    }
+}
+
+impl<T> FromStr for PgRange<T>
+where
+    T: FromStr + PartialOrd,
+{
+    type Err = PgRangeError;
+
+    /// Parses a range literal, as accepted by PostgreSQL.
+    ///
+    /// Unlike PostgreSQL, bound values get trimmed of surrounding unquoted whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.eq_ignore_ascii_case("empty") {
+            return Ok(Self::Empty);
+        }
+
+        let (is_lower_inclusive, rest) = if let Some(rest) = s.strip_prefix('[') {
+            (true, rest)
+        } else if let Some(rest) = s.strip_prefix('(') {
+            (false, rest)
+        } else {
+            return Err(PgRangeError::MissingLowerBracket);
+        };
+
+        let (is_upper_inclusive, rest) = if let Some(rest) = rest.strip_suffix(']') {
+            (true, rest)
+        } else if let Some(rest) = rest.strip_suffix(')') {
+            (false, rest)
+        } else {
+            return Err(PgRangeError::MissingUpperBracket);
+        };
+
+        let (lower, upper) = split_bounds(rest)?;
+
+        let lower = parse_bound(lower, is_lower_inclusive)?;
+        let upper = parse_bound(upper, is_upper_inclusive)?;
+
+        Self::new(lower, upper)
+    }
+}
+
+impl<T> Display for PgRange<T>
+where
+    T: Display,
+{
+    /// Formats the range as a range literal, as emitted by PostgreSQL.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self::NonEmpty { lower, upper } = self else {
+            return f.write_str("empty");
+        };
+
+        f.write_char(match lower {
+            PgBound::Inclusive(_) => '[',
+            PgBound::Exclusive(_) | PgBound::Unbounded => '(',
+        })?;
+
+        if let Some(value) = lower.value() {
+            write_value(f, value)?;
+        }
+
+        f.write_char(',')?;
+
+        if let Some(value) = upper.value() {
+            write_value(f, value)?;
+        }
+
+        f.write_char(match upper {
+            PgBound::Inclusive(_) => ']',
+            PgBound::Exclusive(_) | PgBound::Unbounded => ')',
+        })
+    }
+}
+
+/// Splits the contents of a range literal at the comma separating its bounds.
+fn split_bounds(s: &str) -> Result<(&str, &str), PgRangeError> {
+    let mut comma = None;
+    let mut is_quoted = false;
+    let mut is_escaped = false;
+
+    for (index, c) in s.char_indices() {
+        match c {
+            _ if is_escaped => is_escaped = false,
+            '\\' => is_escaped = true,
+            '"' => is_quoted = !is_quoted,
+            ',' if !is_quoted => {
+                if comma.is_some() {
+                    return Err(PgRangeError::TooManyCommas);
+                }
+                comma = Some(index);
+            }
+            _ => {}
+        }
+    }
+
+    if is_quoted || is_escaped {
+        return Err(PgRangeError::UnterminatedQuote);
+    }
+
+    let comma = comma.ok_or(PgRangeError::MissingComma)?;
+
+    Ok((&s[..comma], &s[(comma + 1)..]))
+}
+
+/// Parses a (possibly quoted) bound of a range literal.
+fn parse_bound<T>(s: &str, is_inclusive: bool) -> Result<PgBound<T>, PgRangeError>
+where
+    T: FromStr,
+{
+    if s.is_empty() {
+        return Ok(PgBound::Unbounded);
+    }
+
+    let mut value = String::with_capacity(s.len());
+    let mut is_quoted = false;
+    let mut chars = s.trim().chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => value.extend(chars.next()),
+            '"' if is_quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                value.push('"');
+            }
+            '"' => is_quoted = !is_quoted,
+            c => value.push(c),
+        }
+    }
+
+    let value = value.parse().map_err(|_| PgRangeError::InvalidValue)?;
+
+    Ok(if is_inclusive {
+        PgBound::Inclusive(value)
+    } else {
+        PgBound::Exclusive(value)
+    })
+}
+
+/// Writes a bound value, quoting it if necessary.
+fn write_value<T>(f: &mut fmt::Formatter<'_>, value: &T) -> fmt::Result
+where
+    T: Display,
+{
+    let value = value.to_string();
+
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| matches!(c, '"' | '\\' | '(' | ')' | '[' | ']' | ',') || c.is_whitespace());
+
+    if !needs_quotes {
+        return f.write_str(&value);
+    }
+
+    f.write_char('"')?;
+
+    for c in value.chars() {
+        if matches!(c, '"' | '\\') {
+            f.write_char(c)?;
+        }
+        f.write_char(c)?;
+    }
+
+    f.write_char('"')
+}
+
+#[cfg(feature = "postgres-types")]
+mod sql {
+    use std::error::Error;
+
+    use bytes::BytesMut;
+    use postgres_protocol::{
+        types::{self as protocol, Range, RangeBound},
+        IsNull as ProtocolIsNull,
+    };
+    use postgres_types::{to_sql_checked, FromSql, IsNull, Kind, ToSql, Type};
+
+    use super::*;
+
+    type BoxError = Box<dyn Error + Sync + Send>;
+
+    fn member_type(ty: &Type) -> Result<&Type, BoxError> {
+        match ty.kind() {
+            Kind::Range(member) => Ok(member),
+            _ => Err(format!("expected range type, found {ty}").into()),
+        }
+    }
+
+    fn bound_to_sql<T>(
+        bound: &PgBound<T>,
+        member: &Type,
+        out: &mut BytesMut,
+    ) -> Result<RangeBound<ProtocolIsNull>, BoxError>
+    where
+        T: ToSql,
+    {
+        let value = |value: &T, out: &mut BytesMut| -> Result<ProtocolIsNull, BoxError> {
+            match value.to_sql(member, out)? {
+                IsNull::No => Ok(ProtocolIsNull::No),
+                IsNull::Yes => Err(PgRangeError::NullValue.into()),
+            }
+        };
+
+        Ok(match bound {
+            PgBound::Inclusive(v) => RangeBound::Inclusive(value(v, out)?),
+            PgBound::Exclusive(v) => RangeBound::Exclusive(value(v, out)?),
+            PgBound::Unbounded => RangeBound::Unbounded,
+        })
+    }
+
+    impl<'a, T> FromSql<'a> for PgRange<T>
+    where
+        T: FromSql<'a>,
+    {
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+            let member = member_type(ty)?;
+
+            let bound = |bound: RangeBound<Option<&'a [u8]>>| -> Result<PgBound<T>, BoxError> {
+                let value = |raw: Option<&'a [u8]>| -> Result<T, BoxError> {
+                    T::from_sql(member, raw.ok_or(PgRangeError::NullValue)?)
+                };
+
+                Ok(match bound {
+                    RangeBound::Inclusive(raw) => PgBound::Inclusive(value(raw)?),
+                    RangeBound::Exclusive(raw) => PgBound::Exclusive(value(raw)?),
+                    RangeBound::Unbounded => PgBound::Unbounded,
+                })
+            };
+
+            match protocol::range_from_sql(raw)? {
+                Range::Empty => Ok(Self::Empty),
+                Range::Nonempty(lower, upper) => Ok(Self::NonEmpty {
+                    lower: bound(lower)?,
+                    upper: bound(upper)?,
+                }),
+            }
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            match ty.kind() {
+                Kind::Range(member) => T::accepts(member),
+                _ => false,
+            }
+        }
+    }
+
+    impl<T> ToSql for PgRange<T>
+    where
+        T: ToSql,
+    {
+        fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+            let member = member_type(ty)?;
+
+            let Self::NonEmpty { lower, upper } = self else {
+                protocol::empty_range_to_sql(out);
+                return Ok(IsNull::No);
+            };
+
+            protocol::range_to_sql(
+                |out| bound_to_sql(lower, member, out),
+                |out| bound_to_sql(upper, member, out),
+                out,
+            )?;
+
+            Ok(IsNull::No)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            match ty.kind() {
+                Kind::Range(member) => T::accepts(member),
+                _ => false,
+            }
+        }
+
+        to_sql_checked!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Interval, IntervalFrom, IntervalTo};
+
+    fn parse(s: &str) -> Result<PgRange<i32>, PgRangeError> {
+        s.parse()
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            parse("[1,5)"),
+            Ok(PgRange::NonEmpty {
+                lower: PgBound::Inclusive(1),
+                upper: PgBound::Exclusive(5),
+            })
+        );
+        assert_eq!(
+            parse("  (,10] "),
+            Ok(PgRange::NonEmpty {
+                lower: PgBound::Unbounded,
+                upper: PgBound::Inclusive(10),
+            })
+        );
+        assert_eq!(
+            parse("[ 3 , \"4\" ]"),
+            Ok(PgRange::NonEmpty {
+                lower: PgBound::Inclusive(3),
+                upper: PgBound::Inclusive(4),
+            })
+        );
+        assert_eq!(
+            parse("[,]"),
+            Ok(PgRange::NonEmpty {
+                lower: PgBound::Unbounded,
+                upper: PgBound::Unbounded,
+            })
+        );
+        assert_eq!(parse("empty"), Ok(PgRange::Empty));
+        assert_eq!(parse("EMPTY"), Ok(PgRange::Empty));
+        assert_eq!(parse("[5,5)"), Ok(PgRange::Empty));
+        assert_eq!(
+            parse("[5,5]"),
+            Ok(PgRange::NonEmpty {
+                lower: PgBound::Inclusive(5),
+                upper: PgBound::Inclusive(5),
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_errors() {
+        assert_eq!(parse("1,5)"), Err(PgRangeError::MissingLowerBracket));
+        assert_eq!(parse("[1,5"), Err(PgRangeError::MissingUpperBracket));
+        assert_eq!(parse("[15]"), Err(PgRangeError::MissingComma));
+        assert_eq!(parse("[1,5,6]"), Err(PgRangeError::TooManyCommas));
+        assert_eq!(parse("[\"1,5]"), Err(PgRangeError::UnterminatedQuote));
+        assert_eq!(parse("[a,5]"), Err(PgRangeError::InvalidValue));
+        assert_eq!(parse("[5,1]"), Err(PgRangeError::InvertedBounds));
+        assert_eq!(
+            "[NaN,1]".parse::<PgRange<f64>>(),
+            Err(PgRangeError::AmbiguousOrder)
+        );
+    }
+
+    #[test]
+    fn quoted_values() {
+        let range: PgRange<String> = r#"["2024-01-01 00:00","a \"b\" ""c"""]"#.parse().unwrap();
+
+        assert_eq!(
+            range,
+            PgRange::NonEmpty {
+                lower: PgBound::Inclusive("2024-01-01 00:00".to_owned()),
+                upper: PgBound::Inclusive("a \"b\" \"c\"".to_owned()),
+            }
+        );
+        assert_eq!(range.to_string(), r#"["2024-01-01 00:00","a ""b"" ""c"""]"#);
+        assert_eq!(range.to_string().parse(), Ok(range));
+    }
+
+    #[test]
+    fn display() {
+        for s in ["[1,5)", "(,10]", "[3,)", "(,)", "(1,2]", "empty"] {
+            assert_eq!(parse(s).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn canonicalize() {
+        assert_eq!(parse("(1,5]").unwrap().canonicalize(), parse("[2,6)"));
+        assert_eq!(parse("(1,2)").unwrap().canonicalize(), Ok(PgRange::Empty));
+        assert_eq!(parse("[5,5]").unwrap().canonicalize(), parse("[5,6)"));
+        assert_eq!(
+            PgRange::NonEmpty {
+                lower: PgBound::Inclusive(1),
+                upper: PgBound::Inclusive(i32::MAX),
+            }
+            .canonicalize(),
+            Err(PgRangeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let bounds = parse("[1,5]")
+            .unwrap()
+            .into_discrete_bounds()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            Interval::try_from(bounds),
+            Ok(Interval { start: 1, end: 6 })
+        );
+
+        let bounds = parse("(,5]")
+            .unwrap()
+            .into_discrete_bounds()
+            .unwrap()
+            .unwrap();
+        assert_eq!(IntervalTo::try_from(bounds), Ok(IntervalTo { end: 6 }));
+
+        let bounds = parse("(1,)")
+            .unwrap()
+            .into_discrete_bounds()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            IntervalFrom::try_from(bounds),
+            Ok(IntervalFrom { start: 2 })
+        );
+
+        assert_eq!(parse("empty").unwrap().into_discrete_bounds(), Ok(None));
+
+        let range: PgRange<f64> = "(1.5,2.5]".parse().unwrap();
+        let bounds = range.into_continuous_bounds().unwrap();
+        assert_eq!(
+            Interval::try_from(bounds),
+            Ok(Interval {
+                start: 1.5,
+                end: 2.5
+            })
+        );
+
+        let bounds = Bounds {
+            start: Bound::Bounded(1),
+            end: Bound::Unbounded,
+        };
+        assert_eq!(PgRange::from_discrete_bounds(bounds).to_string(), "[1,)");
+
+        let bounds = Bounds {
+            start: Bound::Bounded(1.5),
+            end: Bound::Bounded(2.5),
+        };
+        assert_eq!(
+            PgRange::from_continuous_bounds(bounds).to_string(),
+            "[1.5,2.5]"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn canonicalize_dates() {
+        use chrono::NaiveDate;
+
+        let range: PgRange<NaiveDate> = "(2024-02-28,2024-02-29]".parse().unwrap();
+        assert_eq!(
+            range.canonicalize().unwrap().to_string(),
+            "[2024-02-29,2024-03-01)"
+        );
+
+        let bounds = range.into_discrete_bounds().unwrap().unwrap();
+        assert_eq!(
+            Interval::try_from(bounds),
+            Ok(Interval {
+                start: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+                end: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            })
+        );
+
+        let range = PgRange::NonEmpty {
+            lower: PgBound::Inclusive(NaiveDate::MIN),
+            upper: PgBound::Inclusive(NaiveDate::MAX),
+        };
+        assert_eq!(range.canonicalize(), Err(PgRangeError::Overflow));
+    }
+
+    #[cfg(feature = "postgres-types")]
+    mod sql {
+        use bytes::BytesMut;
+        use postgres_types::{FromSql, ToSql, Type};
+
+        use super::*;
+
+        fn round_trip<T>(ty: &Type, range: PgRange<T>) -> Vec<u8>
+        where
+            T: ToSql + for<'a> FromSql<'a> + PartialEq,
+        {
+            let mut buf = BytesMut::new();
+            range.to_sql_checked(ty, &mut buf).unwrap();
+
+            assert_eq!(PgRange::<T>::from_sql(ty, &buf).unwrap(), range);
+
+            buf.to_vec()
+        }
+
+        #[test]
+        fn binary() {
+            assert_eq!(
+                round_trip(&Type::INT4_RANGE, parse("[1,5)").unwrap()),
+                [0x02, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 5]
+            );
+            assert_eq!(
+                round_trip(&Type::INT4_RANGE, parse("(,10]").unwrap()),
+                [0x0c, 0, 0, 0, 4, 0, 0, 0, 10]
+            );
+            assert_eq!(
+                round_trip(&Type::INT4_RANGE, parse("empty").unwrap()),
+                [0x01]
+            );
+
+            round_trip(
+                &Type::INT8_RANGE,
+                PgRange::NonEmpty {
+                    lower: PgBound::Exclusive(i64::MIN),
+                    upper: PgBound::Unbounded,
+                },
+            );
+        }
+
+        #[cfg(feature = "chrono")]
+        #[test]
+        fn chrono() {
+            use chrono::{DateTime, NaiveDate, Utc};
+
+            let date = |day| NaiveDate::from_ymd_opt(2024, 2, day).unwrap();
+            let range = PgRange::NonEmpty {
+                lower: PgBound::Inclusive(date(28)),
+                upper: PgBound::Inclusive(date(29)),
+            };
+            round_trip(&Type::DATE_RANGE, range.canonicalize().unwrap());
+
+            let timestamp = |hour| date(28).and_hms_opt(hour, 30, 0).unwrap();
+            round_trip(
+                &Type::TS_RANGE,
+                PgRange::NonEmpty {
+                    lower: PgBound::Inclusive(timestamp(9)),
+                    upper: PgBound::Exclusive(timestamp(17)),
+                },
+            );
+            round_trip(
+                &Type::TSTZ_RANGE,
+                PgRange::NonEmpty {
+                    lower: PgBound::Unbounded,
+                    upper: PgBound::Inclusive(DateTime::<Utc>::from_naive_utc_and_offset(
+                        timestamp(17),
+                        Utc,
+                    )),
+                },
+            );
+
+            assert!(<PgRange<NaiveDate> as ToSql>::accepts(&Type::DATE_RANGE));
+            assert!(!<PgRange<NaiveDate> as ToSql>::accepts(&Type::TS_RANGE));
+        }
+
+        #[test]
+        fn accepts() {
+            assert!(<PgRange<i32> as ToSql>::accepts(&Type::INT4_RANGE));
+            assert!(<PgRange<i64> as FromSql>::accepts(&Type::INT8_RANGE));
+            assert!(!<PgRange<i32> as ToSql>::accepts(&Type::INT8_RANGE));
+            assert!(!<PgRange<i32> as FromSql>::accepts(&Type::INT4));
+
+            let mut buf = BytesMut::new();
+            assert!(parse("[1,5)")
+                .unwrap()
+                .to_sql_checked(&Type::INT8_RANGE, &mut buf)
+                .is_err());
+        }
+    }
+}
@@ -0,0 +1,449 @@
+//! [`proptest`](https://docs.rs/proptest) strategies for generating intervals
+//! and pairs of intervals that stand in a given [`Relation`].
+//!
+//! ```
+//! use allen_intervals::{proptest::intervals_with_relation, FromIntervals, Interval, IntervalFrom, Relation};
+//! use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+//!
+//! let relation = Relation::Overlaps { is_inverted: false };
+//! let strategy = intervals_with_relation::<Interval<i32>, IntervalFrom<i32>, _>(any::<i32>(), relation)
+//!     .expect("an interval can overlap an interval-from");
+//!
+//! let mut runner = TestRunner::default();
+//! let (s, t) = strategy.new_tree(&mut runner).unwrap().current();
+//!
+//! assert_eq!(Relation::from_intervals(&s, &t), relation);
+//! ```
+
+use core::fmt::Debug;
+use std::rc::Rc;
+
+use ::proptest::{
+    arbitrary::Arbitrary,
+    collection::btree_set,
+    sample::select,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use crate::{Bound, Bounds, Interval, IntervalFrom, IntervalFull, IntervalTo, NonEmpty, Relation};
+
+/// `IntervalShape` is implemented by the crate's built-in interval types.
+///
+/// It describes which of an interval type's endpoints are bounded,
+/// allowing strategies to construct intervals of a given type from raw endpoint values.
+pub trait IntervalShape<T>: Sized {
+    /// `true` iff the interval type has a start bound.
+    const HAS_START: bool;
+
+    /// `true` iff the interval type has an end bound.
+    const HAS_END: bool;
+
+    /// Creates a non-empty interval from its endpoint values.
+    ///
+    /// The caller guarantees that exactly the type's bounded endpoints are provided
+    /// and that `start < end`, if both are bounded.
+    fn from_endpoints(start: Option<T>, end: Option<T>) -> NonEmpty<Self>;
+}
+
+impl<T> IntervalShape<T> for Interval<T> {
+    const HAS_START: bool = true;
+    const HAS_END: bool = true;
+
+    fn from_endpoints(start: Option<T>, end: Option<T>) -> NonEmpty<Self> {
+        let (Some(start), Some(end)) = (start, end) else {
+            unreachable!("expected bounded start and end");
+        };
+        NonEmpty(Self { start, end })
+    }
+}
+
+impl<T> IntervalShape<T> for IntervalFrom<T> {
+    const HAS_START: bool = true;
+    const HAS_END: bool = false;
+
+    fn from_endpoints(start: Option<T>, _end: Option<T>) -> NonEmpty<Self> {
+        let Some(start) = start else {
+            unreachable!("expected bounded start");
+        };
+        NonEmpty(Self { start })
+    }
+}
+
+impl<T> IntervalShape<T> for IntervalTo<T> {
+    const HAS_START: bool = false;
+    const HAS_END: bool = true;
+
+    fn from_endpoints(_start: Option<T>, end: Option<T>) -> NonEmpty<Self> {
+        let Some(end) = end else {
+            unreachable!("expected bounded end");
+        };
+        NonEmpty(Self { end })
+    }
+}
+
+impl<T> IntervalShape<T> for IntervalFull {
+    const HAS_START: bool = false;
+    const HAS_END: bool = false;
+
+    fn from_endpoints(_start: Option<T>, _end: Option<T>) -> NonEmpty<Self> {
+        NonEmpty(Self)
+    }
+}
+
+/// Returns a strategy generating non-empty intervals of type `S`,
+/// with bounded endpoints drawn from `values`.
+///
+/// `values` must generate at least as many distinct values as `S` has bounded endpoints
+/// (e.g. two for `Interval`), or the strategy will be rejected.
+pub fn non_empty<S, T>(values: impl Strategy<Value = T>) -> impl Strategy<Value = NonEmpty<S>>
+where
+    S: IntervalShape<T> + Debug,
+    T: Ord + Debug,
+{
+    let count = usize::from(S::HAS_START) + usize::from(S::HAS_END);
+
+    btree_set(values, count).prop_map(|values| {
+        let mut values = values.into_iter();
+        let start = if S::HAS_START { values.next() } else { None };
+        let end = if S::HAS_END { values.next() } else { None };
+        S::from_endpoints(start, end)
+    })
+}
+
+/// Returns a strategy generating non-empty intervals,
+/// with endpoints drawn from `values`.
+pub fn interval<T>(values: impl Strategy<Value = T>) -> impl Strategy<Value = NonEmpty<Interval<T>>>
+where
+    T: Ord + Debug,
+{
+    non_empty(values)
+}
+
+/// Returns a strategy generating intervals only bounded below,
+/// with endpoints drawn from `values`.
+pub fn interval_from<T>(
+    values: impl Strategy<Value = T>,
+) -> impl Strategy<Value = NonEmpty<IntervalFrom<T>>>
+where
+    T: Ord + Debug,
+{
+    non_empty(values)
+}
+
+/// Returns a strategy generating intervals only bounded above,
+/// with endpoints drawn from `values`.
+pub fn interval_to<T>(
+    values: impl Strategy<Value = T>,
+) -> impl Strategy<Value = NonEmpty<IntervalTo<T>>>
+where
+    T: Ord + Debug,
+{
+    non_empty(values)
+}
+
+/// Returns a strategy generating unbounded intervals.
+pub fn interval_full() -> impl Strategy<Value = NonEmpty<IntervalFull>> {
+    Just(NonEmpty(IntervalFull))
+}
+
+/// Returns a strategy generating any of the thirteen basic relations.
+pub fn relation() -> impl Strategy<Value = Relation> {
+    select(&Relation::ALL[..])
+}
+
+/// Returns a strategy generating pairs of non-empty intervals `(s, t)`
+/// of types `S` and `T` that stand in `relation`,
+/// with bounded endpoints drawn from `values`.
+///
+/// Returns `None` if no pair of intervals of types `S` and `T`
+/// can stand in `relation` (e.g. `IntervalFull` can only equal `IntervalFull`).
+///
+/// Only as many distinct values are drawn as the intervals have distinct endpoints,
+/// so `values` must generate at least that many (e.g. two for `Interval`s that are equal,
+/// but four for `Interval`s where one contains the other), or the strategy will be rejected.
+pub fn intervals_with_relation<S, T, V>(
+    values: impl Strategy<Value = V>,
+    relation: Relation,
+) -> Option<impl Strategy<Value = (NonEmpty<S>, NonEmpty<T>)>>
+where
+    S: IntervalShape<V> + Debug,
+    T: IntervalShape<V> + Debug,
+    V: Ord + Clone + Debug,
+{
+    let patterns = patterns::<S, T, V>(relation);
+
+    if patterns.is_empty() {
+        return None;
+    }
+
+    let values = Rc::new(values);
+
+    let strategy = select(patterns)
+        .prop_flat_map(move |pattern| (Just(pattern), btree_set(Rc::clone(&values), pattern.ranks)))
+        .prop_map(|(pattern, values)| {
+            let values: Vec<V> = values.into_iter().collect();
+            let value = |rank: Option<usize>| rank.map(|rank| values[rank].clone());
+
+            let s = S::from_endpoints(value(pattern.s_start), value(pattern.s_end));
+            let t = T::from_endpoints(value(pattern.t_start), value(pattern.t_end));
+
+            (s, t)
+        });
+
+    Some(strategy)
+}
+
+// Any combination of Allen relations involves at most four distinct endpoints,
+// so ranking four distinct values is sufficient for realizing any pattern:
+const RANKS: usize = 4;
+
+/// Endpoint ranks of a pair of intervals.
+#[derive(Clone, Copy, Debug)]
+struct Pattern {
+    s_start: Option<usize>,
+    s_end: Option<usize>,
+    t_start: Option<usize>,
+    t_end: Option<usize>,
+    /// The number of distinct ranks (i.e. `0..ranks`).
+    ranks: usize,
+}
+
+/// Enumerates all dense rankings of the endpoints of intervals of types `S` and `T`
+/// (i.e. using each of the ranks `0..n` for some `n`)
+/// for which the intervals stand in `relation`.
+fn patterns<S, T, V>(relation: Relation) -> Vec<Pattern>
+where
+    S: IntervalShape<V>,
+    T: IntervalShape<V>,
+{
+    let s_endpoints = endpoints(S::HAS_START, S::HAS_END);
+    let t_endpoints = endpoints(T::HAS_START, T::HAS_END);
+
+    let mut patterns = vec![];
+
+    for &(s_start, s_end) in &s_endpoints {
+        for &(t_start, t_end) in &t_endpoints {
+            let s = bounds(s_start, s_end);
+            let t = bounds(t_start, t_end);
+
+            if Relation::from_bounds(&s, &t) != relation {
+                continue;
+            }
+
+            let ranks = [s_start, s_end, t_start, t_end]
+                .into_iter()
+                .flatten()
+                .fold(0_u8, |ranks, rank| ranks | 1 << rank);
+
+            // Skip rankings with gaps, which realize the same orders as their dense counterparts:
+            if (ranks + 1) & ranks == 0 {
+                patterns.push(Pattern {
+                    s_start,
+                    s_end,
+                    t_start,
+                    t_end,
+                    ranks: ranks.count_ones() as usize,
+                });
+            }
+        }
+    }
+
+    patterns
+}
+
+/// Enumerates all non-empty rankings of an interval's endpoints.
+fn endpoints(has_start: bool, has_end: bool) -> Vec<(Option<usize>, Option<usize>)> {
+    let ranks = |is_bounded: bool| -> Vec<Option<usize>> {
+        if is_bounded {
+            (0..RANKS).map(Some).collect()
+        } else {
+            vec![None]
+        }
+    };
+
+    let mut endpoints = vec![];
+
+    for start in ranks(has_start) {
+        for end in ranks(has_end) {
+            let is_non_empty = match (start, end) {
+                (Some(start), Some(end)) => start < end,
+                _ => true,
+            };
+
+            if is_non_empty {
+                endpoints.push((start, end));
+            }
+        }
+    }
+
+    endpoints
+}
+
+fn bounds(start: Option<usize>, end: Option<usize>) -> Bounds<usize> {
+    let bound = |rank: Option<usize>| rank.map_or(Bound::Unbounded, Bound::Bounded);
+
+    Bounds {
+        start: bound(start),
+        end: bound(end),
+    }
+}
+
+impl<T> Arbitrary for NonEmpty<Interval<T>>
+where
+    T: Arbitrary + Ord + 'static,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        interval(T::arbitrary_with(args)).boxed()
+    }
+}
+
+impl<T> Arbitrary for NonEmpty<IntervalFrom<T>>
+where
+    T: Arbitrary + Ord + 'static,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        interval_from(T::arbitrary_with(args)).boxed()
+    }
+}
+
+impl<T> Arbitrary for NonEmpty<IntervalTo<T>>
+where
+    T: Arbitrary + Ord + 'static,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        interval_to(T::arbitrary_with(args)).boxed()
+    }
+}
+
+impl Arbitrary for NonEmpty<IntervalFull> {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        Just(NonEmpty(IntervalFull))
+    }
+}
+
+impl Arbitrary for Relation {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        relation().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::proptest::{
+        arbitrary::any, prop_assert, prop_assert_eq, proptest, strategy::ValueTree,
+        test_runner::TestRunner,
+    };
+
+    use crate::{FromIntervals, IntervalBounds};
+
+    use super::*;
+
+    fn assert_relation<S, T>(expected: usize)
+    where
+        S: IntervalShape<i32> + Debug,
+        T: IntervalShape<i32> + Debug,
+        Relation: FromIntervals<S, T>,
+    {
+        let mut runner = TestRunner::deterministic();
+        let mut count = 0;
+
+        for relation in Relation::ALL {
+            let Some(strategy) = intervals_with_relation::<S, T, _>(any::<i32>(), relation) else {
+                continue;
+            };
+
+            count += 1;
+
+            for _ in 0..16 {
+                let (s, t) = strategy.new_tree(&mut runner).unwrap().current();
+                assert_eq!(Relation::from_intervals(&s, &t), relation);
+            }
+        }
+
+        assert_eq!(count, expected);
+    }
+
+    #[test]
+    fn intervals_with_relations() {
+        assert_relation::<IntervalFull, IntervalFull>(1);
+        assert_relation::<IntervalFull, IntervalTo<i32>>(1);
+        assert_relation::<IntervalFull, IntervalFrom<i32>>(1);
+        assert_relation::<IntervalFull, Interval<i32>>(1);
+
+        assert_relation::<IntervalTo<i32>, IntervalFull>(1);
+        assert_relation::<IntervalTo<i32>, IntervalTo<i32>>(3);
+        assert_relation::<IntervalTo<i32>, IntervalFrom<i32>>(3);
+        assert_relation::<IntervalTo<i32>, Interval<i32>>(5);
+
+        assert_relation::<IntervalFrom<i32>, IntervalFull>(1);
+        assert_relation::<IntervalFrom<i32>, IntervalTo<i32>>(3);
+        assert_relation::<IntervalFrom<i32>, IntervalFrom<i32>>(3);
+        assert_relation::<IntervalFrom<i32>, Interval<i32>>(5);
+
+        assert_relation::<Interval<i32>, IntervalFull>(1);
+        assert_relation::<Interval<i32>, IntervalTo<i32>>(5);
+        assert_relation::<Interval<i32>, IntervalFrom<i32>>(5);
+        assert_relation::<Interval<i32>, Interval<i32>>(13);
+    }
+
+    #[test]
+    fn few_distinct_values() {
+        let mut runner = TestRunner::deterministic();
+
+        for relation in Relation::ALL {
+            // Any relation can be realized by four distinct values, but `e` only needs two:
+            let values = if relation == Relation::Equals {
+                0..2_u8
+            } else {
+                0..4_u8
+            };
+            let strategy =
+                intervals_with_relation::<Interval<u8>, Interval<u8>, _>(values, relation).unwrap();
+
+            for _ in 0..16 {
+                let (s, t) = strategy.new_tree(&mut runner).unwrap().current();
+                assert_eq!(Relation::from_intervals(&s, &t), relation);
+            }
+        }
+
+        let strategy = intervals_with_relation::<IntervalFrom<u8>, IntervalTo<u8>, _>(
+            Just(0_u8),
+            Relation::Meets { is_inverted: true },
+        )
+        .unwrap();
+        let (s, t) = strategy.new_tree(&mut runner).unwrap().current();
+        assert_eq!(
+            Relation::from_intervals(&s, &t),
+            Relation::Meets { is_inverted: true }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn intervals_are_non_empty(interval in any::<NonEmpty<Interval<u8>>>()) {
+            let interval = interval.into_inner();
+            prop_assert!(interval.start < interval.end);
+        }
+
+        #[test]
+        fn half_bounded_intervals(from in interval_from(0..10_u8), to in interval_to(0..10_u8)) {
+            prop_assert_eq!(from.end_bound(), Bound::Unbounded);
+            prop_assert_eq!(to.start_bound(), Bound::Unbounded);
+        }
+    }
+}
@@ -0,0 +1,536 @@
+//! Reading and writing qualitative constraint networks (QCNs) of Allen's interval relations
+//! in the text format of the [GQR] and [SparQ] reasoners.
+//!
+//! A network starts with a header of its highest node index
+//! (or `-1`, if it has no nodes; optionally followed by a `#` comment),
+//! lists a constraint per line as a pair of nodes followed by a parenthesized set of relations,
+//! and is terminated by a `.` line:
+//!
+//! ```text
+//! 2 # a, b and c
+//! 0 1 ( < m )
+//! 1 2 ( d s f )
+//! .
+//! ```
+//!
+//! Relations are written in GQR's symbols (i.e. `<`, `>`, `=`, `m`, `mi`, `o`, `oi`, `s`, `si`, `d`, `di`, `f`, `fi`)
+//! or (with [`Dialect::Sparq`]) in SparQ's (i.e. `b`, `bi`, `eq`, and the rest as in GQR's),
+//! and parsed in either (as well as in any other notation accepted by [`Relation`]'s `FromStr` implementation).
+//!
+//! ```
+//! use allen_intervals::{qcn::{Dialect, Network}, Interval, NonEmpty, Relation, RelationSet};
+//!
+//! let network: Network = "2\n0 1 ( < m )\n1 2 ( d )\n.\n".parse().unwrap();
+//! assert_eq!(network.get(1, 0), "{mi, pi}".parse::<RelationSet>().unwrap());
+//!
+//! let intervals: Vec<NonEmpty<_>> = [Interval { start: 1, end: 3 }, Interval { start: 3, end: 5 }]
+//!     .into_iter()
+//!     .map(|interval| interval.try_into().unwrap())
+//!     .collect();
+//!
+//! let network = Network::from_intervals(&intervals);
+//! assert_eq!(network.to_string(), "1\n0 1 ( m )\n.\n");
+//! assert_eq!(network.display(Dialect::Sparq).to_string(), "1\n0 1 ( m )\n.\n");
+//! ```
+//!
+//! [GQR]: https://github.com/m-westphal/gqr
+//! [SparQ]: https://www.uni-bamberg.de/en/sme/research/sparq/
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{FromIntervals, NonEmpty, ParseRelationError, Relation, RelationSet};
+
+/// Error type for parsing qualitative constraint networks.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, thiserror::Error)]
+pub enum QcnError {
+    /// The line is not a header of the highest node index (e.g. `3 # comment`).
+    #[error("line {line}: expected a header of the highest node index (e.g. `3 # comment`)")]
+    InvalidHeader {
+        /// The (one-based) line number.
+        line: usize,
+    },
+    /// The line is neither a constraint (e.g. `0 1 ( < m )`), nor a `.` terminator.
+    #[error("line {line}: expected a constraint (e.g. `0 1 ( < m )`), or `.`")]
+    InvalidConstraint {
+        /// The (one-based) line number.
+        line: usize,
+    },
+    /// A constraint's relation could not be parsed.
+    #[error("line {line}: invalid relation")]
+    InvalidRelation {
+        /// The (one-based) line number.
+        line: usize,
+        /// The relation's parse error.
+        #[source]
+        error: ParseRelationError,
+    },
+    /// A constraint's node exceeds the network's highest node index.
+    #[error("line {line}: node {node} exceeds the highest node index")]
+    NodeOutOfRange {
+        /// The (one-based) line number.
+        line: usize,
+        /// The offending node.
+        node: usize,
+    },
+    /// The input does not contain exactly one network.
+    #[error("expected exactly one network, found {count}")]
+    NetworkCount {
+        /// The number of networks found.
+        count: usize,
+    },
+}
+
+/// The relation symbols of a reasoner's Allen calculus.
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub enum Dialect {
+    /// GQR's symbols (i.e. `<`, `>` and `=` for "precedes", "is preceded by" and "equals").
+    #[default]
+    Gqr,
+    /// SparQ's symbols (i.e. `b`, `bi` and `eq` for "precedes", "is preceded by" and "equals").
+    Sparq,
+}
+
+impl Dialect {
+    /// Returns the dialect's symbol for `relation`.
+    pub fn symbol(&self, relation: Relation) -> &'static str {
+        match (self, relation) {
+            (Self::Gqr, Relation::Precedes { is_inverted: false }) => "<",
+            (Self::Gqr, Relation::Precedes { is_inverted: true }) => ">",
+            (Self::Gqr, Relation::Equals) => "=",
+            (Self::Sparq, Relation::Precedes { is_inverted: false }) => "b",
+            (Self::Sparq, Relation::Precedes { is_inverted: true }) => "bi",
+            (Self::Sparq, Relation::Equals) => "eq",
+            (_, relation) => relation.abbreviation(),
+        }
+    }
+}
+
+/// A qualitative constraint network of Allen's interval relations
+/// over the nodes `0..node_count`.
+///
+/// Unconstrained pairs of distinct nodes stand in any relation,
+/// while each node equals itself.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Network {
+    node_count: usize,
+    comment: Option<String>,
+    constraints: BTreeMap<(usize, usize), RelationSet>,
+}
+
+impl Network {
+    /// Creates an unconstrained network of `node_count` nodes.
+    pub fn new(node_count: usize) -> Self {
+        Self {
+            node_count,
+            comment: None,
+            constraints: BTreeMap::new(),
+        }
+    }
+
+    /// Creates the network of the relations between each pair of `intervals`.
+    pub fn from_intervals<I>(intervals: &[NonEmpty<I>]) -> Self
+    where
+        Relation: FromIntervals<I, I>,
+    {
+        let mut network = Self::new(intervals.len());
+
+        for (s, s_interval) in intervals.iter().enumerate() {
+            for (t, t_interval) in intervals.iter().enumerate().skip(s + 1) {
+                let relation = Relation::from_intervals(s_interval, t_interval);
+                network.constraints.insert((s, t), relation.into());
+            }
+        }
+
+        network
+    }
+
+    /// Sets the network's comment (written after its header).
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Returns the network's comment.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Returns the relations node `s` may stand in to node `t`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is out of range.
+    pub fn get(&self, s: usize, t: usize) -> RelationSet {
+        self.assert_node(s);
+        self.assert_node(t);
+
+        if s <= t {
+            self.constraints
+                .get(&(s, t))
+                .copied()
+                .unwrap_or_else(|| match s == t {
+                    true => Relation::Equals.into(),
+                    false => RelationSet::all(),
+                })
+        } else {
+            self.get(t, s).as_converse()
+        }
+    }
+
+    /// Constrains node `s` to stand in any of `relations` to node `t`
+    /// (in addition to any existing constraints between them).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is out of range.
+    pub fn constrain(&mut self, s: usize, t: usize, relations: RelationSet) {
+        self.assert_node(s);
+        self.assert_node(t);
+
+        let (key, relations) = match s <= t {
+            true => ((s, t), relations),
+            false => ((t, s), relations.as_converse()),
+        };
+
+        let constraint = self.get(key.0, key.1).intersection(&relations);
+        self.constraints.insert(key, constraint);
+    }
+
+    /// Returns an iterator over the network's explicit constraints, ordered by their nodes `s <= t`.
+    pub fn constraints(&self) -> impl Iterator<Item = (usize, usize, RelationSet)> + '_ {
+        self.constraints
+            .iter()
+            .map(|(&(s, t), &relations)| (s, t, relations))
+    }
+
+    /// Returns an object formatting the network using `dialect`'s relation symbols.
+    pub fn display(&self, dialect: Dialect) -> DisplayNetwork<'_> {
+        DisplayNetwork {
+            network: self,
+            dialect,
+        }
+    }
+
+    /// Parses all networks of `s` (e.g. of a benchmark file containing several instances).
+    pub fn parse_all(s: &str) -> Result<Vec<Self>, QcnError> {
+        let mut networks = vec![];
+        let mut current: Option<Self> = None;
+
+        for (index, line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match current.as_mut() {
+                None => current = Some(Self::parse_header(line, line_number)?),
+                Some(_) if line == "." => networks.extend(current.take()),
+                Some(network) => network.parse_constraint(line, line_number)?,
+            }
+        }
+
+        // Be lenient about a missing terminator of the last network:
+        networks.extend(current);
+
+        Ok(networks)
+    }
+
+    fn parse_header(line: &str, line_number: usize) -> Result<Self, QcnError> {
+        let invalid = || QcnError::InvalidHeader { line: line_number };
+
+        let (index, comment) = match line.split_once('#') {
+            Some((index, comment)) => (index, Some(comment.trim())),
+            None => (line, None),
+        };
+
+        let node_count = match index.trim() {
+            "-1" => 0,
+            index => {
+                let highest_index: usize = index.parse().map_err(|_| invalid())?;
+                highest_index.checked_add(1).ok_or_else(invalid)?
+            }
+        };
+
+        Ok(Self {
+            node_count,
+            comment: comment
+                .filter(|comment| !comment.is_empty())
+                .map(Into::into),
+            constraints: BTreeMap::new(),
+        })
+    }
+
+    fn parse_constraint(&mut self, line: &str, line_number: usize) -> Result<(), QcnError> {
+        let invalid = || QcnError::InvalidConstraint { line: line_number };
+
+        let (nodes, rest) = line.split_once('(').ok_or_else(invalid)?;
+        let (relations, rest) = rest.split_once(')').ok_or_else(invalid)?;
+
+        let rest = rest.trim();
+        if !rest.is_empty() && !rest.starts_with('#') {
+            return Err(invalid());
+        }
+
+        let mut nodes = nodes.split_whitespace().map(str::parse::<usize>);
+        let (Some(Ok(s)), Some(Ok(t)), None) = (nodes.next(), nodes.next(), nodes.next()) else {
+            return Err(invalid());
+        };
+
+        for node in [s, t] {
+            if node >= self.node_count {
+                return Err(QcnError::NodeOutOfRange {
+                    line: line_number,
+                    node,
+                });
+            }
+        }
+
+        let relations = relations
+            .split_whitespace()
+            .map(|relation| {
+                relation
+                    .parse::<Relation>()
+                    .map_err(|error| QcnError::InvalidRelation {
+                        line: line_number,
+                        error,
+                    })
+            })
+            .collect::<Result<RelationSet, _>>()?;
+
+        self.constrain(s, t, relations);
+
+        Ok(())
+    }
+
+    fn assert_node(&self, node: usize) {
+        assert!(
+            node < self.node_count,
+            "node {node} out of range for network of {} nodes",
+            self.node_count
+        );
+    }
+}
+
+/// Formats the network in the GQR dialect.
+impl Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(Dialect::Gqr).fmt(f)
+    }
+}
+
+/// Parses a single network.
+impl FromStr for Network {
+    type Err = QcnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut networks = Self::parse_all(s)?;
+
+        match networks.len() {
+            1 => Ok(networks.remove(0)),
+            count => Err(QcnError::NetworkCount { count }),
+        }
+    }
+}
+
+/// Formats a [`Network`] using a [`Dialect`]'s relation symbols.
+///
+/// Created by [`Network::display`].
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayNetwork<'a> {
+    network: &'a Network,
+    dialect: Dialect,
+}
+
+impl Display for DisplayNetwork<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { network, dialect } = self;
+
+        match network.node_count.checked_sub(1) {
+            Some(highest_index) => write!(f, "{highest_index}")?,
+            None => write!(f, "-1")?,
+        }
+        if let Some(comment) = &network.comment {
+            write!(f, " # {comment}")?;
+        }
+        writeln!(f)?;
+
+        for (s, t, relations) in network.constraints() {
+            write!(f, "{s} {t} (")?;
+            for relation in relations {
+                write!(f, " {}", dialect.symbol(relation))?;
+            }
+            writeln!(f, " )")?;
+        }
+
+        writeln!(f, ".")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_helpers::set, Interval};
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let network: Network = "\
+# leading comment
+3 # example
+0 1 ( < m o )
+1 2 (d)
+3 0 ( > ) # trailing comment
+.
+"
+        .parse()
+        .unwrap();
+
+        assert_eq!(network.node_count(), 4);
+        assert_eq!(network.comment(), Some("example"));
+        assert_eq!(network.get(0, 1), set("{p, m, o}"));
+        assert_eq!(network.get(1, 0), set("{pi, mi, oi}"));
+        assert_eq!(network.get(1, 2), set("{d}"));
+        assert_eq!(network.get(0, 3), set("{p}"));
+        assert_eq!(network.get(0, 2), RelationSet::all());
+        assert_eq!(network.get(2, 2), set("{eq}"));
+    }
+
+    #[test]
+    fn parse_sparq_symbols() {
+        let network: Network = "1\n0 1 ( b bi eq )\n.".parse().unwrap();
+
+        assert_eq!(network.get(0, 1), set("{p, eq, pi}"));
+    }
+
+    #[test]
+    fn parse_all() {
+        let networks = Network::parse_all("1\n0 1 ( < )\n.\n\n2 # second\n1 2 ( = )\n.\n").unwrap();
+
+        assert_eq!(networks.len(), 2);
+        assert_eq!(networks[0].get(0, 1), set("{p}"));
+        assert_eq!(networks[1].comment(), Some("second"));
+
+        assert_eq!(
+            "1\n.\n1\n.\n".parse::<Network>(),
+            Err(QcnError::NetworkCount { count: 2 })
+        );
+        assert_eq!(
+            "".parse::<Network>(),
+            Err(QcnError::NetworkCount { count: 0 })
+        );
+    }
+
+    #[test]
+    fn parse_intersects_repeated_constraints() {
+        let network: Network = "1\n0 1 ( < m o )\n1 0 ( mi oi d )\n.".parse().unwrap();
+
+        assert_eq!(network.get(0, 1), set("{m, o}"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "x\n.".parse::<Network>(),
+            Err(QcnError::InvalidHeader { line: 1 })
+        );
+        assert_eq!(
+            "1\n0 1 < )\n.".parse::<Network>(),
+            Err(QcnError::InvalidConstraint { line: 2 })
+        );
+        assert_eq!(
+            "1\n0 ( < )\n.".parse::<Network>(),
+            Err(QcnError::InvalidConstraint { line: 2 })
+        );
+        assert_eq!(
+            "1\n0 1 ( < ) x\n.".parse::<Network>(),
+            Err(QcnError::InvalidConstraint { line: 2 })
+        );
+        assert_eq!(
+            "1\n0 1 ( < x )\n.".parse::<Network>(),
+            Err(QcnError::InvalidRelation {
+                line: 2,
+                error: ParseRelationError::InvalidRelation
+            })
+        );
+        assert_eq!(
+            "1\n0 2 ( < )\n.".parse::<Network>(),
+            Err(QcnError::NodeOutOfRange { line: 2, node: 2 })
+        );
+    }
+
+    #[test]
+    fn display() {
+        let mut network = Network::new(3).with_comment("example");
+        network.constrain(0, 1, set("{p, m, eq}"));
+        network.constrain(2, 1, set("{pi}"));
+
+        assert_eq!(
+            network.to_string(),
+            "2 # example\n0 1 ( < m = )\n1 2 ( < )\n.\n"
+        );
+        assert_eq!(
+            network.display(Dialect::Sparq).to_string(),
+            "2 # example\n0 1 ( b m eq )\n1 2 ( b )\n.\n"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut network = Network::new(4);
+        network.constrain(0, 3, RelationSet::all().complement());
+        network.constrain(1, 2, set("{fi, di, s, f, oi}"));
+
+        for dialect in [Dialect::Gqr, Dialect::Sparq] {
+            let text = network.display(dialect).to_string();
+            assert_eq!(text.parse::<Network>(), Ok(network.clone()));
+        }
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        let network = Network::new(0).with_comment("empty");
+
+        let text = network.to_string();
+        assert_eq!(text, "-1 # empty\n.\n");
+        assert_eq!(text.parse::<Network>(), Ok(network));
+
+        assert_eq!(
+            "0\n.\n"
+                .parse::<Network>()
+                .map(|network| network.node_count()),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn from_intervals() {
+        let intervals: Vec<NonEmpty<_>> = [(1, 3), (3, 5), (2, 4)]
+            .into_iter()
+            .map(|(start, end)| Interval { start, end }.try_into().unwrap())
+            .collect();
+
+        let network = Network::from_intervals(&intervals);
+
+        assert_eq!(network.node_count(), 3);
+        assert_eq!(network.get(0, 1), set("{m}"));
+        assert_eq!(network.get(2, 0), set("{oi}"));
+        assert_eq!(network.get(1, 2), set("{oi}"));
+    }
+
+    #[test]
+    #[should_panic(expected = "node 2 out of range")]
+    fn get_out_of_range() {
+        Network::new(2).get(0, 2);
+    }
+}
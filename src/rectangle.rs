@@ -0,0 +1,359 @@
+//! The rectangle algebra, relating axis-aligned rectangles by an Allen relation per axis.
+//!
+//! > Balbiani, P., Condotta, J. F., & Fariñas del Cerro, L. (1998).
+//! > A model for reasoning about bidimensional temporal relations.
+//! > In KR'98 (pp. 124-130).
+//!
+//! Pairing the thirteen relations of the x axis with the thirteen relations of the y axis
+//! yields 169 basic relations, from which the topological relations
+//! (e.g. whether rectangles are disjoint, touch, overlap or are inside one another) are derived.
+//!
+//! ```
+//! use allen_intervals::{
+//!     rectangle::{Rectangle, RectangleRelation, Topology},
+//!     Interval, NonEmpty, Relation,
+//! };
+//!
+//! let rectangle = |x: (i32, i32), y: (i32, i32)| {
+//!     Rectangle::new(
+//!         NonEmpty::try_from(Interval { start: x.0, end: x.1 }).unwrap(),
+//!         NonEmpty::try_from(Interval { start: y.0, end: y.1 }).unwrap(),
+//!     )
+//! };
+//!
+//! let page = rectangle((0, 100), (0, 200));
+//! let header = rectangle((0, 100), (0, 20));
+//! let body = rectangle((10, 90), (20, 190));
+//!
+//! let relation = RectangleRelation::from_rectangles(&header, &page);
+//! assert_eq!(relation.x(), Relation::Equals);
+//! assert_eq!(relation.y(), Relation::Starts { is_inverted: false });
+//! assert!(relation.is_inside());
+//!
+//! let relation = RectangleRelation::from_rectangles(&header, &body);
+//! assert_eq!(relation.topology(), Topology::Touching);
+//! ```
+
+use core::fmt::{self, Display};
+
+use crate::{FromIntervals, IntervalError, NonEmpty, Relation, TryFromIntervals};
+
+/// An axis-aligned rectangle, of a non-empty interval per axis.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Rectangle<X, Y> {
+    /// The rectangle's extent along the x axis.
+    pub x: NonEmpty<X>,
+    /// The rectangle's extent along the y axis.
+    pub y: NonEmpty<Y>,
+}
+
+impl<X, Y> Rectangle<X, Y> {
+    /// Creates a rectangle from its extents along the x and y axes.
+    #[inline]
+    pub const fn new(x: NonEmpty<X>, y: NonEmpty<Y>) -> Self {
+        Self { x, y }
+    }
+}
+
+/// The topological relation between two rectangles (e.g. `s` and `t`),
+/// as in the region connection calculus (RCC8).
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Topology {
+    /// The rectangles share no points (RCC8's `DC`).
+    Disjoint,
+    /// The rectangles share boundary points, but no interior points (RCC8's `EC`).
+    Touching,
+    /// The rectangles share interior points, but neither is inside the other (RCC8's `PO`).
+    Overlapping,
+    /// `s` is inside `t`, touching its boundary (RCC8's `TPP`).
+    TangentiallyInside,
+    /// `s` is inside `t`, not touching its boundary (RCC8's `NTPP`).
+    Inside,
+    /// The rectangles are equal (RCC8's `EQ`).
+    Equal,
+    /// `t` is inside `s`, touching its boundary (RCC8's `TPPi`).
+    TangentiallyContains,
+    /// `t` is inside `s`, not touching its boundary (RCC8's `NTPPi`).
+    Contains,
+}
+
+impl Topology {
+    /// Returns the topological relation between boxes of any dimension,
+    /// given the Allen relation of their extents along each axis.
+    pub(crate) fn from_axes(axes: &[Relation]) -> Self {
+        let is_any = |f: fn(Relation) -> bool| axes.iter().copied().any(f);
+        let is_all = |f: fn(Relation) -> bool| axes.iter().copied().all(f);
+
+        if is_any(|relation| matches!(relation, Relation::Precedes { .. })) {
+            Self::Disjoint
+        } else if is_any(|relation| matches!(relation, Relation::Meets { .. })) {
+            Self::Touching
+        } else if is_all(|relation| relation == Relation::Equals) {
+            Self::Equal
+        } else if is_all(|relation| relation == Relation::Contains { is_inverted: true }) {
+            Self::Inside
+        } else if is_all(|relation| relation == Relation::Contains { is_inverted: false }) {
+            Self::Contains
+        } else if is_all(is_within) {
+            Self::TangentiallyInside
+        } else if is_all(|relation| is_within(relation.as_converse())) {
+            Self::TangentiallyContains
+        } else {
+            Self::Overlapping
+        }
+    }
+}
+
+/// A basic relation of the rectangle algebra between two rectangles (e.g. `s` and `t`),
+/// i.e. the Allen relation of their extents along each axis.
+///
+/// The relations are comparable (via `Ord`) by their relation along the x axis
+/// and then within that by their relation along the y axis.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct RectangleRelation {
+    x: Relation,
+    y: Relation,
+}
+
+impl RectangleRelation {
+    /// All 169 basic relations, in ascending order.
+    pub const ALL: [Self; 169] = {
+        let mut relations = [Self::new(Relation::Equals, Relation::Equals); 169];
+
+        let mut index = 0;
+        while index < relations.len() {
+            relations[index] = Self::new(Relation::ALL[index / 13], Relation::ALL[index % 13]);
+            index += 1;
+        }
+
+        relations
+    };
+
+    /// Creates the relation of the Allen relations along the x and y axes.
+    #[inline]
+    pub const fn new(x: Relation, y: Relation) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the relation between the rectangles `s` and `t`.
+    #[inline]
+    pub fn from_rectangles<SX, SY, TX, TY>(s: &Rectangle<SX, SY>, t: &Rectangle<TX, TY>) -> Self
+    where
+        Relation: FromIntervals<SX, TX> + FromIntervals<SY, TY>,
+    {
+        Self {
+            x: Relation::from_intervals(&s.x, &t.x),
+            y: Relation::from_intervals(&s.y, &t.y),
+        }
+    }
+
+    /// Returns the relation between the rectangles `s` and `t`,
+    /// or an error if any of their endpoints are not totally ordered.
+    #[inline]
+    pub fn try_from_rectangles<SX, SY, TX, TY>(
+        s: &Rectangle<SX, SY>,
+        t: &Rectangle<TX, TY>,
+    ) -> Result<Self, IntervalError>
+    where
+        Relation: TryFromIntervals<SX, TX> + TryFromIntervals<SY, TY>,
+    {
+        Ok(Self {
+            x: Relation::try_from_intervals(&s.x, &t.x)?,
+            y: Relation::try_from_intervals(&s.y, &t.y)?,
+        })
+    }
+
+    /// Returns the Allen relation along the x axis.
+    #[inline]
+    pub const fn x(&self) -> Relation {
+        self.x
+    }
+
+    /// Returns the Allen relation along the y axis.
+    #[inline]
+    pub const fn y(&self) -> Relation {
+        self.y
+    }
+
+    /// Returns the relation's converse.
+    #[inline]
+    pub const fn as_converse(&self) -> Self {
+        Self {
+            x: self.x.as_converse(),
+            y: self.y.as_converse(),
+        }
+    }
+
+    /// Returns the topological relation between the rectangles.
+    #[inline]
+    pub fn topology(&self) -> Topology {
+        Topology::from_axes(&[self.x, self.y])
+    }
+
+    /// Returns `true` iff the rectangles share no points.
+    #[inline]
+    pub fn is_disjoint(&self) -> bool {
+        self.topology() == Topology::Disjoint
+    }
+
+    /// Returns `true` iff the rectangles share boundary points, but no interior points.
+    #[inline]
+    pub fn is_touching(&self) -> bool {
+        self.topology() == Topology::Touching
+    }
+
+    /// Returns `true` iff the rectangles share interior points
+    /// (including if one is inside the other).
+    #[inline]
+    pub fn is_overlapping(&self) -> bool {
+        !matches!(self.topology(), Topology::Disjoint | Topology::Touching)
+    }
+
+    /// Returns `true` iff `s` is inside (or equal to) `t`.
+    #[inline]
+    pub fn is_inside(&self) -> bool {
+        matches!(
+            self.topology(),
+            Topology::Inside | Topology::TangentiallyInside | Topology::Equal
+        )
+    }
+
+    /// Returns `true` iff `t` is inside (or equal to) `s`.
+    #[inline]
+    pub fn is_containing(&self) -> bool {
+        self.as_converse().is_inside()
+    }
+}
+
+/// Returns `true` iff `relation` places `s` within `t` along an axis.
+fn is_within(relation: Relation) -> bool {
+    matches!(
+        relation,
+        Relation::Starts { is_inverted: false }
+            | Relation::Contains { is_inverted: true }
+            | Relation::Finishes { is_inverted: false }
+            | Relation::Equals
+    )
+}
+
+/// Formats as the pair of Allen relations (e.g. `(o, d)`),
+/// using their full names for the alternate (`{:#}`) format.
+impl Display for RectangleRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "({:#}, {:#})", self.x, self.y)
+        } else {
+            write!(f, "({}, {})", self.x, self.y)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Interval, IntervalFrom, IntervalTo};
+
+    use super::*;
+
+    type Rect = Rectangle<Interval<i32>, Interval<i32>>;
+
+    fn rectangle(x: (i32, i32), y: (i32, i32)) -> Rect {
+        let interval = |(start, end)| Interval { start, end }.try_into().unwrap();
+        Rectangle::new(interval(x), interval(y))
+    }
+
+    #[test]
+    fn all() {
+        let mut relations = RectangleRelation::ALL;
+        relations.sort();
+        assert_eq!(relations, RectangleRelation::ALL);
+
+        for x in Relation::ALL {
+            for y in Relation::ALL {
+                assert!(RectangleRelation::ALL.contains(&RectangleRelation::new(x, y)));
+            }
+        }
+    }
+
+    #[test]
+    fn from_rectangles() {
+        let s = rectangle((0, 4), (0, 4));
+        let t = rectangle((2, 6), (4, 8));
+
+        let relation = RectangleRelation::from_rectangles(&s, &t);
+
+        assert_eq!(relation.x(), Relation::Overlaps { is_inverted: false });
+        assert_eq!(relation.y(), Relation::Meets { is_inverted: false });
+        assert_eq!(
+            RectangleRelation::from_rectangles(&t, &s),
+            relation.as_converse()
+        );
+        assert_eq!(RectangleRelation::try_from_rectangles(&s, &t), Ok(relation));
+        assert_eq!(relation.to_string(), "(o, m)");
+        assert_eq!(format!("{relation:#}"), "(overlaps, meets)");
+    }
+
+    #[test]
+    fn mixed_interval_types() {
+        let column: Rectangle<Interval<i32>, IntervalFrom<i32>> = Rectangle::new(
+            Interval { start: 0, end: 4 }.try_into().unwrap(),
+            IntervalFrom { start: 0 }.into(),
+        );
+        let cell = rectangle((1, 3), (5, 6));
+        let margin: Rectangle<IntervalTo<i32>, Interval<i32>> = Rectangle::new(
+            IntervalTo { end: 0 }.into(),
+            Interval { start: 0, end: 9 }.try_into().unwrap(),
+        );
+
+        assert_eq!(
+            RectangleRelation::from_rectangles(&cell, &column).topology(),
+            Topology::Inside
+        );
+        assert_eq!(
+            RectangleRelation::from_rectangles(&margin, &column).topology(),
+            Topology::Touching
+        );
+    }
+
+    #[test]
+    fn topology() {
+        let s = rectangle((2, 6), (2, 6));
+
+        let topology = |x, y| RectangleRelation::from_rectangles(&s, &rectangle(x, y)).topology();
+
+        assert_eq!(topology((7, 9), (0, 9)), Topology::Disjoint);
+        assert_eq!(topology((6, 9), (3, 5)), Topology::Touching);
+        assert_eq!(topology((6, 9), (6, 9)), Topology::Touching);
+        assert_eq!(topology((4, 9), (4, 9)), Topology::Overlapping);
+        assert_eq!(topology((3, 5), (0, 9)), Topology::Overlapping);
+        assert_eq!(topology((2, 6), (2, 6)), Topology::Equal);
+        assert_eq!(topology((0, 9), (0, 9)), Topology::Inside);
+        assert_eq!(topology((2, 9), (0, 9)), Topology::TangentiallyInside);
+        assert_eq!(topology((3, 5), (3, 5)), Topology::Contains);
+        assert_eq!(topology((2, 5), (3, 6)), Topology::TangentiallyContains);
+    }
+
+    #[test]
+    fn predicates() {
+        for relation in RectangleRelation::ALL {
+            let topology = relation.topology();
+
+            assert_eq!(relation.is_disjoint(), topology == Topology::Disjoint);
+            assert_eq!(relation.is_touching(), topology == Topology::Touching);
+            assert_eq!(
+                relation.is_overlapping(),
+                !relation.is_disjoint() && !relation.is_touching()
+            );
+            assert_eq!(relation.is_containing(), relation.as_converse().is_inside());
+
+            if relation.is_inside() && relation.is_containing() {
+                assert_eq!(topology, Topology::Equal);
+            }
+        }
+
+        let inside = RectangleRelation::ALL
+            .iter()
+            .filter(|relation| relation.is_inside())
+            .count();
+        assert_eq!(inside, 16);
+    }
+}
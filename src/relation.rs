@@ -5,35 +5,23 @@ use crate::{
     IntervalFull, IntervalTo, NonEmpty, TryFromIntervals,
 };
 
+mod code;
+mod composition;
 mod contains;
 mod equals;
 mod finishes;
 mod meets;
+mod notation;
 mod overlaps;
 mod precedes;
+mod set;
 mod starts;
 
 pub use self::{
-    contains::*, equals::*, finishes::*, meets::*, overlaps::*, precedes::*, starts::*,
+    code::*, contains::*, equals::*, finishes::*, meets::*, notation::*, overlaps::*, precedes::*,
+    set::*, starts::*,
 };
 
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
-enum RelationOrder {
-    Precedes,
-    Meets,
-    Overlaps,
-    IsFinishedBy,
-    Contains,
-    Starts,
-    Equals,
-    IsStartedBy,
-    IsContainedBy,
-    Finishes,
-    IsOverlappedBy,
-    IsMetBy,
-    IsPrecededBy,
-}
-
 /// A type describing the possible relations between two intervals (e.g. `s` and `t`).
 ///
 /// The relations are comparable (via `Ord`) by the degree to which `s` begins before `t` and then within that by the degree to which `s` ends before `t`.
@@ -171,8 +159,25 @@ pub enum Relation {
 }
 
 impl Relation {
+    /// All thirteen basic relations, in ascending order.
+    pub const ALL: [Self; 13] = [
+        Self::Precedes { is_inverted: false },
+        Self::Meets { is_inverted: false },
+        Self::Overlaps { is_inverted: false },
+        Self::Finishes { is_inverted: true },
+        Self::Contains { is_inverted: false },
+        Self::Starts { is_inverted: false },
+        Self::Equals,
+        Self::Starts { is_inverted: true },
+        Self::Contains { is_inverted: true },
+        Self::Finishes { is_inverted: false },
+        Self::Overlaps { is_inverted: true },
+        Self::Meets { is_inverted: true },
+        Self::Precedes { is_inverted: true },
+    ];
+
     #[inline]
-    fn from_bounds<T>(s: &Bounds<T>, t: &Bounds<T>) -> Self
+    pub(crate) fn from_bounds<T>(s: &Bounds<T>, t: &Bounds<T>) -> Self
     where
         T: Ord,
     {
@@ -185,7 +190,7 @@ impl Relation {
     }
 
     #[inline]
-    fn try_from_bounds<T>(s: &Bounds<T>, t: &Bounds<T>) -> Result<Self, IntervalError>
+    pub(crate) fn try_from_bounds<T>(s: &Bounds<T>, t: &Bounds<T>) -> Result<Self, IntervalError>
     where
         T: PartialOrd,
     {
@@ -208,7 +213,7 @@ impl Relation {
     /// > An efficient approach for the generation of Allen relations.
     /// > In ECAI 2016 (pp. 948-956). IOS Press.
     #[inline]
-    fn from_atomic_relations(bb: Bb, be: Be, eb: Eb, ee: Ee) -> Self {
+    pub(crate) const fn from_atomic_relations(bb: Bb, be: Be, eb: Eb, ee: Ee) -> Self {
         use Ordering::*;
 
         match (bb.0, be.0, eb.0, ee.0) {
@@ -261,27 +266,34 @@ impl Relation {
         }
     }
 
-    fn order(&self) -> RelationOrder {
-        match self {
-            Relation::Precedes { is_inverted: false } => RelationOrder::Precedes,
-            Relation::Precedes { is_inverted: true } => RelationOrder::IsPrecededBy,
-            Relation::Meets { is_inverted: false } => RelationOrder::Meets,
-            Relation::Meets { is_inverted: true } => RelationOrder::IsMetBy,
-            Relation::Overlaps { is_inverted: false } => RelationOrder::Overlaps,
-            Relation::Overlaps { is_inverted: true } => RelationOrder::IsOverlappedBy,
-            Relation::Finishes { is_inverted: false } => RelationOrder::Finishes,
-            Relation::Finishes { is_inverted: true } => RelationOrder::IsFinishedBy,
-            Relation::Contains { is_inverted: false } => RelationOrder::Contains,
-            Relation::Contains { is_inverted: true } => RelationOrder::IsContainedBy,
-            Relation::Starts { is_inverted: false } => RelationOrder::IsStartedBy,
-            Relation::Starts { is_inverted: true } => RelationOrder::Starts,
-            Relation::Equals => RelationOrder::Equals,
-        }
+    /// Returns the atomic relations implied by the relation
+    /// (i.e. the inverse of [`Self::from_atomic_relations`]).
+    #[inline]
+    pub(crate) const fn atomic_relations(&self) -> (Bb, Be, Eb, Ee) {
+        use Ordering::*;
+
+        let (bb, be, eb, ee) = match self {
+            Self::Precedes { is_inverted: false } => (Less, Less, Less, Less),
+            Self::Meets { is_inverted: false } => (Less, Less, Equal, Less),
+            Self::Overlaps { is_inverted: false } => (Less, Less, Greater, Less),
+            Self::Finishes { is_inverted: true } => (Less, Less, Greater, Equal),
+            Self::Contains { is_inverted: false } => (Less, Less, Greater, Greater),
+            Self::Starts { is_inverted: false } => (Equal, Less, Greater, Less),
+            Self::Equals => (Equal, Less, Greater, Equal),
+            Self::Starts { is_inverted: true } => (Equal, Less, Greater, Greater),
+            Self::Contains { is_inverted: true } => (Greater, Less, Greater, Less),
+            Self::Finishes { is_inverted: false } => (Greater, Less, Greater, Equal),
+            Self::Overlaps { is_inverted: true } => (Greater, Less, Greater, Greater),
+            Self::Meets { is_inverted: true } => (Greater, Equal, Greater, Greater),
+            Self::Precedes { is_inverted: true } => (Greater, Greater, Greater, Greater),
+        };
+
+        (Bb(bb), Be(be), Eb(eb), Ee(ee))
     }
 
     /// Returns the relation's converse.
-    pub fn as_converse(&self) -> Self {
-        match self {
+    pub const fn as_converse(&self) -> Self {
+        match *self {
             Self::Precedes { is_inverted } => Self::Precedes {
                 is_inverted: !is_inverted,
             },
@@ -308,7 +320,7 @@ impl Relation {
 impl Ord for Relation {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        self.order().cmp(&other.order())
+        BasicRelation::from(*self).cmp(&BasicRelation::from(*other))
     }
 }
 
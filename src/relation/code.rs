@@ -0,0 +1,241 @@
+use crate::Relation;
+
+/// Error type for converting invalid codes into relations (or sets thereof).
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RelationCodeError {
+    /// The code is not a relation's code (i.e. not within `0..=12`).
+    #[cfg_attr(
+        feature = "std",
+        error("invalid relation code {0}, expected a code within `0..=12`")
+    )]
+    InvalidCode(u8),
+    /// The mask contains bits beyond the thirteen relations' bits (i.e. above bit `12`).
+    #[cfg_attr(
+        feature = "std",
+        error("invalid relation set mask {0:#06x}, expected a mask within `0x0000..=0x1fff`")
+    )]
+    InvalidMask(u16),
+}
+
+/// A flat (i.e. field-less) representation of [`Relation`],
+/// with variants in ascending (i.e. Allen's) order.
+///
+/// Each variant's discriminant is its stable code (i.e. its index in [`Relation::ALL`]),
+/// which is guaranteed not to change between releases:
+///
+/// | Code | Variant          | Abbreviation |
+/// |------|------------------|--------------|
+/// | 0    | `Precedes`       | `p`          |
+/// | 1    | `Meets`          | `m`          |
+/// | 2    | `Overlaps`       | `o`          |
+/// | 3    | `IsFinishedBy`   | `fi`         |
+/// | 4    | `Contains`       | `di`         |
+/// | 5    | `Starts`         | `s`          |
+/// | 6    | `Equals`         | `eq`         |
+/// | 7    | `IsStartedBy`    | `si`         |
+/// | 8    | `IsContainedBy`  | `d`          |
+/// | 9    | `Finishes`       | `f`          |
+/// | 10   | `IsOverlappedBy` | `oi`         |
+/// | 11   | `IsMetBy`        | `mi`         |
+/// | 12   | `IsPrecededBy`   | `pi`         |
+///
+/// ```
+/// use allen_intervals::{BasicRelation, Relation};
+///
+/// let relation = Relation::Contains { is_inverted: true };
+///
+/// assert_eq!(BasicRelation::from(relation), BasicRelation::IsContainedBy);
+/// assert_eq!(u8::from(relation), 8);
+/// assert_eq!(Relation::try_from(8), Ok(relation));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum BasicRelation {
+    /// "s precedes t" (i.e. `Relation::Precedes { is_inverted: false }`).
+    Precedes = 0,
+    /// "s meets t" (i.e. `Relation::Meets { is_inverted: false }`).
+    Meets = 1,
+    /// "s overlaps t" (i.e. `Relation::Overlaps { is_inverted: false }`).
+    Overlaps = 2,
+    /// "s is finished by t" (i.e. `Relation::Finishes { is_inverted: true }`).
+    IsFinishedBy = 3,
+    /// "s contains t" (i.e. `Relation::Contains { is_inverted: false }`).
+    Contains = 4,
+    /// "s starts t" (i.e. `Relation::Starts { is_inverted: false }`).
+    Starts = 5,
+    /// "s equals t" (i.e. `Relation::Equals`).
+    Equals = 6,
+    /// "s is started by t" (i.e. `Relation::Starts { is_inverted: true }`).
+    IsStartedBy = 7,
+    /// "s is contained by t" (i.e. `Relation::Contains { is_inverted: true }`).
+    IsContainedBy = 8,
+    /// "s finishes t" (i.e. `Relation::Finishes { is_inverted: false }`).
+    Finishes = 9,
+    /// "s is overlapped by t" (i.e. `Relation::Overlaps { is_inverted: true }`).
+    IsOverlappedBy = 10,
+    /// "s is met by t" (i.e. `Relation::Meets { is_inverted: true }`).
+    IsMetBy = 11,
+    /// "s is preceded by t" (i.e. `Relation::Precedes { is_inverted: true }`).
+    IsPrecededBy = 12,
+}
+
+impl BasicRelation {
+    /// All thirteen basic relations, in ascending order (i.e. indexed by their codes).
+    pub const ALL: [Self; 13] = [
+        Self::Precedes,
+        Self::Meets,
+        Self::Overlaps,
+        Self::IsFinishedBy,
+        Self::Contains,
+        Self::Starts,
+        Self::Equals,
+        Self::IsStartedBy,
+        Self::IsContainedBy,
+        Self::Finishes,
+        Self::IsOverlappedBy,
+        Self::IsMetBy,
+        Self::IsPrecededBy,
+    ];
+
+    /// Returns the flat representation of `relation`.
+    #[inline]
+    pub const fn from_relation(relation: Relation) -> Self {
+        match relation {
+            Relation::Precedes { is_inverted: false } => Self::Precedes,
+            Relation::Precedes { is_inverted: true } => Self::IsPrecededBy,
+            Relation::Meets { is_inverted: false } => Self::Meets,
+            Relation::Meets { is_inverted: true } => Self::IsMetBy,
+            Relation::Overlaps { is_inverted: false } => Self::Overlaps,
+            Relation::Overlaps { is_inverted: true } => Self::IsOverlappedBy,
+            Relation::Finishes { is_inverted: false } => Self::Finishes,
+            Relation::Finishes { is_inverted: true } => Self::IsFinishedBy,
+            Relation::Contains { is_inverted: false } => Self::Contains,
+            Relation::Contains { is_inverted: true } => Self::IsContainedBy,
+            Relation::Starts { is_inverted: false } => Self::Starts,
+            Relation::Starts { is_inverted: true } => Self::IsStartedBy,
+            Relation::Equals => Self::Equals,
+        }
+    }
+
+    /// Returns the relation's stable code (i.e. its index in [`Relation::ALL`]).
+    #[inline]
+    pub const fn code(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl Relation {
+    /// Returns the relation's stable code (i.e. its index in [`Relation::ALL`]).
+    ///
+    /// See [`BasicRelation`] for the table of codes.
+    #[inline]
+    pub const fn code(&self) -> u8 {
+        BasicRelation::from_relation(*self).code()
+    }
+}
+
+impl From<Relation> for BasicRelation {
+    #[inline]
+    fn from(relation: Relation) -> Self {
+        Self::from_relation(relation)
+    }
+}
+
+impl From<BasicRelation> for Relation {
+    #[inline]
+    fn from(relation: BasicRelation) -> Self {
+        Self::ALL[usize::from(relation.code())]
+    }
+}
+
+impl From<BasicRelation> for u8 {
+    #[inline]
+    fn from(relation: BasicRelation) -> Self {
+        relation.code()
+    }
+}
+
+impl From<Relation> for u8 {
+    #[inline]
+    fn from(relation: Relation) -> Self {
+        relation.code()
+    }
+}
+
+impl TryFrom<u8> for BasicRelation {
+    type Error = RelationCodeError;
+
+    #[inline]
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        Self::ALL
+            .get(usize::from(code))
+            .copied()
+            .ok_or(RelationCodeError::InvalidCode(code))
+    }
+}
+
+impl TryFrom<u8> for Relation {
+    type Error = RelationCodeError;
+
+    #[inline]
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        BasicRelation::try_from(code).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RelationSet;
+
+    use super::*;
+
+    #[test]
+    fn codes_are_stable() {
+        let abbreviations = [
+            "p", "m", "o", "fi", "di", "s", "eq", "si", "d", "f", "oi", "mi", "pi",
+        ];
+
+        for (code, abbreviation) in (0_u8..).zip(abbreviations) {
+            let relation = Relation::try_from(code).unwrap();
+
+            assert_eq!(relation.abbreviation(), abbreviation);
+            assert_eq!(u8::from(relation), code);
+            assert_eq!(BasicRelation::from(relation).code(), code);
+            assert_eq!(Relation::from(BasicRelation::from(relation)), relation);
+        }
+
+        assert_eq!(
+            Relation::try_from(13),
+            Err(RelationCodeError::InvalidCode(13))
+        );
+        assert_eq!(
+            BasicRelation::try_from(u8::MAX),
+            Err(RelationCodeError::InvalidCode(u8::MAX))
+        );
+    }
+
+    #[test]
+    fn order_matches_codes() {
+        for (s, t) in BasicRelation::ALL.iter().zip(&BasicRelation::ALL[1..]) {
+            assert!(s < t);
+            assert!(Relation::from(*s) < Relation::from(*t));
+        }
+    }
+
+    #[test]
+    fn masks_are_stable() {
+        let set: RelationSet = "{p, eq, pi}".parse().unwrap();
+
+        assert_eq!(set.bits(), 0b1_0000_0100_0001);
+        assert_eq!(u16::from(RelationSet::all()), 0x1fff);
+        assert_eq!(RelationSet::from_bits(0b1_0000_0100_0001), Some(set));
+        assert_eq!(RelationSet::try_from(0x1fff), Ok(RelationSet::all()));
+        assert_eq!(RelationSet::from_bits(0x2000), None);
+        assert_eq!(
+            RelationSet::try_from(0xffff),
+            Err(RelationCodeError::InvalidMask(0xffff))
+        );
+        assert_eq!(RelationSet::from_bits_truncate(0xffff), RelationSet::all());
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{Interval, NonEmpty, Relation, RelationSet};
+
+/// The composition table of the thirteen basic relations, indexed by their codes,
+/// containing the masks of the relations possible between `s` and `u`,
+/// given the relations of `s` to `t` and of `t` to `u`.
+///
+/// Derived by enumerating all triples of intervals with endpoints within `0..6`,
+/// which realize every ordering of three intervals' (up to six distinct) endpoints.
+const COMPOSITION: [[u16; 13]; 13] = {
+    const VALUES: u8 = 6;
+    const COUNT: usize = (VALUES as usize * (VALUES as usize - 1)) / 2;
+
+    let mut intervals = [NonEmpty(Interval {
+        start: 0_u8,
+        end: 1,
+    }); COUNT];
+
+    let mut index = 0;
+    let mut start = 0;
+    while start < VALUES {
+        let mut end = start + 1;
+        while end < VALUES {
+            intervals[index] = NonEmpty(Interval { start, end });
+            index += 1;
+            end += 1;
+        }
+        start += 1;
+    }
+
+    let mut table = [[0_u16; 13]; 13];
+
+    let mut s = 0;
+    while s < COUNT {
+        let mut t = 0;
+        while t < COUNT {
+            let st = intervals[s].relation(&intervals[t]).code() as usize;
+
+            let mut u = 0;
+            while u < COUNT {
+                let tu = intervals[t].relation(&intervals[u]).code() as usize;
+                let su = intervals[s].relation(&intervals[u]).code();
+
+                table[st][tu] |= 1 << su;
+                u += 1;
+            }
+            t += 1;
+        }
+        s += 1;
+    }
+
+    table
+};
+
+impl Relation {
+    /// Returns the relations possible between `s` and `u`,
+    /// given that `s` stands in `self` to `t`, and `t` stands in `other` to `u`
+    /// (i.e. the entry of Allen's composition table).
+    ///
+    /// ```
+    /// use allen_intervals::{Relation, RelationSet};
+    ///
+    /// let during = Relation::Contains { is_inverted: true };
+    /// let meets = Relation::Meets { is_inverted: false };
+    ///
+    /// assert_eq!(during.compose(&meets), "{p}".parse::<RelationSet>().unwrap());
+    /// ```
+    #[inline]
+    pub const fn compose(&self, other: &Self) -> RelationSet {
+        RelationSet::from_bits_truncate(COMPOSITION[self.code() as usize][other.code() as usize])
+    }
+}
+
+impl RelationSet {
+    /// Returns the relations possible between `s` and `u`,
+    /// given that `s` stands in any of `self` to `t`, and `t` stands in any of `other` to `u`
+    /// (i.e. the union of the compositions of each pair of their relations).
+    #[inline]
+    pub fn compose(&self, other: &Self) -> Self {
+        self.iter()
+            .flat_map(|s| other.iter().map(move |t| s.compose(&t)))
+            .fold(Self::empty(), |composition, relations| {
+                composition.union(&relations)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::set;
+
+    #[test]
+    fn composition_table() {
+        // Spot checks against Allen's (1983) table:
+        let cases = [
+            ("p", "p", "{p}"),
+            ("p", "pi", "{p, m, o, fi, di, s, eq, si, d, f, oi, mi, pi}"),
+            ("m", "m", "{p}"),
+            ("m", "mi", "{fi, eq, f}"),
+            ("o", "o", "{p, m, o}"),
+            ("o", "oi", "{o, fi, di, s, eq, si, d, f, oi}"),
+            ("d", "d", "{d}"),
+            ("d", "di", "{p, m, o, fi, di, s, eq, si, d, f, oi, mi, pi}"),
+            ("d", "p", "{p}"),
+            ("s", "si", "{s, eq, si}"),
+            ("f", "fi", "{fi, eq, f}"),
+            ("di", "d", "{o, fi, di, s, eq, si, d, f, oi}"),
+            ("o", "d", "{o, s, d}"),
+        ];
+
+        for (s, t, expected) in cases {
+            let s: Relation = s.parse().unwrap();
+            let t: Relation = t.parse().unwrap();
+
+            assert_eq!(s.compose(&t), set(expected), "{s} ∘ {t}");
+        }
+    }
+
+    #[test]
+    fn identity_and_converse() {
+        for relation in Relation::ALL {
+            assert_eq!(relation.compose(&Relation::Equals), relation.into());
+            assert_eq!(Relation::Equals.compose(&relation), relation.into());
+
+            for other in Relation::ALL {
+                assert_eq!(
+                    relation.compose(&other).as_converse(),
+                    other.as_converse().compose(&relation.as_converse())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn set_composition() {
+        assert_eq!(set("{p, m}").compose(&set("{p}")), set("{p}"));
+        assert_eq!(set("{}").compose(&set("{p}")), set("{}"));
+        assert_eq!(set("{s, d}").compose(&set("{f}")), set("{d}"));
+        assert_eq!(
+            RelationSet::all().compose(&RelationSet::all()),
+            RelationSet::all()
+        );
+    }
+}
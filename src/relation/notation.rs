@@ -0,0 +1,283 @@
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{Relation, RelationSet};
+
+/// Error type for parsing relations (and sets thereof) from their textual notation.
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ParseRelationError {
+    /// The input is neither a known abbreviation, nor a known name of a relation.
+    #[cfg_attr(
+        feature = "std",
+        error("expected a relation's abbreviation (e.g. `oi`), or name (e.g. `is overlapped by`)")
+    )]
+    InvalidRelation,
+    /// The input is not a set of relations in set notation (e.g. `{p, m, o}`).
+    #[cfg_attr(
+        feature = "std",
+        error("expected a set of relations in set notation (e.g. `{{p, m, o}}`)")
+    )]
+    InvalidSet,
+}
+
+/// Accepted abbreviations (in addition to the canonical ones) for each relation, in `Relation::ALL` order.
+const ALTERNATIVE_ABBREVIATIONS: [&[&str]; 13] = [
+    &["b", "<"],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &["e", "="],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &["bi", "a", ">"],
+];
+
+/// Accepted names (in addition to the canonical ones) for each relation, in `Relation::ALL` order.
+const ALTERNATIVE_NAMES: [&[&str]; 13] = [
+    &["before"],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &["equal"],
+    &[],
+    &["during"],
+    &[],
+    &[],
+    &[],
+    &["after"],
+];
+
+impl Relation {
+    /// Returns the relation's conventional abbreviation
+    /// (i.e. one of `p`, `m`, `o`, `fi`, `di`, `s`, `eq`, `si`, `d`, `f`, `oi`, `mi`, `pi`).
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::Precedes { is_inverted: false } => "p",
+            Self::Precedes { is_inverted: true } => "pi",
+            Self::Meets { is_inverted: false } => "m",
+            Self::Meets { is_inverted: true } => "mi",
+            Self::Overlaps { is_inverted: false } => "o",
+            Self::Overlaps { is_inverted: true } => "oi",
+            Self::Finishes { is_inverted: false } => "f",
+            Self::Finishes { is_inverted: true } => "fi",
+            Self::Contains { is_inverted: false } => "di",
+            Self::Contains { is_inverted: true } => "d",
+            Self::Starts { is_inverted: false } => "s",
+            Self::Starts { is_inverted: true } => "si",
+            Self::Equals => "eq",
+        }
+    }
+
+    /// Returns the relation's full English name (e.g. `is overlapped by`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Precedes { is_inverted: false } => "precedes",
+            Self::Precedes { is_inverted: true } => "is preceded by",
+            Self::Meets { is_inverted: false } => "meets",
+            Self::Meets { is_inverted: true } => "is met by",
+            Self::Overlaps { is_inverted: false } => "overlaps",
+            Self::Overlaps { is_inverted: true } => "is overlapped by",
+            Self::Finishes { is_inverted: false } => "finishes",
+            Self::Finishes { is_inverted: true } => "is finished by",
+            Self::Contains { is_inverted: false } => "contains",
+            Self::Contains { is_inverted: true } => "is contained by",
+            Self::Starts { is_inverted: false } => "starts",
+            Self::Starts { is_inverted: true } => "is started by",
+            Self::Equals => "equals",
+        }
+    }
+}
+
+/// Formats as the relation's abbreviation (e.g. `oi`),
+/// or as its full name (e.g. `is overlapped by`) for the alternate (`{:#}`) format.
+impl Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.pad(self.name())
+        } else {
+            f.pad(self.abbreviation())
+        }
+    }
+}
+
+/// Parses any of the relation's abbreviations
+/// (e.g. `p`, as well as `b` or `<` for "precedes"),
+/// or any of its names (e.g. `precedes`, as well as `before`), ignoring case.
+///
+/// Words of a name may be separated by spaces, underscores or hyphens (e.g. `is_met_by`).
+impl FromStr for Relation {
+    type Err = ParseRelationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let matches_name = |name: &str| {
+            let mut words = s
+                .split(|c: char| c.is_whitespace() || c == '_' || c == '-')
+                .filter(|word| !word.is_empty());
+
+            name.split(' ').all(|expected| {
+                words
+                    .next()
+                    .is_some_and(|word| word.eq_ignore_ascii_case(expected))
+            }) && words.next().is_none()
+        };
+
+        Self::ALL
+            .into_iter()
+            .zip(ALTERNATIVE_ABBREVIATIONS.into_iter().zip(ALTERNATIVE_NAMES))
+            .find_map(|(relation, (abbreviations, names))| {
+                let is_match = core::iter::once(relation.abbreviation())
+                    .chain(abbreviations.iter().copied())
+                    .any(|abbreviation| s.eq_ignore_ascii_case(abbreviation))
+                    || core::iter::once(relation.name())
+                        .chain(names.iter().copied())
+                        .any(matches_name);
+
+                is_match.then_some(relation)
+            })
+            .ok_or(ParseRelationError::InvalidRelation)
+    }
+}
+
+/// Formats in set notation (e.g. `{p, m, o}`),
+/// using the relations' full names for the alternate (`{:#}`) format.
+impl Display for RelationSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+
+        for (index, relation) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            relation.fmt(f)?;
+        }
+
+        f.write_str("}")
+    }
+}
+
+/// Parses set notation (e.g. `{p, m, o}`), with relations separated by commas,
+/// or (in the absence of commas) by whitespace (e.g. `{p m o}`).
+impl FromStr for RelationSet {
+    type Err = ParseRelationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .trim()
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or(ParseRelationError::InvalidSet)?;
+
+        if s.trim().is_empty() {
+            return Ok(Self::empty());
+        }
+
+        if s.contains(',') {
+            s.split(',').map(str::parse).collect()
+        } else {
+            s.split_whitespace().map(str::parse).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let abbreviations: Vec<_> = Relation::ALL.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            abbreviations,
+            ["p", "m", "o", "fi", "di", "s", "eq", "si", "d", "f", "oi", "mi", "pi"]
+        );
+
+        let is_overlapped_by = Relation::Overlaps { is_inverted: true };
+        assert_eq!(format!("{is_overlapped_by:#}"), "is overlapped by");
+        assert_eq!(format!("{is_overlapped_by:>4}"), "  oi");
+    }
+
+    #[test]
+    fn from_str() {
+        for relation in Relation::ALL {
+            assert_eq!(relation.abbreviation().parse(), Ok(relation));
+            assert_eq!(relation.name().parse(), Ok(relation));
+            assert_eq!(relation.to_string().to_uppercase().parse(), Ok(relation));
+        }
+
+        let precedes = Relation::Precedes { is_inverted: false };
+        let is_preceded_by = Relation::Precedes { is_inverted: true };
+        let is_met_by = Relation::Meets { is_inverted: true };
+
+        assert_eq!("b".parse(), Ok(precedes));
+        assert_eq!("<".parse(), Ok(precedes));
+        assert_eq!("Before".parse(), Ok(precedes));
+        assert_eq!("bi".parse(), Ok(is_preceded_by));
+        assert_eq!("after".parse(), Ok(is_preceded_by));
+        assert_eq!("=".parse(), Ok(Relation::Equals));
+        assert_eq!(
+            "during".parse(),
+            Ok(Relation::Contains { is_inverted: true })
+        );
+        assert_eq!(" is_met_by ".parse(), Ok(is_met_by));
+        assert_eq!("Is  Met-By".parse(), Ok(is_met_by));
+
+        for invalid in ["", "x", "is met", "met by", "is met by t"] {
+            assert_eq!(
+                invalid.parse::<Relation>(),
+                Err(ParseRelationError::InvalidRelation),
+                "{invalid:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn set() {
+        let set: RelationSet = [
+            Relation::Overlaps { is_inverted: false },
+            Relation::Precedes { is_inverted: false },
+            Relation::Meets { is_inverted: false },
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.to_string(), "{p, m, o}");
+        assert_eq!(format!("{set:#}"), "{precedes, meets, overlaps}");
+        assert_eq!(RelationSet::empty().to_string(), "{}");
+
+        assert_eq!("{p, m, o}".parse(), Ok(set));
+        assert_eq!("{o m b}".parse(), Ok(set));
+        assert_eq!("{ overlaps,meets , before }".parse(), Ok(set));
+        assert_eq!("{precedes, meets, overlaps}".parse(), Ok(set));
+        assert_eq!("{ }".parse(), Ok(RelationSet::empty()));
+        assert_eq!(
+            RelationSet::all().to_string().parse(),
+            Ok(RelationSet::all())
+        );
+
+        assert_eq!(
+            "p, m".parse::<RelationSet>(),
+            Err(ParseRelationError::InvalidSet)
+        );
+        assert_eq!(
+            "{p, x}".parse::<RelationSet>(),
+            Err(ParseRelationError::InvalidRelation)
+        );
+        assert_eq!(
+            "{p,}".parse::<RelationSet>(),
+            Err(ParseRelationError::InvalidRelation)
+        );
+    }
+}
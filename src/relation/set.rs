@@ -0,0 +1,241 @@
+use core::iter::FusedIterator;
+
+use crate::{Relation, RelationCodeError};
+
+/// A set of basic relations, denoting their disjunction (e.g. `{p, m, o}`).
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct RelationSet(u16);
+
+impl RelationSet {
+    const MASK: u16 = (1 << Relation::ALL.len()) - 1;
+
+    /// Returns the empty set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns the set of all thirteen basic relations.
+    pub const fn all() -> Self {
+        Self(Self::MASK)
+    }
+
+    /// Returns the number of relations in the set.
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns `true` iff the set contains no relations.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` iff the set contains `relation`.
+    pub const fn contains(&self, relation: Relation) -> bool {
+        self.0 & Self::bit(relation) != 0
+    }
+
+    /// Adds `relation` to the set, returning `true` iff it was not already contained.
+    pub fn insert(&mut self, relation: Relation) -> bool {
+        let is_new = !self.contains(relation);
+        self.0 |= Self::bit(relation);
+        is_new
+    }
+
+    /// Removes `relation` from the set, returning `true` iff it was contained.
+    pub fn remove(&mut self, relation: Relation) -> bool {
+        let was_contained = self.contains(relation);
+        self.0 &= !Self::bit(relation);
+        was_contained
+    }
+
+    /// Returns the relations contained in either `self` or `other`.
+    pub const fn union(&self, other: &Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the relations contained in both `self` and `other`.
+    pub const fn intersection(&self, other: &Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the relations not contained in `self`.
+    pub const fn complement(&self) -> Self {
+        Self(!self.0 & Self::MASK)
+    }
+
+    /// Returns the set of the converses of the set's relations.
+    pub fn as_converse(&self) -> Self {
+        self.iter().map(|relation| relation.as_converse()).collect()
+    }
+
+    /// Returns an iterator over the set's relations, in ascending order.
+    pub fn iter(&self) -> RelationSetIter {
+        RelationSetIter {
+            bits: self.0,
+            index: 0,
+        }
+    }
+
+    /// Returns the set's stable 16-bit mask, in which bit `i` is set
+    /// iff the set contains the relation with code `i` (see [`BasicRelation`](crate::BasicRelation)).
+    ///
+    /// Bits `13..16` are always unset.
+    #[inline]
+    pub const fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the set of the given 16-bit mask (see [`RelationSet::bits`]),
+    /// or `None` if any of bits `13..16` are set.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Option<Self> {
+        match bits & !Self::MASK {
+            0 => Some(Self(bits)),
+            _ => None,
+        }
+    }
+
+    /// Returns the set of the given 16-bit mask (see [`RelationSet::bits`]),
+    /// ignoring any of bits `13..16`.
+    #[inline]
+    pub const fn from_bits_truncate(bits: u16) -> Self {
+        Self(bits & Self::MASK)
+    }
+
+    const fn bit(relation: Relation) -> u16 {
+        1 << relation.code()
+    }
+}
+
+impl From<Relation> for RelationSet {
+    fn from(relation: Relation) -> Self {
+        Self(Self::bit(relation))
+    }
+}
+
+impl From<RelationSet> for u16 {
+    #[inline]
+    fn from(set: RelationSet) -> Self {
+        set.bits()
+    }
+}
+
+impl TryFrom<u16> for RelationSet {
+    type Error = RelationCodeError;
+
+    #[inline]
+    fn try_from(bits: u16) -> Result<Self, Self::Error> {
+        Self::from_bits(bits).ok_or(RelationCodeError::InvalidMask(bits))
+    }
+}
+
+impl FromIterator<Relation> for RelationSet {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Relation>,
+    {
+        let mut set = Self::empty();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Relation> for RelationSet {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Relation>,
+    {
+        for relation in iter {
+            self.insert(relation);
+        }
+    }
+}
+
+impl IntoIterator for RelationSet {
+    type Item = Relation;
+    type IntoIter = RelationSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &RelationSet {
+    type Item = Relation;
+    type IntoIter = RelationSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the relations of a [`RelationSet`], in ascending order.
+#[derive(Clone, Debug)]
+pub struct RelationSetIter {
+    bits: u16,
+    index: usize,
+}
+
+impl Iterator for RelationSetIter {
+    type Item = Relation;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < Relation::ALL.len() {
+            let index = self.index;
+            self.index += 1;
+
+            if self.bits & (1 << index) != 0 {
+                return Some(Relation::ALL[index]);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.bits >> self.index).count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for RelationSetIter {}
+
+impl FusedIterator for RelationSetIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations() {
+        let precedes = Relation::Precedes { is_inverted: false };
+        let meets = Relation::Meets { is_inverted: false };
+        let is_met_by = Relation::Meets { is_inverted: true };
+
+        let mut set = RelationSet::empty();
+        assert!(set.is_empty());
+
+        assert!(set.insert(meets));
+        assert!(set.insert(precedes));
+        assert!(!set.insert(meets));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(precedes));
+        assert!(!set.contains(is_met_by));
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), [precedes, meets]);
+        assert_eq!(set.iter().len(), 2);
+        assert_eq!(set.as_converse().iter().next(), Some(is_met_by));
+
+        assert_eq!(set.union(&set.complement()), RelationSet::all());
+        assert!(set.intersection(&set.complement()).is_empty());
+
+        assert!(set.remove(meets));
+        assert!(!set.remove(meets));
+        assert_eq!(set, RelationSet::from(precedes));
+
+        assert_eq!(
+            RelationSet::all().iter().collect::<Vec<_>>(),
+            Relation::ALL.to_vec()
+        );
+    }
+}
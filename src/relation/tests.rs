@@ -1,3 +1,6 @@
+// Tests construct all intervals uniformly via `try_into`, even where conversion is infallible:
+#![allow(clippy::unnecessary_fallible_conversions)]
+
 use super::*;
 
 #[test]
@@ -34,6 +37,18 @@ fn converses() {
     }
 }
 
+#[test]
+fn ordering() {
+    let mut relations = Relation::ALL;
+    relations.sort();
+
+    assert_eq!(relations, Relation::ALL);
+
+    for window in Relation::ALL.windows(2) {
+        assert!(window[0] < window[1]);
+    }
+}
+
 mod precedes {
     use super::*;
 
@@ -0,0 +1,595 @@
+//! Freksa's semi-interval relations, relating intervals of which only some endpoints are known.
+//!
+//! > Freksa, C. (1992).
+//! > Temporal reasoning based on semi-intervals.
+//! > Artificial Intelligence, 54(1-2), 199-227.
+//!
+//! Each of the seventeen semi-interval relations (e.g. "`s` is older than `t`")
+//! is a coarse set of Allen's relations, which can be established
+//! from comparing just a single pair of endpoints (e.g. the intervals' starts),
+//! or from the conjunction of two such comparisons.
+//!
+//! ```
+//! use allen_intervals::{
+//!     semi_interval::{PartialInterval, SemiIntervalRelation},
+//!     IntervalFrom, NonEmpty, RelationSet,
+//! };
+//!
+//! // Both still ongoing, or at least of unknown end:
+//! let a = PartialInterval::from(NonEmpty::from(IntervalFrom { start: 1 }));
+//! let b = PartialInterval::from(NonEmpty::from(IntervalFrom { start: 4 }));
+//!
+//! let relations = a.relations(&b);
+//! assert_eq!(relations, "{p, m, o, fi, di}".parse::<RelationSet>().unwrap());
+//! assert_eq!(
+//!     SemiIntervalRelation::from_relations(relations),
+//!     Some(SemiIntervalRelation::Older)
+//! );
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+
+use crate::{
+    atomic::{Bb, Be, Eb, Ee},
+    Interval, IntervalError, IntervalFrom, IntervalFull, IntervalTo, NonEmpty, Relation,
+    RelationSet,
+};
+
+/// An interval, of which either endpoint may be unknown.
+///
+/// Unlike for [`Relation::from_intervals`](crate::FromIntervals::from_intervals),
+/// the missing endpoints of [`IntervalFrom`], [`IntervalTo`] and [`IntervalFull`]
+/// are treated as unknown (rather than as extending indefinitely) when converting from them.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct PartialInterval<T> {
+    start: Option<T>,
+    end: Option<T>,
+}
+
+impl<T> PartialInterval<T> {
+    /// Creates an interval from its (possibly unknown) endpoints,
+    /// or returns an error if both are known and they do not form a non-empty interval,
+    /// or are not totally ordered.
+    pub fn new(start: Option<T>, end: Option<T>) -> Result<Self, IntervalError>
+    where
+        T: PartialOrd,
+    {
+        if let (Some(start), Some(end)) = (&start, &end) {
+            match start.partial_cmp(end) {
+                Some(Ordering::Less) => {}
+                Some(_) => return Err(IntervalError::EmptyInterval),
+                None => return Err(IntervalError::AmbiguousOrder),
+            }
+        }
+
+        Ok(Self { start, end })
+    }
+
+    /// Creates an interval of known start and unknown end.
+    #[inline]
+    pub const fn from_start(start: T) -> Self {
+        Self {
+            start: Some(start),
+            end: None,
+        }
+    }
+
+    /// Creates an interval of unknown start and known end.
+    #[inline]
+    pub const fn from_end(end: T) -> Self {
+        Self {
+            start: None,
+            end: Some(end),
+        }
+    }
+
+    /// Creates an interval of unknown start and unknown end.
+    #[inline]
+    pub const fn unknown() -> Self {
+        Self {
+            start: None,
+            end: None,
+        }
+    }
+
+    /// Returns the interval's start, if known.
+    #[inline]
+    pub fn start(&self) -> Option<&T> {
+        self.start.as_ref()
+    }
+
+    /// Returns the interval's end, if known.
+    #[inline]
+    pub fn end(&self) -> Option<&T> {
+        self.end.as_ref()
+    }
+
+    /// Returns the Allen relations possible between `self` and `other`,
+    /// given the orderings of their known endpoints.
+    ///
+    /// The unknown endpoints are assumed to be free to take any value
+    /// that keeps their interval non-empty (as in a continuous time domain).
+    #[inline]
+    pub fn relations(&self, other: &Self) -> RelationSet
+    where
+        T: Ord,
+    {
+        Self::possible_relations(self.orderings(other, |s, t| Some(s.cmp(t))))
+    }
+
+    /// Returns the Allen relations possible between `self` and `other`,
+    /// given the orderings of their known endpoints,
+    /// or an error if any of their known endpoints are not totally ordered.
+    #[inline]
+    pub fn try_relations(&self, other: &Self) -> Result<RelationSet, IntervalError>
+    where
+        T: PartialOrd,
+    {
+        let orderings = self.orderings(other, |s, t| s.partial_cmp(t));
+
+        if orderings.contains(&Some(None)) {
+            return Err(IntervalError::AmbiguousOrder);
+        }
+
+        Ok(Self::possible_relations(orderings))
+    }
+
+    /// Returns the known orderings of `s`'s start and end to `t`'s start and end
+    /// (in the order of `Bb`, `Be`, `Eb`, `Ee`).
+    #[inline]
+    fn orderings<F>(&self, other: &Self, cmp: F) -> [Option<Option<Ordering>>; 4]
+    where
+        F: Fn(&T, &T) -> Option<Ordering>,
+    {
+        let cmp = |s: &Option<T>, t: &Option<T>| Some(cmp(s.as_ref()?, t.as_ref()?));
+
+        [
+            cmp(&self.start, &other.start),
+            cmp(&self.start, &other.end),
+            cmp(&self.end, &other.start),
+            cmp(&self.end, &other.end),
+        ]
+    }
+
+    #[inline]
+    fn possible_relations(orderings: [Option<Option<Ordering>>; 4]) -> RelationSet {
+        Relation::ALL
+            .into_iter()
+            .filter(|relation| {
+                let (Bb(bb), Be(be), Eb(eb), Ee(ee)) = relation.atomic_relations();
+
+                [bb, be, eb, ee]
+                    .into_iter()
+                    .zip(orderings)
+                    .all(|(ordering, known)| known.map_or(true, |known| known == Some(ordering)))
+            })
+            .collect()
+    }
+}
+
+impl<T> From<NonEmpty<Interval<T>>> for PartialInterval<T> {
+    #[inline]
+    fn from(interval: NonEmpty<Interval<T>>) -> Self {
+        let Interval { start, end } = interval.into_inner();
+
+        Self {
+            start: Some(start),
+            end: Some(end),
+        }
+    }
+}
+
+impl<T> From<NonEmpty<IntervalFrom<T>>> for PartialInterval<T> {
+    #[inline]
+    fn from(interval: NonEmpty<IntervalFrom<T>>) -> Self {
+        Self::from_start(interval.into_inner().start)
+    }
+}
+
+impl<T> From<NonEmpty<IntervalTo<T>>> for PartialInterval<T> {
+    #[inline]
+    fn from(interval: NonEmpty<IntervalTo<T>>) -> Self {
+        Self::from_end(interval.into_inner().end)
+    }
+}
+
+impl<T> From<NonEmpty<IntervalFull>> for PartialInterval<T> {
+    #[inline]
+    fn from(_: NonEmpty<IntervalFull>) -> Self {
+        Self::unknown()
+    }
+}
+
+/// A semi-interval relation between two intervals (e.g. `s` and `t`),
+/// i.e. a coarse set of Allen relations.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum SemiIntervalRelation {
+    /// `s` starts before `t` (`{p, m, o, fi, di}`).
+    Older,
+    /// `s` starts with `t` (`{s, eq, si}`).
+    HeadToHead,
+    /// `s` starts after `t` (`{d, f, oi, mi, pi}`).
+    Younger,
+    /// `s` ends after `t` (`{di, si, oi, mi, pi}`).
+    Survives,
+    /// `s` ends with `t` (`{fi, eq, f}`).
+    TailToTail,
+    /// `s` ends before `t` (`{p, m, o, s, d}`).
+    SurvivedBy,
+    /// `s` ends before or as `t` starts (`{p, m}`).
+    Precedes,
+    /// `s` starts before `t` ends (`{p, m, o, fi, di, s, eq, si, d, f, oi}`).
+    BornBeforeDeath,
+    /// `s` and `t` share some time (`{o, fi, di, s, eq, si, d, f, oi}`).
+    Contemporary,
+    /// `s` ends after `t` starts (`{o, fi, di, s, eq, si, d, f, oi, mi, pi}`).
+    DiedAfterBirth,
+    /// `s` starts after or as `t` ends (`{mi, pi}`).
+    Succeeds,
+    /// `s` starts and ends before `t` (`{p, m, o}`).
+    OlderAndSurvivedBy,
+    /// `s` starts before `t`, and they share some time (`{o, fi, di}`).
+    OlderContemporary,
+    /// `s` ends after `t`, and they share some time (`{di, si, oi}`).
+    SurvivingContemporary,
+    /// `s` ends before `t`, and they share some time (`{o, s, d}`).
+    SurvivedByContemporary,
+    /// `s` starts after `t`, and they share some time (`{d, f, oi}`).
+    YoungerContemporary,
+    /// `s` starts and ends after `t` (`{oi, mi, pi}`).
+    YoungerAndSurvives,
+}
+
+impl SemiIntervalRelation {
+    /// All seventeen relations, in ascending order.
+    pub const ALL: [Self; 17] = [
+        Self::Older,
+        Self::HeadToHead,
+        Self::Younger,
+        Self::Survives,
+        Self::TailToTail,
+        Self::SurvivedBy,
+        Self::Precedes,
+        Self::BornBeforeDeath,
+        Self::Contemporary,
+        Self::DiedAfterBirth,
+        Self::Succeeds,
+        Self::OlderAndSurvivedBy,
+        Self::OlderContemporary,
+        Self::SurvivingContemporary,
+        Self::SurvivedByContemporary,
+        Self::YoungerContemporary,
+        Self::YoungerAndSurvives,
+    ];
+
+    /// Returns the Allen relations the relation consists of.
+    pub const fn relations(&self) -> RelationSet {
+        const fn set(relations: &[Relation]) -> RelationSet {
+            let mut bits = 0;
+
+            let mut index = 0;
+            while index < relations.len() {
+                bits |= 1 << relations[index].code();
+                index += 1;
+            }
+
+            RelationSet::from_bits_truncate(bits)
+        }
+
+        const P: Relation = Relation::Precedes { is_inverted: false };
+        const M: Relation = Relation::Meets { is_inverted: false };
+        const O: Relation = Relation::Overlaps { is_inverted: false };
+        const FI: Relation = Relation::Finishes { is_inverted: true };
+        const DI: Relation = Relation::Contains { is_inverted: false };
+        const S: Relation = Relation::Starts { is_inverted: false };
+        const EQ: Relation = Relation::Equals;
+        const SI: Relation = Relation::Starts { is_inverted: true };
+        const D: Relation = Relation::Contains { is_inverted: true };
+        const F: Relation = Relation::Finishes { is_inverted: false };
+        const OI: Relation = Relation::Overlaps { is_inverted: true };
+        const MI: Relation = Relation::Meets { is_inverted: true };
+        const PI: Relation = Relation::Precedes { is_inverted: true };
+
+        match self {
+            Self::Older => set(&[P, M, O, FI, DI]),
+            Self::HeadToHead => set(&[S, EQ, SI]),
+            Self::Younger => set(&[D, F, OI, MI, PI]),
+            Self::Survives => set(&[DI, SI, OI, MI, PI]),
+            Self::TailToTail => set(&[FI, EQ, F]),
+            Self::SurvivedBy => set(&[P, M, O, S, D]),
+            Self::Precedes => set(&[P, M]),
+            Self::BornBeforeDeath => set(&[P, M, O, FI, DI, S, EQ, SI, D, F, OI]),
+            Self::Contemporary => set(&[O, FI, DI, S, EQ, SI, D, F, OI]),
+            Self::DiedAfterBirth => set(&[O, FI, DI, S, EQ, SI, D, F, OI, MI, PI]),
+            Self::Succeeds => set(&[MI, PI]),
+            Self::OlderAndSurvivedBy => set(&[P, M, O]),
+            Self::OlderContemporary => set(&[O, FI, DI]),
+            Self::SurvivingContemporary => set(&[DI, SI, OI]),
+            Self::SurvivedByContemporary => set(&[O, S, D]),
+            Self::YoungerContemporary => set(&[D, F, OI]),
+            Self::YoungerAndSurvives => set(&[OI, MI, PI]),
+        }
+    }
+
+    /// Returns the relation consisting of exactly `relations`, if any.
+    pub fn from_relations(relations: RelationSet) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|relation| relation.relations() == relations)
+    }
+
+    /// Returns the relations implied by `relations`
+    /// (i.e. those containing each of the non-empty `relations`), in ascending order.
+    ///
+    /// ```
+    /// use allen_intervals::{semi_interval::SemiIntervalRelation, Relation};
+    ///
+    /// let meets = Relation::Meets { is_inverted: false };
+    /// let relations: Vec<_> = SemiIntervalRelation::implied_by(meets.into()).collect();
+    ///
+    /// assert_eq!(
+    ///     relations,
+    ///     [
+    ///         SemiIntervalRelation::Older,
+    ///         SemiIntervalRelation::SurvivedBy,
+    ///         SemiIntervalRelation::Precedes,
+    ///         SemiIntervalRelation::BornBeforeDeath,
+    ///         SemiIntervalRelation::OlderAndSurvivedBy,
+    ///     ]
+    /// );
+    /// ```
+    pub fn implied_by(relations: RelationSet) -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter().filter(move |relation| {
+            !relations.is_empty() && relation.relations().intersection(&relations) == relations
+        })
+    }
+
+    /// Returns `true` iff `relation` is one of the relation's Allen relations.
+    #[inline]
+    pub const fn contains(&self, relation: Relation) -> bool {
+        self.relations().contains(relation)
+    }
+
+    /// Returns the relation's converse.
+    pub const fn as_converse(&self) -> Self {
+        match self {
+            Self::Older => Self::Younger,
+            Self::HeadToHead => Self::HeadToHead,
+            Self::Younger => Self::Older,
+            Self::Survives => Self::SurvivedBy,
+            Self::TailToTail => Self::TailToTail,
+            Self::SurvivedBy => Self::Survives,
+            Self::Precedes => Self::Succeeds,
+            Self::BornBeforeDeath => Self::DiedAfterBirth,
+            Self::Contemporary => Self::Contemporary,
+            Self::DiedAfterBirth => Self::BornBeforeDeath,
+            Self::Succeeds => Self::Precedes,
+            Self::OlderAndSurvivedBy => Self::YoungerAndSurvives,
+            Self::OlderContemporary => Self::YoungerContemporary,
+            Self::SurvivingContemporary => Self::SurvivedByContemporary,
+            Self::SurvivedByContemporary => Self::SurvivingContemporary,
+            Self::YoungerContemporary => Self::OlderContemporary,
+            Self::YoungerAndSurvives => Self::OlderAndSurvivedBy,
+        }
+    }
+
+    /// Returns the relation's conventional abbreviation (e.g. `ol` for "older").
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::Older => "ol",
+            Self::HeadToHead => "hh",
+            Self::Younger => "yo",
+            Self::Survives => "sv",
+            Self::TailToTail => "tt",
+            Self::SurvivedBy => "sb",
+            Self::Precedes => "pr",
+            Self::BornBeforeDeath => "bd",
+            Self::Contemporary => "ct",
+            Self::DiedAfterBirth => "db",
+            Self::Succeeds => "sd",
+            Self::OlderAndSurvivedBy => "ob",
+            Self::OlderContemporary => "oc",
+            Self::SurvivingContemporary => "sc",
+            Self::SurvivedByContemporary => "bc",
+            Self::YoungerContemporary => "yc",
+            Self::YoungerAndSurvives => "ys",
+        }
+    }
+
+    /// Returns the relation's full English name (e.g. `is older than`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Older => "is older than",
+            Self::HeadToHead => "is head to head with",
+            Self::Younger => "is younger than",
+            Self::Survives => "survives",
+            Self::TailToTail => "is tail to tail with",
+            Self::SurvivedBy => "is survived by",
+            Self::Precedes => "precedes",
+            Self::BornBeforeDeath => "is born before death of",
+            Self::Contemporary => "is contemporary of",
+            Self::DiedAfterBirth => "died after birth of",
+            Self::Succeeds => "succeeds",
+            Self::OlderAndSurvivedBy => "is older and survived by",
+            Self::OlderContemporary => "is older contemporary of",
+            Self::SurvivingContemporary => "is surviving contemporary of",
+            Self::SurvivedByContemporary => "is survived by contemporary of",
+            Self::YoungerContemporary => "is younger contemporary of",
+            Self::YoungerAndSurvives => "is younger and survives",
+        }
+    }
+}
+
+impl From<SemiIntervalRelation> for RelationSet {
+    #[inline]
+    fn from(relation: SemiIntervalRelation) -> Self {
+        relation.relations()
+    }
+}
+
+/// Formats as the relation's abbreviation (e.g. `ol`),
+/// or as its full name (e.g. `is older than`) for the alternate (`{:#}`) format.
+impl Display for SemiIntervalRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.pad(self.name())
+        } else {
+            f.pad(self.abbreviation())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_helpers::{interval, set},
+        FromIntervals,
+    };
+
+    #[test]
+    fn relations() {
+        for relation in SemiIntervalRelation::ALL {
+            assert_eq!(
+                relation.as_converse().relations(),
+                relation.relations().as_converse(),
+                "{relation}"
+            );
+            assert_eq!(
+                SemiIntervalRelation::from_relations(relation.relations()),
+                Some(relation)
+            );
+        }
+
+        // The compound relations are intersections of the simple ones:
+        use SemiIntervalRelation::*;
+        let cases = [
+            (Contemporary, BornBeforeDeath, DiedAfterBirth),
+            (OlderAndSurvivedBy, Older, SurvivedBy),
+            (OlderContemporary, Older, Contemporary),
+            (SurvivingContemporary, Survives, Contemporary),
+            (SurvivedByContemporary, SurvivedBy, Contemporary),
+            (YoungerContemporary, Younger, Contemporary),
+            (YoungerAndSurvives, Younger, Survives),
+        ];
+
+        for (relation, s, t) in cases {
+            assert_eq!(
+                relation.relations(),
+                s.relations().intersection(&t.relations())
+            );
+        }
+
+        assert_eq!(SemiIntervalRelation::from_relations(set("{p}")), None);
+        assert_eq!(SemiIntervalRelation::implied_by(set("{}")).count(), 0);
+    }
+
+    #[test]
+    fn single_endpoints() {
+        let from = PartialInterval::from_start;
+        let to = PartialInterval::from_end;
+        let relation = |s: PartialInterval<i32>, t| {
+            SemiIntervalRelation::from_relations(s.relations(&t)).unwrap()
+        };
+
+        assert_eq!(relation(from(0), from(1)), SemiIntervalRelation::Older);
+        assert_eq!(relation(from(1), from(1)), SemiIntervalRelation::HeadToHead);
+        assert_eq!(relation(from(2), from(1)), SemiIntervalRelation::Younger);
+        assert_eq!(relation(to(2), to(1)), SemiIntervalRelation::Survives);
+        assert_eq!(relation(to(1), to(1)), SemiIntervalRelation::TailToTail);
+        assert_eq!(relation(to(0), to(1)), SemiIntervalRelation::SurvivedBy);
+        assert_eq!(
+            relation(from(0), to(1)),
+            SemiIntervalRelation::BornBeforeDeath
+        );
+        assert_eq!(
+            relation(to(1), from(0)),
+            SemiIntervalRelation::DiedAfterBirth
+        );
+
+        // Some single comparisons already determine a basic relation:
+        assert_eq!(from(1).relations(&to(1)), set("{mi}"));
+        assert_eq!(to(1).relations(&from(1)), set("{m}"));
+        assert_eq!(
+            PartialInterval::<i32>::unknown().relations(&from(1)),
+            RelationSet::all()
+        );
+    }
+
+    #[test]
+    fn partial_intervals() {
+        // Known endpoints are spread apart (by 3, leaving room below and above them),
+        // such that unknown endpoints may fall between any two of them:
+        let intervals: Vec<_> = (0..5)
+            .flat_map(|start| (start + 1..6).map(move |end| (start * 3 + 3, end * 3 + 3)))
+            .collect();
+        let completions: Vec<_> = (0..21)
+            .flat_map(|start| (start + 1..22).map(move |end| (start, end)))
+            .collect();
+        let masks = [(false, false), (true, false), (false, true), (true, true)];
+
+        let partial = |(start, end): (i32, i32), (has_start, has_end): (bool, bool)| {
+            PartialInterval::new(has_start.then_some(start), has_end.then_some(end)).unwrap()
+        };
+        let agrees =
+            |(start, end): (i32, i32), known: (i32, i32), (has_start, has_end): (bool, bool)| {
+                (!has_start || start == known.0) && (!has_end || end == known.1)
+            };
+
+        for &s in &intervals {
+            for &t in &intervals {
+                let relation = Relation::from_intervals(&interval(s.0, s.1), &interval(t.0, t.1));
+
+                assert_eq!(
+                    PartialInterval::from(interval(s.0, s.1)).relations(&interval(t.0, t.1).into()),
+                    relation.into()
+                );
+
+                for s_mask in masks {
+                    for t_mask in masks {
+                        let (ps, pt) = (partial(s, s_mask), partial(t, t_mask));
+
+                        // The relations of all intervals agreeing with the known endpoints:
+                        let mut expected = RelationSet::empty();
+                        for &u in completions.iter().filter(|u| agrees(**u, s, s_mask)) {
+                            for &v in completions.iter().filter(|v| agrees(**v, t, t_mask)) {
+                                expected.insert(Relation::from_intervals(
+                                    &interval(u.0, u.1),
+                                    &interval(v.0, v.1),
+                                ));
+                            }
+                        }
+
+                        assert!(expected.contains(relation));
+                        assert_eq!(ps.relations(&pt), expected, "{ps:?} {pt:?}");
+                        assert_eq!(ps.try_relations(&pt), Ok(expected));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn try_relations() {
+        let s = PartialInterval::from_start(f64::NAN);
+        let t = PartialInterval::from_end(1.0);
+
+        assert_eq!(s.try_relations(&t), Err(IntervalError::AmbiguousOrder));
+        assert_eq!(
+            PartialInterval::new(Some(1.0), Some(1.0)),
+            Err(IntervalError::EmptyInterval)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(SemiIntervalRelation::Older.to_string(), "ol");
+        assert_eq!(
+            format!("{:#}", SemiIntervalRelation::SurvivedByContemporary),
+            "is survived by contemporary of"
+        );
+    }
+}
@@ -0,0 +1,602 @@
+//! Simple temporal networks (STNs), constraining the metric distances between intervals' endpoints.
+//!
+//! > Dechter, R., Meiri, I., & Pearl, J. (1991).
+//! > Temporal constraint networks.
+//! > Artificial Intelligence, 49(1-3), 61-95.
+//!
+//! A network relates the starts and ends of its intervals (as well as a common origin)
+//! by difference constraints (e.g. "`b` starts 5 to 10 minutes after `a` ends"),
+//! which are checked for consistency and tightened to their minimal form
+//! by computing all pairs' shortest paths.
+//! Any consistent network yields a schedule of concrete intervals satisfying all of its constraints.
+//!
+//! Allen's basic relations translate into constraints on the orderings of the intervals' endpoints.
+//! As these include strict orderings (e.g. an interval's start before its end),
+//! each network has a *resolution*, i.e. the smallest positive distance between distinct endpoints
+//! (e.g. `1` for integer time, or an arbitrarily small epsilon for floating-point time).
+//!
+//! ```
+//! use allen_intervals::{
+//!     stn::{Point, Stn},
+//!     Interval, NonEmpty, Relation,
+//! };
+//!
+//! let (a, b, c) = (0, 1, 2);
+//! let mut stn = Stn::new(3, 1);
+//!
+//! // `a` takes 30 to 60 minutes, and starts within the first 15 minutes:
+//! stn.constrain(Point::Start(a), Point::End(a), 30..=60).unwrap();
+//! stn.constrain(Point::Origin, Point::Start(a), 0..=15).unwrap();
+//! // `b` starts 5 to 10 minutes after `a` ends, and takes at least 20 minutes:
+//! stn.constrain(Point::End(a), Point::Start(b), 5..=10).unwrap();
+//! stn.constrain(Point::Start(b), Point::End(b), 20..).unwrap();
+//! // `c` finishes `b`, and is over within the first 90 minutes:
+//! stn.constrain_relation(c, b, Relation::Finishes { is_inverted: false });
+//! stn.constrain(Point::Origin, Point::End(c), ..=90).unwrap();
+//!
+//! stn.minimize().unwrap();
+//! assert_eq!(stn.bounds(Point::Origin, Point::Start(b)), (Some(35), Some(70)));
+//! assert_eq!(stn.bounds(Point::Start(a), Point::Start(c)), (Some(36), Some(89)));
+//!
+//! let schedule = stn.schedule().unwrap();
+//! assert_eq!(schedule[a], NonEmpty::try_from(Interval { start: 0, end: 30 }).unwrap());
+//! assert_eq!(schedule[b], NonEmpty::try_from(Interval { start: 35, end: 55 }).unwrap());
+//! assert_eq!(schedule[c], NonEmpty::try_from(Interval { start: 36, end: 55 }).unwrap());
+//! ```
+
+use std::{
+    cmp::Ordering,
+    ops::{self, RangeBounds},
+};
+
+use num_traits::Signed;
+
+use crate::{
+    atomic::{Bb, Be, Eb, Ee},
+    Interval, IntervalError, NonEmpty, Relation,
+};
+
+/// Error type for simple temporal networks.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, thiserror::Error)]
+pub enum StnError {
+    /// The network's constraints cannot all be satisfied.
+    #[error("inconsistent constraints")]
+    Inconsistent,
+    /// The constraint's bounds are not totally ordered.
+    #[error(transparent)]
+    Interval(#[from] IntervalError),
+}
+
+/// A point in time of a simple temporal network.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Point {
+    /// The origin, i.e. the zero of the network's schedules.
+    Origin,
+    /// The start of the interval of the given index.
+    Start(usize),
+    /// The end of the interval of the given index.
+    End(usize),
+}
+
+/// A simple temporal network over the starts and ends of the intervals `0..interval_count`.
+///
+/// Each interval's start precedes its end by at least the network's resolution,
+/// while all other pairs of points are unconstrained, unless constrained explicitly.
+///
+/// Times and distances share the type `T`, which needs to be signed
+/// (as distances between points may be negative; see [`Time`]).
+/// Bounds beyond `T`'s range (e.g. of paths of several long distances) are not representable,
+/// so that looser bounds are ignored, while tighter bounds render the network inconsistent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stn<T> {
+    interval_count: usize,
+    resolution: T,
+    /// The upper bounds on the distance from each point to each point (i.e. `to - from`),
+    /// in a row-major matrix over the points' indices.
+    bounds: Vec<Option<T>>,
+}
+
+/// `Time` is implemented by the signed primitive numbers,
+/// providing the (checked) arithmetic on the times and distances of an [`Stn`].
+pub trait Time: Copy + PartialOrd + Signed {
+    /// Returns `self + other`, or `None` if it is not representable.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Returns `self - other`, or `None` if it is not representable.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! time_impl {
+    (checked: $($t:ty),*) => {
+        $(
+            impl Time for $t {
+                #[inline]
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                #[inline]
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+    (float: $($t:ty),*) => {
+        $(
+            impl Time for $t {
+                #[inline]
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    Some(self + other)
+                }
+
+                #[inline]
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    Some(self - other)
+                }
+            }
+        )*
+    };
+}
+
+time_impl!(checked: i8, i16, i32, i64, i128, isize);
+time_impl!(float: f32, f64);
+
+impl<T> Stn<T>
+where
+    T: Time,
+{
+    /// Creates a network of `interval_count` intervals (of otherwise unconstrained endpoints)
+    /// and the given `resolution`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `resolution` is not positive.
+    pub fn new(interval_count: usize, resolution: T) -> Self {
+        assert!(resolution > T::zero(), "resolution must be positive");
+
+        let point_count = 1 + 2 * interval_count;
+        let mut bounds = vec![None; point_count * point_count];
+
+        for point in 0..point_count {
+            bounds[point * point_count + point] = Some(T::zero());
+        }
+
+        let mut stn = Self {
+            interval_count,
+            resolution,
+            bounds,
+        };
+
+        for interval in 0..interval_count {
+            stn.constrain_ordering(Point::Start(interval), Point::End(interval), Ordering::Less);
+        }
+
+        stn
+    }
+
+    /// Returns the number of the network's intervals.
+    pub fn interval_count(&self) -> usize {
+        self.interval_count
+    }
+
+    /// Returns the network's resolution.
+    pub fn resolution(&self) -> T {
+        self.resolution
+    }
+
+    /// Constrains the distance from `from` to `to` (i.e. `to - from`) to lie within `range`
+    /// (in addition to any existing constraints between them),
+    /// or returns an error if `range`'s bounds are not totally ordered,
+    /// or if an excluded bound cannot be satisfied within `T`'s range.
+    ///
+    /// Excluded bounds are shifted by the network's resolution
+    /// (e.g. `5..10` is equivalent to `5..=9` for a resolution of `1`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either point is out of range.
+    pub fn constrain<R>(&mut self, from: Point, to: Point, range: R) -> Result<(), StnError>
+    where
+        R: RangeBounds<T>,
+    {
+        for bound in [range.start_bound(), range.end_bound()] {
+            if let ops::Bound::Included(value) | ops::Bound::Excluded(value) = bound {
+                if value.partial_cmp(value).is_none() {
+                    return Err(IntervalError::AmbiguousOrder.into());
+                }
+            }
+        }
+
+        // A lower bound on `to - from` is an upper bound on `from - to`:
+        let min = match range.start_bound() {
+            ops::Bound::Included(&min) => Some(min),
+            ops::Bound::Excluded(&min) => Some(
+                min.checked_add(self.resolution)
+                    .ok_or(StnError::Inconsistent)?,
+            ),
+            ops::Bound::Unbounded => None,
+        };
+
+        let max = match range.end_bound() {
+            ops::Bound::Included(&max) => Some(max),
+            ops::Bound::Excluded(&max) => Some(
+                max.checked_sub(self.resolution)
+                    .ok_or(StnError::Inconsistent)?,
+            ),
+            ops::Bound::Unbounded => None,
+        };
+
+        // Negating the most negative integer overflows into a looser bound than any representable one:
+        if let Some(max) = min.and_then(|min| T::zero().checked_sub(min)) {
+            self.tighten(to, from, max);
+        }
+
+        if let Some(max) = max {
+            self.tighten(from, to, max);
+        }
+
+        Ok(())
+    }
+
+    /// Constrains the intervals `s` and `t` to stand in `relation`
+    /// (in addition to any existing constraints between them).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either interval is out of range.
+    pub fn constrain_relation(&mut self, s: usize, t: usize, relation: Relation) {
+        let (Bb(bb), Be(be), Eb(eb), Ee(ee)) = relation.atomic_relations();
+
+        self.constrain_ordering(Point::Start(s), Point::Start(t), bb);
+        self.constrain_ordering(Point::Start(s), Point::End(t), be);
+        self.constrain_ordering(Point::End(s), Point::Start(t), eb);
+        self.constrain_ordering(Point::End(s), Point::End(t), ee);
+    }
+
+    /// Returns the lower and upper bounds (if any) on the distance from `from` to `to` (i.e. `to - from`).
+    ///
+    /// Unless the network has been [minimized](Self::minimize),
+    /// these are only the bounds of the constraints between the two points themselves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either point is out of range.
+    pub fn bounds(&self, from: Point, to: Point) -> (Option<T>, Option<T>) {
+        let min = self
+            .bound(to, from)
+            .and_then(|bound| T::zero().checked_sub(bound));
+        let max = self.bound(from, to);
+
+        (min, max)
+    }
+
+    /// Returns `true` if the network's constraints can all be satisfied.
+    pub fn is_consistent(&self) -> bool {
+        self.clone().minimize().is_ok()
+    }
+
+    /// Tightens each constraint to the bounds implied by all of the network's constraints
+    /// (via the Floyd-Warshall algorithm),
+    /// or returns an error (leaving the network unchanged) if its constraints are inconsistent.
+    pub fn minimize(&mut self) -> Result<(), StnError> {
+        let point_count = self.point_count();
+        let mut bounds = self.bounds.clone();
+
+        for via in 0..point_count {
+            for from in 0..point_count {
+                let Some(first) = bounds[from * point_count + via] else {
+                    continue;
+                };
+
+                for to in 0..point_count {
+                    let Some(second) = bounds[via * point_count + to] else {
+                        continue;
+                    };
+
+                    let bound = &mut bounds[from * point_count + to];
+
+                    // An overflowing path is either looser than any representable bound,
+                    // or tighter than any representable distance:
+                    let Some(distance) = first.checked_add(second) else {
+                        if second < T::zero() {
+                            return Err(StnError::Inconsistent);
+                        }
+                        continue;
+                    };
+
+                    if bound.map_or(true, |bound| distance < bound) {
+                        *bound = Some(distance);
+                    }
+                }
+            }
+        }
+
+        // A negative cycle would require a point to precede itself:
+        let is_consistent = (0..point_count).all(|point| {
+            bounds[point * point_count + point].map_or(true, |bound| bound >= T::zero())
+        });
+
+        if !is_consistent {
+            return Err(StnError::Inconsistent);
+        }
+
+        self.bounds = bounds;
+
+        Ok(())
+    }
+
+    /// Returns a schedule of the network's intervals (in order of their indices)
+    /// satisfying all of its constraints, relative to an origin of zero,
+    /// or an error if its constraints are inconsistent
+    /// (or cannot be satisfied by the times scheduled within `T`'s range).
+    ///
+    /// Each point is scheduled as early as the constraints permit
+    /// (or as late, if they do not bound it from below),
+    /// given the points scheduled before it (in the order of the origin,
+    /// followed by each interval's start and end).
+    pub fn schedule(&self) -> Result<Vec<NonEmpty<Interval<T>>>, StnError> {
+        let mut stn = self.clone();
+        stn.minimize()?;
+
+        let point_count = stn.point_count();
+        let mut times: Vec<T> = Vec::with_capacity(point_count);
+
+        for point in 0..point_count {
+            let (mut earliest, mut latest) = (None, None);
+
+            for (scheduled, &time) in times.iter().enumerate() {
+                // A bound overflowing `T`'s range either does not constrain any representable time,
+                // or cannot be satisfied by any:
+                if let Some(bound) = stn.bounds[point * point_count + scheduled] {
+                    match time.checked_sub(bound) {
+                        Some(min) => {
+                            earliest =
+                                Some(earliest.map_or(min, |earliest: T| max_of(earliest, min)));
+                        }
+                        None if bound < T::zero() => return Err(StnError::Inconsistent),
+                        None => {}
+                    }
+                }
+
+                if let Some(bound) = stn.bounds[scheduled * point_count + point] {
+                    match time.checked_add(bound) {
+                        Some(max) => {
+                            latest = Some(latest.map_or(max, |latest: T| min_of(latest, max)));
+                        }
+                        None if bound < T::zero() => return Err(StnError::Inconsistent),
+                        None => {}
+                    }
+                }
+            }
+
+            // The minimal network guarantees any time within the bounds to be extensible
+            // to the remaining points:
+            times.push(earliest.or(latest).unwrap_or_else(T::zero));
+        }
+
+        (0..stn.interval_count)
+            .map(|interval| {
+                let start = times[stn.index(Point::Start(interval))];
+                let end = times[stn.index(Point::End(interval))];
+
+                Ok(NonEmpty::try_from(Interval { start, end })?)
+            })
+            .collect()
+    }
+
+    /// Constrains `from` to stand in `ordering` to `to`.
+    fn constrain_ordering(&mut self, from: Point, to: Point, ordering: Ordering) {
+        match ordering {
+            Ordering::Less => self.tighten(to, from, T::zero() - self.resolution),
+            Ordering::Equal => {
+                self.tighten(from, to, T::zero());
+                self.tighten(to, from, T::zero());
+            }
+            Ordering::Greater => self.tighten(from, to, T::zero() - self.resolution),
+        }
+    }
+
+    /// Tightens the upper bound on the distance from `from` to `to` to at most `max`.
+    fn tighten(&mut self, from: Point, to: Point, max: T) {
+        let index = self.index(from) * self.point_count() + self.index(to);
+        let bound = &mut self.bounds[index];
+
+        if bound.map_or(true, |bound| max < bound) {
+            *bound = Some(max);
+        }
+    }
+
+    fn bound(&self, from: Point, to: Point) -> Option<T> {
+        self.bounds[self.index(from) * self.point_count() + self.index(to)]
+    }
+
+    fn point_count(&self) -> usize {
+        1 + 2 * self.interval_count
+    }
+
+    fn index(&self, point: Point) -> usize {
+        let index = match point {
+            Point::Origin => return 0,
+            Point::Start(interval) => 1 + 2 * interval,
+            Point::End(interval) => 2 + 2 * interval,
+        };
+
+        assert!(
+            index < self.point_count(),
+            "point {point:?} out of range for {} intervals",
+            self.interval_count
+        );
+
+        index
+    }
+}
+
+/// Returns the greater of `a` and `b`.
+fn max_of<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns the lesser of `a` and `b`.
+fn min_of<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FromIntervals;
+
+    #[test]
+    fn relations() {
+        for relation in Relation::ALL {
+            let mut stn = Stn::new(2, 1);
+            stn.constrain_relation(0, 1, relation);
+
+            let schedule = stn.schedule().unwrap();
+            assert_eq!(
+                Relation::from_intervals(&schedule[0], &schedule[1]),
+                relation
+            );
+        }
+    }
+
+    #[test]
+    fn inconsistent() {
+        let mut stn = Stn::new(2, 1);
+        stn.constrain_relation(0, 1, Relation::Precedes { is_inverted: false });
+        stn.constrain_relation(1, 0, Relation::Meets { is_inverted: false });
+
+        let unchanged = stn.clone();
+        assert!(!stn.is_consistent());
+        assert_eq!(stn.minimize(), Err(StnError::Inconsistent));
+        assert_eq!(stn, unchanged);
+        assert_eq!(stn.schedule(), Err(StnError::Inconsistent));
+
+        // An interval shorter than the resolution:
+        let mut stn = Stn::new(1, 1);
+        stn.constrain(Point::Start(0), Point::End(0), ..1).unwrap();
+        assert!(!stn.is_consistent());
+    }
+
+    #[test]
+    fn minimize() {
+        let mut stn = Stn::new(2, 1);
+        stn.constrain(Point::Origin, Point::Start(0), 10..=20)
+            .unwrap();
+        stn.constrain(Point::Start(0), Point::End(0), 5..=5)
+            .unwrap();
+        stn.constrain(Point::End(0), Point::End(1), 1..=3).unwrap();
+        stn.constrain(Point::Origin, Point::End(1), ..=20).unwrap();
+
+        assert_eq!(stn.bounds(Point::Origin, Point::End(1)), (None, Some(20)));
+        stn.minimize().unwrap();
+
+        assert_eq!(
+            stn.bounds(Point::Origin, Point::Start(0)),
+            (Some(10), Some(14))
+        );
+        assert_eq!(
+            stn.bounds(Point::Origin, Point::End(1)),
+            (Some(16), Some(20))
+        );
+        assert_eq!(
+            stn.bounds(Point::End(1), Point::Start(0)),
+            (Some(-8), Some(-6))
+        );
+        assert_eq!(stn.bounds(Point::Start(1), Point::End(1)), (Some(1), None));
+
+        let schedule = stn.schedule().unwrap();
+        assert_eq!(schedule[0].as_inner(), &Interval { start: 10, end: 15 });
+        assert_eq!(schedule[1].as_inner(), &Interval { start: 17, end: 18 });
+    }
+
+    #[test]
+    fn floats() {
+        let mut stn = Stn::new(2, 0.001);
+        stn.constrain(Point::Origin, Point::Start(0), 0.0..=0.0)
+            .unwrap();
+        stn.constrain(Point::Start(0), Point::End(0), 1.5..)
+            .unwrap();
+        stn.constrain(Point::End(0), Point::Start(1), 0.0..0.5)
+            .unwrap();
+        stn.constrain_relation(1, 0, Relation::Precedes { is_inverted: true });
+
+        let schedule = stn.schedule().unwrap();
+        assert_eq!(
+            schedule[0].as_inner(),
+            &Interval {
+                start: 0.0,
+                end: 1.5
+            }
+        );
+        assert_eq!(schedule[1].as_inner().start, 1.501);
+
+        assert_eq!(
+            stn.constrain(Point::Origin, Point::End(1), ..f64::NAN),
+            Err(StnError::Interval(IntervalError::AmbiguousOrder))
+        );
+    }
+
+    #[test]
+    fn extreme_bounds() {
+        // Paths through long constraints overflow, without tightening any bounds:
+        let mut stn = Stn::new(1, 1);
+        stn.constrain(Point::Origin, Point::Start(0), ..=i32::MAX)
+            .unwrap();
+        stn.constrain(Point::Start(0), Point::End(0), ..=i32::MAX)
+            .unwrap();
+        stn.constrain(Point::Origin, Point::Start(0), i32::MIN..)
+            .unwrap();
+
+        stn.minimize().unwrap();
+        assert_eq!(stn.bounds(Point::Origin, Point::End(0)), (None, None));
+        assert_eq!(
+            stn.bounds(Point::Origin, Point::Start(0)),
+            (None, Some(i32::MAX))
+        );
+
+        stn.constrain(Point::Origin, Point::End(0), ..=i32::MAX)
+            .unwrap();
+        stn.minimize().unwrap();
+        assert_eq!(
+            stn.bounds(Point::Origin, Point::Start(0)),
+            (None, Some(i32::MAX - 1))
+        );
+
+        let schedule = stn.schedule().unwrap();
+        assert_eq!(
+            schedule[0].as_inner(),
+            &Interval {
+                start: i32::MAX - 1,
+                end: i32::MAX
+            }
+        );
+
+        // Bounds beyond the range of distances cannot be satisfied:
+        let mut stn = Stn::new(1, 1);
+        assert_eq!(
+            stn.constrain(Point::Origin, Point::Start(0), ..i32::MIN),
+            Err(StnError::Inconsistent)
+        );
+        stn.constrain(Point::Origin, Point::Start(0), ..=i32::MIN)
+            .unwrap();
+        stn.constrain(Point::End(0), Point::Origin, ..=-1).unwrap();
+        assert_eq!(stn.minimize(), Err(StnError::Inconsistent));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range() {
+        let mut stn = Stn::new(2, 1);
+        stn.constrain_relation(0, 2, Relation::Equals);
+    }
+}
@@ -0,0 +1,712 @@
+//! Rendering of labeled intervals as SVG timeline (i.e. Gantt) diagrams.
+//!
+//! Unlike [`Timeline`](crate::timeline::Timeline), endpoints are laid out to scale,
+//! which requires them to be convertible to `f64` (via [`ToPrimitive`]).
+//! Unbounded ends are rendered as arrows pointing towards the edge of the diagram.
+//!
+//! ```
+//! use allen_intervals::{svg::SvgTimeline, Interval, IntervalFrom, NonEmpty};
+//!
+//! let mut timeline = SvgTimeline::new().with_tick_format(|value| format!("day {value}"));
+//!
+//! let design = timeline.push("design", &NonEmpty::try_from(Interval { start: 0, end: 5 }).unwrap());
+//! let build = timeline.push("build", &NonEmpty::try_from(Interval { start: 5, end: 12 }).unwrap());
+//! timeline.push_with_category("support", "ops", &NonEmpty::from(IntervalFrom { start: 12 }));
+//!
+//! // Annotates the pair with their relation (i.e. "meets"):
+//! timeline.annotate(design, build);
+//!
+//! let svg = timeline.render();
+//!
+//! assert!(svg.starts_with("<svg"));
+//! assert!(svg.contains(">m</text>"));
+//! assert!(svg.contains(">day 10</text>"));
+//! ```
+
+use std::fmt::Write as _;
+
+use num_traits::ToPrimitive;
+
+use crate::{timeline::Layout, Bound, Bounds, IntervalBounds, NonEmpty, Relation};
+
+/// The default colours, assigned to categories (or labels) in order of first appearance.
+const PALETTE: [&str; 10] = [
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac",
+];
+
+const MARGIN: f64 = 10.0;
+const LANE_HEIGHT: f64 = 24.0;
+const BAR_HEIGHT: f64 = 16.0;
+const ARROW_WIDTH: f64 = 8.0;
+const AXIS_HEIGHT: f64 = 30.0;
+const TICK_LENGTH: f64 = 5.0;
+const FONT_SIZE: f64 = 12.0;
+/// A rough estimate of the average width of a character, relative to the font size.
+const CHAR_WIDTH: f64 = 0.6 * FONT_SIZE;
+
+type TickFormat = Box<dyn Fn(f64) -> String>;
+
+#[derive(Clone, Debug)]
+struct Entry<T> {
+    label: String,
+    category: Option<String>,
+    bounds: Bounds<T>,
+}
+
+/// An SVG timeline diagram of labeled intervals.
+pub struct SvgTimeline<T> {
+    entries: Vec<Entry<T>>,
+    annotations: Vec<(usize, usize)>,
+    layout: Layout,
+    width: f64,
+    tick_count: usize,
+    tick_format: Option<TickFormat>,
+    palette: Vec<String>,
+}
+
+impl<T> Default for SvgTimeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SvgTimeline<T> {
+    /// Creates an empty timeline, 800 pixels wide, with one lane per interval.
+    pub fn new() -> Self {
+        Self {
+            entries: vec![],
+            annotations: vec![],
+            layout: Layout::Rows,
+            width: 800.0,
+            tick_count: 5,
+            tick_format: None,
+            palette: PALETTE.iter().map(|colour| colour.to_string()).collect(),
+        }
+    }
+
+    /// Sets the arrangement of intervals into lanes.
+    ///
+    /// With [`Layout::Rows`] labels are rendered to the left of the lanes,
+    /// with [`Layout::Packed`] labels are rendered within the intervals.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the width of the diagram (in pixels).
+    pub fn with_width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the approximate number of ticks on the axis.
+    pub fn with_tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = tick_count.max(1);
+        self
+    }
+
+    /// Sets the formatting of the axis' tick values
+    /// (by default formatted with as many decimals as needed by the tick spacing).
+    pub fn with_tick_format<F>(mut self, tick_format: F) -> Self
+    where
+        F: Fn(f64) -> String + 'static,
+    {
+        self.tick_format = Some(Box::new(tick_format));
+        self
+    }
+
+    /// Sets the colours (as SVG paint values, e.g. `#4e79a7`) assigned to categories (or labels),
+    /// in order of their first appearance.
+    pub fn with_palette<I, S>(mut self, palette: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.palette = palette.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a labeled interval to the timeline, coloured by its label,
+    /// returning its index.
+    pub fn push<I>(&mut self, label: impl Into<String>, interval: &NonEmpty<I>) -> usize
+    where
+        NonEmpty<I>: IntervalBounds<T>,
+    {
+        self.push_entry(label.into(), None, interval.bounds())
+    }
+
+    /// Adds a labeled interval to the timeline, coloured by its category,
+    /// returning its index.
+    pub fn push_with_category<I>(
+        &mut self,
+        label: impl Into<String>,
+        category: impl Into<String>,
+        interval: &NonEmpty<I>,
+    ) -> usize
+    where
+        NonEmpty<I>: IntervalBounds<T>,
+    {
+        self.push_entry(label.into(), Some(category.into()), interval.bounds())
+    }
+
+    fn push_entry(&mut self, label: String, category: Option<String>, bounds: Bounds<T>) -> usize {
+        self.entries.push(Entry {
+            label,
+            category,
+            bounds,
+        });
+
+        self.entries.len() - 1
+    }
+
+    /// Annotates the intervals at indices `s` and `t` with a line showing their [`Relation`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn annotate(&mut self, s: usize, t: usize) {
+        assert!(s < self.entries.len(), "index of `s` out of bounds");
+        assert!(t < self.entries.len(), "index of `t` out of bounds");
+
+        self.annotations.push((s, t));
+    }
+
+    /// Returns the number of intervals in the timeline.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` iff the timeline contains no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// The horizontal extent of an interval (in pixels), along with which of its ends are unbounded.
+#[derive(Clone, Copy, Debug)]
+struct Extent {
+    start: f64,
+    end: f64,
+    is_start_unbounded: bool,
+    is_end_unbounded: bool,
+}
+
+impl<T> SvgTimeline<T>
+where
+    T: PartialOrd + ToPrimitive,
+{
+    /// Renders the timeline as an SVG document.
+    ///
+    /// Intervals with endpoints not representable as `f64` are omitted.
+    pub fn render(&self) -> String {
+        let positions: Vec<Option<(Option<f64>, Option<f64>)>> = self
+            .entries
+            .iter()
+            .map(|entry| Some((position(&entry.bounds.start)?, position(&entry.bounds.end)?)))
+            .collect();
+
+        let (domain_start, domain_end) = domain(positions.iter().flatten());
+        let (ticks, decimals) = ticks(domain_start, domain_end, self.tick_count);
+
+        let gutter = match self.layout {
+            Layout::Rows => {
+                let widest = self
+                    .entries
+                    .iter()
+                    .map(|entry| entry.label.chars().count())
+                    .max()
+                    .unwrap_or(0);
+                (widest as f64) * CHAR_WIDTH + MARGIN
+            }
+            Layout::Packed => 0.0,
+        };
+
+        let plot_start = MARGIN + gutter;
+        let plot_end = (self.width - MARGIN).max(plot_start + 1.0);
+        let scale = |value: f64| {
+            plot_start
+                + (value - domain_start) / (domain_end - domain_start) * (plot_end - plot_start)
+        };
+
+        let extents: Vec<Option<Extent>> = positions
+            .iter()
+            .map(|position| {
+                let (start, end) = (*position)?;
+                Some(Extent {
+                    start: start.map_or(plot_start, scale),
+                    end: end.map_or(plot_end, scale),
+                    is_start_unbounded: start.is_none(),
+                    is_end_unbounded: end.is_none(),
+                })
+            })
+            .collect();
+
+        let lanes = self.lanes(&extents);
+        let lane_count = lanes.iter().flatten().max().map_or(0, |lane| lane + 1);
+        let axis_y = MARGIN + (lane_count as f64) * LANE_HEIGHT;
+        let height = axis_y + AXIS_HEIGHT + MARGIN;
+
+        let mut svg = String::new();
+
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="{FONT_SIZE}">"#,
+            width = number(self.width),
+            height = number(height),
+        );
+
+        let colours = self.colours();
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            let (Some(extent), Some(lane)) = (extents[index], lanes[index]) else {
+                continue;
+            };
+
+            let y = MARGIN + (lane as f64) * LANE_HEIGHT + (LANE_HEIGHT - BAR_HEIGHT) / 2.0;
+            let label = escape(&entry.label);
+
+            let _ = writeln!(svg, r#"<g fill="{}">"#, escape(&colours[index]));
+            let _ = writeln!(svg, "<title>{label}</title>");
+            write_bar(&mut svg, &extent, y);
+            let _ = writeln!(svg, "</g>");
+
+            let text_y = number(y + BAR_HEIGHT / 2.0);
+
+            match self.layout {
+                Layout::Rows => {
+                    let _ = writeln!(
+                        svg,
+                        r#"<text x="{}" y="{text_y}" dominant-baseline="central">{label}</text>"#,
+                        number(MARGIN),
+                    );
+                }
+                Layout::Packed => {
+                    let inset = if extent.is_start_unbounded {
+                        ARROW_WIDTH
+                    } else {
+                        0.0
+                    };
+                    let _ = writeln!(
+                        svg,
+                        r#"<text x="{}" y="{text_y}" dominant-baseline="central" fill="white">{label}</text>"#,
+                        number(extent.start + inset + 4.0),
+                    );
+                }
+            }
+        }
+
+        // Axis:
+        let _ = writeln!(
+            svg,
+            r#"<g stroke="black"><line x1="{}" y1="{y}" x2="{}" y2="{y}"/>"#,
+            number(plot_start),
+            number(plot_end),
+            y = number(axis_y),
+        );
+
+        for &tick in &ticks {
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{x}" y1="{}" x2="{x}" y2="{}"/>"#,
+                number(axis_y),
+                number(axis_y + TICK_LENGTH),
+                x = number(scale(tick)),
+            );
+        }
+
+        let _ = writeln!(svg, "</g>");
+
+        for &tick in &ticks {
+            let text = match &self.tick_format {
+                Some(tick_format) => tick_format(tick),
+                None => format!("{tick:.decimals$}"),
+            };
+
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" text-anchor="middle" dominant-baseline="hanging">{}</text>"#,
+                number(scale(tick)),
+                number(axis_y + TICK_LENGTH + 2.0),
+                escape(&text),
+            );
+        }
+
+        // Annotations:
+        for &(s, t) in &self.annotations {
+            let (Some(s_extent), Some(t_extent)) = (extents[s], extents[t]) else {
+                continue;
+            };
+            let (Some(s_lane), Some(t_lane)) = (lanes[s], lanes[t]) else {
+                continue;
+            };
+            let Ok(relation) =
+                Relation::try_from_bounds(&self.entries[s].bounds, &self.entries[t].bounds)
+            else {
+                continue;
+            };
+
+            let (x1, y1) = anchor(&s_extent, s_lane);
+            let (x2, y2) = anchor(&t_extent, t_lane);
+            let (x, y) = ((x1 + x2) / 2.0, (y1 + y2) / 2.0);
+
+            let _ = writeln!(svg, "<g>");
+            let _ = writeln!(
+                svg,
+                "<title>{} {relation:#} {}</title>",
+                escape(&self.entries[s].label),
+                escape(&self.entries[t].label),
+            );
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="dimgray" stroke-dasharray="4 2"/>"#,
+                number(x1),
+                number(y1),
+                number(x2),
+                number(y2),
+            );
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" text-anchor="middle" dominant-baseline="central" stroke="white" stroke-width="3" paint-order="stroke">{relation}</text>"#,
+                number(x),
+                number(y),
+            );
+            let _ = writeln!(svg, "</g>");
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Assigns lanes to the (representable) intervals.
+    fn lanes(&self, extents: &[Option<Extent>]) -> Vec<Option<usize>> {
+        let mut lanes = vec![None; extents.len()];
+
+        match self.layout {
+            Layout::Rows => {
+                let representable = extents.iter().enumerate().filter(|(_, e)| e.is_some());
+
+                for (lane, (index, _)) in representable.enumerate() {
+                    lanes[index] = Some(lane);
+                }
+            }
+            Layout::Packed => {
+                let mut indices: Vec<usize> = (0..extents.len())
+                    .filter(|&index| extents[index].is_some())
+                    .collect();
+                indices.sort_by(|&lhs, &rhs| {
+                    let lhs = extents[lhs].map(|extent| (extent.start, extent.end));
+                    let rhs = extents[rhs].map(|extent| (extent.start, extent.end));
+                    lhs.partial_cmp(&rhs).unwrap_or(core::cmp::Ordering::Equal)
+                });
+
+                // The end of the last interval within each lane:
+                let mut ends: Vec<f64> = vec![];
+
+                for index in indices {
+                    let Some(extent) = extents[index] else {
+                        continue;
+                    };
+
+                    let lane = match ends.iter().position(|&end| end <= extent.start) {
+                        Some(lane) => lane,
+                        None => {
+                            ends.push(f64::NEG_INFINITY);
+                            ends.len() - 1
+                        }
+                    };
+
+                    ends[lane] = extent.end;
+                    lanes[index] = Some(lane);
+                }
+            }
+        }
+
+        lanes
+    }
+
+    /// Returns each interval's colour, assigning colours to categories (or labels)
+    /// in order of their first appearance.
+    fn colours(&self) -> Vec<String> {
+        let mut keys: Vec<&str> = vec![];
+
+        self.entries
+            .iter()
+            .map(|entry| {
+                let key = entry.category.as_deref().unwrap_or(&entry.label);
+
+                let index = match keys.iter().position(|&known| known == key) {
+                    Some(index) => index,
+                    None => {
+                        keys.push(key);
+                        keys.len() - 1
+                    }
+                };
+
+                match self.palette.len() {
+                    0 => "gray".to_owned(),
+                    len => self.palette[index % len].clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns a bound's position, or `None` if not representable as `f64`.
+fn position<T>(bound: &Bound<T>) -> Option<Option<f64>>
+where
+    T: ToPrimitive,
+{
+    match bound {
+        Bound::Bounded(value) => value.to_f64().filter(|value| value.is_finite()).map(Some),
+        Bound::Unbounded => Some(None),
+    }
+}
+
+/// Returns the range of values covered by the diagram,
+/// leaving room for arrows if any of the intervals are unbounded.
+fn domain<'a>(positions: impl Iterator<Item = &'a (Option<f64>, Option<f64>)>) -> (f64, f64) {
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    let mut is_start_unbounded = false;
+    let mut is_end_unbounded = false;
+
+    for &(start, end) in positions {
+        for value in [start, end].into_iter().flatten() {
+            min = min.min(value);
+            max = max.max(value);
+        }
+
+        is_start_unbounded |= start.is_none();
+        is_end_unbounded |= end.is_none();
+    }
+
+    if min > max {
+        return (0.0, 1.0);
+    }
+
+    if min == max {
+        (min, max) = (min - 0.5, max + 0.5);
+    }
+
+    let padding = (max - min) * 0.1;
+
+    (
+        if is_start_unbounded {
+            min - padding
+        } else {
+            min
+        },
+        if is_end_unbounded { max + padding } else { max },
+    )
+}
+
+/// Returns "nice" (i.e. multiples of 1, 2 or 5 times a power of ten) tick values within the range,
+/// along with the number of decimals needed for formatting them.
+fn ticks(start: f64, end: f64, count: usize) -> (Vec<f64>, usize) {
+    let raw_step = (end - start) / (count as f64);
+    let magnitude = 10_f64.powf(raw_step.log10().floor());
+
+    // Rounds the raw step to the nearest (geometrically) nice factor:
+    let factor = match raw_step / magnitude {
+        ratio if ratio >= 50_f64.sqrt() => 10.0,
+        ratio if ratio >= 10_f64.sqrt() => 5.0,
+        ratio if ratio >= 2_f64.sqrt() => 2.0,
+        _ => 1.0,
+    };
+    let step = factor * magnitude;
+
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+
+    let first = (start / step).ceil() as i64;
+    let last = (end / step).floor() as i64;
+
+    // Round away any floating-point noise (e.g. `0.6000000000000001`):
+    let precision = 10_f64.powi(decimals as i32);
+    let ticks = (first..=last)
+        .map(|index| ((index as f64) * step * precision).round() / precision)
+        .collect();
+
+    (ticks, decimals)
+}
+
+/// Writes an interval's bar, with arrows for its unbounded ends.
+fn write_bar(svg: &mut String, extent: &Extent, y: f64) {
+    let start = extent.start
+        + if extent.is_start_unbounded {
+            ARROW_WIDTH
+        } else {
+            0.0
+        };
+    let end = extent.end
+        - if extent.is_end_unbounded {
+            ARROW_WIDTH
+        } else {
+            0.0
+        };
+    let middle = y + BAR_HEIGHT / 2.0;
+    let bottom = y + BAR_HEIGHT;
+
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{}" y="{}" width="{}" height="{}"/>"#,
+        number(start),
+        number(y),
+        number((end - start).max(1.0)),
+        number(BAR_HEIGHT),
+    );
+
+    if extent.is_start_unbounded {
+        let _ = writeln!(
+            svg,
+            r#"<polygon points="{start},{y} {tip},{middle} {start},{bottom}"/>"#,
+            start = number(start),
+            tip = number(extent.start),
+            y = number(y),
+            middle = number(middle),
+            bottom = number(bottom),
+        );
+    }
+
+    if extent.is_end_unbounded {
+        let _ = writeln!(
+            svg,
+            r#"<polygon points="{end},{y} {tip},{middle} {end},{bottom}"/>"#,
+            end = number(end),
+            tip = number(extent.end),
+            y = number(y),
+            middle = number(middle),
+            bottom = number(bottom),
+        );
+    }
+}
+
+/// Returns the point from which an annotation line is drawn for an interval.
+fn anchor(extent: &Extent, lane: usize) -> (f64, f64) {
+    let x = (extent.start + extent.end) / 2.0;
+    let y = MARGIN + (lane as f64) * LANE_HEIGHT + LANE_HEIGHT / 2.0;
+
+    (x, y)
+}
+
+/// Formats a coordinate with at most two decimals.
+fn number(value: f64) -> String {
+    let formatted = format!("{value:.2}");
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+
+    match formatted {
+        "-0" => "0".to_owned(),
+        formatted => formatted.to_owned(),
+    }
+}
+
+/// Escapes text for use in SVG text content and attribute values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            char => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_helpers::interval, IntervalFull, IntervalTo};
+
+    use super::*;
+
+    #[test]
+    fn structure() {
+        let mut timeline = SvgTimeline::new().with_width(210.0);
+
+        timeline.push("a", &interval(0, 10));
+        timeline.push("<b>", &interval(5, 20));
+
+        let svg = timeline.render();
+
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="210""#));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect").count(), 2);
+        assert_eq!(svg.matches("<polygon").count(), 0);
+        assert!(svg.contains("<title>&lt;b&gt;</title>"));
+
+        // Labels are rendered in a gutter of 3 characters (and a margin):
+        let plot_start = MARGIN + 3.0 * CHAR_WIDTH + MARGIN;
+        assert!(svg.contains(&format!(r#"<rect x="{}" y="14""#, number(plot_start))));
+
+        // Ticks at multiples of 5:
+        for tick in ["0", "5", "10", "15", "20"] {
+            assert!(svg.contains(&format!(">{tick}</text>")), "{tick}");
+        }
+    }
+
+    #[test]
+    fn unbounded_ends() {
+        let mut timeline = SvgTimeline::new();
+
+        timeline.push("to", &NonEmpty::from(IntervalTo { end: 1.5 }));
+        timeline.push("full", &NonEmpty::from(IntervalFull));
+
+        let svg = timeline.render();
+
+        assert_eq!(svg.matches("<rect").count(), 2);
+        assert_eq!(svg.matches("<polygon").count(), 3);
+    }
+
+    #[test]
+    fn packed_lanes_and_colours() {
+        let mut timeline = SvgTimeline::new()
+            .with_layout(Layout::Packed)
+            .with_palette(["red", "blue"]);
+
+        timeline.push_with_category("a", "x", &interval(0, 5));
+        timeline.push_with_category("b", "y", &interval(5, 10));
+        timeline.push_with_category("c", "x", &interval(2, 7));
+
+        assert_eq!(timeline.colours(), ["red", "blue", "red"]);
+
+        let svg = timeline.render();
+
+        // `a` and `b` share the first lane, `c` is moved to the second one:
+        assert_eq!(svg.matches(r#"y="14""#).count(), 2);
+        assert_eq!(svg.matches(r#"y="38""#).count(), 1);
+    }
+
+    #[test]
+    fn annotations() {
+        let mut timeline = SvgTimeline::new();
+
+        let s = timeline.push("s", &interval(0, 10));
+        let t = timeline.push("t", &interval(2, 4));
+        timeline.annotate(s, t);
+        timeline.annotate(t, s);
+
+        let svg = timeline.render();
+
+        assert!(svg.contains("<title>s contains t</title>"));
+        assert!(svg.contains("<title>t is contained by s</title>"));
+        assert!(svg.contains(">di</text>"));
+        assert!(svg.contains(">d</text>"));
+    }
+
+    #[test]
+    fn ticks() {
+        assert_eq!(
+            super::ticks(0.0, 1.0, 5),
+            (vec![0.0, 0.2, 0.4, 0.6, 0.8, 1.0], 1)
+        );
+        assert_eq!(
+            super::ticks(3.0, 97.0, 4),
+            (vec![20.0, 40.0, 60.0, 80.0], 0)
+        );
+    }
+}
@@ -0,0 +1,24 @@
+//! Fixtures shared by the crate's unit tests.
+
+use crate::{Interval, NonEmpty, RelationSet};
+
+/// Returns the interval `start..end`, panicking if it is empty.
+pub(crate) fn interval<T>(start: T, end: T) -> NonEmpty<Interval<T>>
+where
+    T: PartialOrd,
+{
+    NonEmpty::try_from(Interval { start, end }).unwrap()
+}
+
+/// Returns all intervals with endpoints in `0..=max`,
+/// which (for `max >= 3`) include pairs standing in each of the thirteen relations.
+pub(crate) fn intervals(max: i32) -> Vec<NonEmpty<Interval<i32>>> {
+    (0..max)
+        .flat_map(|start| (start + 1..=max).map(move |end| interval(start, end)))
+        .collect()
+}
+
+/// Returns the relation set written in `s`, panicking if it is malformed.
+pub(crate) fn set(s: &str) -> RelationSet {
+    s.parse().unwrap()
+}
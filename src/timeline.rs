@@ -0,0 +1,514 @@
+//! Rendering of labeled intervals as textual timeline diagrams.
+//!
+//! Endpoints are laid out by rank (i.e. not to scale), with coinciding endpoints sharing a column,
+//! so that the diagrams show the relations between intervals at a glance.
+//! Unbounded ends are rendered as fades (i.e. `─ ─`).
+//!
+//! ```
+//! use allen_intervals::{timeline::Timeline, Interval, IntervalFrom, IntervalTo, NonEmpty};
+//!
+//! let mut timeline = Timeline::new();
+//!
+//! timeline.push("s", &NonEmpty::from(IntervalTo { end: 2 }));
+//! timeline.push("t", &NonEmpty::try_from(Interval { start: 2, end: 5 }).unwrap());
+//! timeline.push("u", &NonEmpty::from(IntervalFrom { start: 3 }));
+//!
+//! assert_eq!(
+//!     timeline.render(),
+//!     "\
+//! s: ─ ─ ┐
+//! t:     └───────┘
+//! u:         ┌──── ─ ─
+//!    ────┬───┬───┬────
+//!        2   3   5
+//! "
+//! );
+//! ```
+
+use std::{
+    fmt::{self, Display},
+    iter,
+};
+
+use crate::{Bound, Bounds, IntervalBounds, NonEmpty};
+
+/// The characters used for rendering a [`Timeline`].
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub enum Charset {
+    /// Box-drawing characters (e.g. `┌────┐`).
+    #[default]
+    Unicode,
+    /// ASCII characters (e.g. `+----+`).
+    Ascii,
+}
+
+impl Charset {
+    fn line(&self) -> char {
+        match self {
+            Self::Unicode => '─',
+            Self::Ascii => '-',
+        }
+    }
+
+    fn tick(&self) -> char {
+        match self {
+            Self::Unicode => '┬',
+            Self::Ascii => '+',
+        }
+    }
+
+    /// Returns the start and end corners for the given lane,
+    /// alternating between top and bottom corners for adjacent lanes.
+    fn corners(&self, lane: usize) -> (char, char) {
+        match (self, lane % 2) {
+            (Self::Unicode, 0) => ('┌', '┐'),
+            (Self::Unicode, _) => ('└', '┘'),
+            (Self::Ascii, _) => ('+', '+'),
+        }
+    }
+}
+
+/// The arrangement of intervals into rows of a [`Timeline`].
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub enum Layout {
+    /// Renders each interval in its own row, prefixed by its label.
+    #[default]
+    Rows,
+    /// Packs non-overlapping intervals into shared lanes, rendering labels within the intervals.
+    Packed,
+}
+
+/// The width (in columns) of a fade (i.e. `─ ─ `).
+const FADE_WIDTH: usize = 4;
+
+/// A timeline diagram of labeled intervals.
+#[derive(Clone, Debug)]
+pub struct Timeline<T> {
+    entries: Vec<(String, Bounds<T>)>,
+    charset: Charset,
+    layout: Layout,
+    spacing: Option<usize>,
+    show_axis: bool,
+}
+
+impl<T> Default for Timeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Timeline<T> {
+    /// Creates an empty timeline, using Unicode characters,
+    /// one row per interval and an axis.
+    pub fn new() -> Self {
+        Self {
+            entries: vec![],
+            charset: Charset::default(),
+            layout: Layout::default(),
+            spacing: None,
+            show_axis: true,
+        }
+    }
+
+    /// Sets the characters used for rendering.
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// Sets the arrangement of intervals into rows.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the number of columns between adjacent endpoints
+    /// (by default derived from the widths of the axis' values).
+    pub fn with_spacing(mut self, spacing: usize) -> Self {
+        self.spacing = Some(spacing.max(1));
+        self
+    }
+
+    /// Sets whether an axis (with the endpoints' values) is rendered below the intervals.
+    pub fn with_axis(mut self, show_axis: bool) -> Self {
+        self.show_axis = show_axis;
+        self
+    }
+
+    /// Adds a labeled interval to the timeline.
+    pub fn push<I>(&mut self, label: impl Into<String>, interval: &NonEmpty<I>)
+    where
+        NonEmpty<I>: IntervalBounds<T>,
+    {
+        self.entries.push((label.into(), interval.bounds()));
+    }
+
+    /// Returns the number of intervals in the timeline.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` iff the timeline contains no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T> Timeline<T>
+where
+    T: Ord + Display,
+{
+    /// Renders the timeline, terminating each row with a newline.
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    fn endpoints(&self) -> Vec<&T> {
+        let mut endpoints: Vec<&T> = self
+            .entries
+            .iter()
+            .flat_map(|(_, bounds)| [&bounds.start, &bounds.end])
+            .filter_map(|bound| match bound {
+                Bound::Bounded(value) => Some(value),
+                Bound::Unbounded => None,
+            })
+            .collect();
+
+        endpoints.sort();
+        endpoints.dedup();
+        endpoints
+    }
+
+    /// Returns the first and last column of an interval.
+    fn columns(&self, bounds: &Bounds<T>, grid: &Grid<'_, T>) -> (usize, usize) {
+        let start = match &bounds.start {
+            Bound::Bounded(value) => grid.column(value),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match &bounds.end {
+            Bound::Bounded(value) => grid.column(value),
+            Bound::Unbounded => grid.width - 1,
+        };
+
+        (start, end)
+    }
+
+    fn draw(&self, row: &mut [char], bounds: &Bounds<T>, lane: usize, grid: &Grid<'_, T>) {
+        let line = self.charset.line();
+        let (start_corner, end_corner) = self.charset.corners(lane);
+        let (start, end) = self.columns(bounds, grid);
+
+        row[start..=end].fill(line);
+
+        match bounds.start {
+            Bound::Bounded(_) => row[start] = start_corner,
+            Bound::Unbounded => {
+                row[1] = ' ';
+                row[3] = ' ';
+            }
+        }
+
+        match bounds.end {
+            Bound::Bounded(_) => row[end] = end_corner,
+            Bound::Unbounded => {
+                row[end - 3] = ' ';
+                row[end - 1] = ' ';
+            }
+        }
+    }
+
+    fn rows(&self, grid: &Grid<'_, T>) -> Vec<(String, Vec<char>)> {
+        match self.layout {
+            Layout::Rows => self
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(lane, (label, bounds))| {
+                    let mut row = vec![' '; grid.width];
+                    self.draw(&mut row, bounds, lane, grid);
+                    (label.clone(), row)
+                })
+                .collect(),
+            Layout::Packed => {
+                let mut entries: Vec<_> = self.entries.iter().collect();
+                entries.sort_by(|(_, lhs), (_, rhs)| {
+                    let lhs = self.columns(lhs, grid);
+                    let rhs = self.columns(rhs, grid);
+                    lhs.cmp(&rhs)
+                });
+
+                // Each lane's row, along with the last column occupied within it:
+                let mut lanes: Vec<(Vec<char>, usize)> = vec![];
+
+                for (label, bounds) in entries {
+                    let (start, end) = self.columns(bounds, grid);
+
+                    let lane = match lanes.iter().position(|&(_, occupied)| occupied < start) {
+                        Some(lane) => lane,
+                        None => {
+                            lanes.push((vec![' '; grid.width], 0));
+                            lanes.len() - 1
+                        }
+                    };
+
+                    let (row, occupied) = &mut lanes[lane];
+                    self.draw(row, bounds, lane, grid);
+                    *occupied = end;
+
+                    // Write the label into the interval's interior, keeping clear of its ends:
+                    let interior_start = match bounds.start {
+                        Bound::Bounded(_) => start + 1,
+                        Bound::Unbounded => FADE_WIDTH,
+                    };
+                    let interior_end = match bounds.end {
+                        Bound::Bounded(_) => end,
+                        Bound::Unbounded => end + 1 - FADE_WIDTH,
+                    };
+
+                    let text = iter::once(' ').chain(label.chars()).chain(iter::once(' '));
+                    let available = interior_end.saturating_sub(interior_start + 1);
+
+                    if !label.is_empty() && available >= 3 {
+                        for (cell, char) in row[interior_start + 1..interior_end]
+                            .iter_mut()
+                            .zip(text.take(available))
+                        {
+                            *cell = char;
+                        }
+                    }
+                }
+
+                lanes
+                    .into_iter()
+                    .map(|(row, _)| (String::new(), row))
+                    .collect()
+            }
+        }
+    }
+
+    fn axis(&self, grid: &Grid<'_, T>) -> [Vec<char>; 2] {
+        let mut line = vec![self.charset.line(); grid.width];
+        let mut values = vec![' '; grid.width];
+
+        // The column after the last written value:
+        let mut cursor = 0;
+
+        for (rank, value) in grid.endpoints.iter().enumerate() {
+            let column = grid.column_of_rank(rank);
+            line[column] = self.charset.tick();
+
+            if column < cursor {
+                continue;
+            }
+
+            let value: Vec<char> = value.to_string().chars().collect();
+
+            if values.len() < column + value.len() {
+                values.resize(column + value.len(), ' ');
+            }
+
+            values[column..column + value.len()].copy_from_slice(&value);
+            cursor = column + value.len() + 1;
+        }
+
+        [line, values]
+    }
+}
+
+/// Formats as the rendered timeline.
+impl<T> Display for Timeline<T>
+where
+    T: Ord + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let endpoints = self.endpoints();
+
+        let spacing = self.spacing.unwrap_or_else(|| {
+            let widest = endpoints
+                .iter()
+                .map(|value| value.to_string().chars().count())
+                .max()
+                .unwrap_or(0);
+
+            (widest + 1).max(FADE_WIDTH)
+        });
+
+        let grid = Grid::new(endpoints, spacing, &self.entries);
+        let rows = self.rows(&grid);
+
+        let label_width = rows
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let write_row = |f: &mut fmt::Formatter<'_>, prefix: &str, row: &[char]| {
+            let row: String = row.iter().collect();
+            let line = format!("{prefix}{row}");
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        for (label, row) in &rows {
+            let prefix = match label_width {
+                0 => String::new(),
+                _ => format!("{label:<label_width$}: "),
+            };
+
+            write_row(f, &prefix, row)?;
+        }
+
+        if self.show_axis {
+            let prefix = match label_width {
+                0 => String::new(),
+                _ => " ".repeat(label_width + 2),
+            };
+
+            for row in self.axis(&grid) {
+                write_row(f, &prefix, &row)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The columns of a timeline's endpoints.
+struct Grid<'a, T> {
+    endpoints: Vec<&'a T>,
+    spacing: usize,
+    offset: usize,
+    width: usize,
+}
+
+impl<'a, T> Grid<'a, T>
+where
+    T: Ord,
+{
+    fn new(endpoints: Vec<&'a T>, spacing: usize, entries: &[(String, Bounds<T>)]) -> Self {
+        // Only leave room for fades on either side of the endpoints if needed:
+        let margin = |is_needed: bool| if is_needed { FADE_WIDTH } else { 0 };
+
+        let offset = margin(
+            endpoints.is_empty()
+                || entries
+                    .iter()
+                    .any(|(_, bounds)| matches!(bounds.start, Bound::Unbounded)),
+        );
+        let trailing = margin(
+            endpoints.is_empty()
+                || entries
+                    .iter()
+                    .any(|(_, bounds)| matches!(bounds.end, Bound::Unbounded)),
+        );
+
+        let span = match endpoints.len() {
+            0 => spacing,
+            len => (len - 1) * spacing + 1,
+        };
+
+        Self {
+            endpoints,
+            spacing,
+            offset,
+            width: offset + span + trailing,
+        }
+    }
+
+    fn column_of_rank(&self, rank: usize) -> usize {
+        self.offset + rank * self.spacing
+    }
+
+    fn column(&self, value: &T) -> usize {
+        let rank = self
+            .endpoints
+            .binary_search(&value)
+            .expect("endpoint should be in grid");
+
+        self.column_of_rank(rank)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_helpers::interval, IntervalFrom, IntervalFull, IntervalTo};
+
+    use super::*;
+
+    #[test]
+    fn rows() {
+        let mut timeline = Timeline::new();
+
+        timeline.push("s", &interval(1, 10));
+        timeline.push("t", &interval(10, 12));
+        timeline.push("long", &NonEmpty::from(IntervalFull));
+
+        assert_eq!(
+            timeline.render(),
+            "\
+s   :     ┌───┐
+t   :         └───┘
+long: ─ ─ ───────── ─ ─
+      ────┬───┬───┬────
+          1   10  12
+"
+        );
+    }
+
+    #[test]
+    fn ascii() {
+        let mut timeline = Timeline::new()
+            .with_charset(Charset::Ascii)
+            .with_axis(false);
+
+        timeline.push("s", &NonEmpty::from(IntervalTo { end: 1 }));
+        timeline.push("t", &NonEmpty::from(IntervalFrom { start: 0 }));
+
+        assert_eq!(
+            timeline.render(),
+            "\
+s: - - ----+
+t:     +---- - -
+"
+        );
+    }
+
+    #[test]
+    fn packed() {
+        let mut timeline = Timeline::new().with_layout(Layout::Packed).with_spacing(6);
+
+        timeline.push("a", &interval(0, 1));
+        timeline.push("b", &interval(2, 3));
+        timeline.push("c", &interval(1, 2));
+        timeline.push("d", &NonEmpty::from(IntervalFrom { start: 0 }));
+
+        assert_eq!(
+            timeline.render(),
+            "\
+┌─ a ─┐     ┌─ b ─┐
+└─ d ────────────── ─ ─
+      ┌─ c ─┐
+┬─────┬─────┬─────┬────
+0     1     2     3
+"
+        );
+    }
+
+    #[test]
+    fn crowded_axis() {
+        let mut timeline = Timeline::new().with_spacing(2);
+
+        timeline.push("s", &interval(100, 101));
+        timeline.push("t", &interval(102, 103));
+
+        assert_eq!(
+            timeline.render(),
+            "\
+s: ┌─┐
+t:     └─┘
+   ┬─┬─┬─┬
+   100 102
+"
+        );
+    }
+}
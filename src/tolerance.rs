@@ -0,0 +1,518 @@
+//! Tolerance-based relations, treating endpoints within a given distance of each other as equal.
+//!
+//! Endpoints obtained from noisy sources (e.g. timestamps from different clocks)
+//! rarely coincide exactly, so that `m`, `s`, `f` and `eq` (and their converses) are hardly ever detected.
+//! Comparing endpoints within a tolerance instead considers two endpoints
+//! equal if their distance does not exceed the tolerance.
+//! Distances are computed via [`Distance`] (implemented for the primitive numeric types),
+//! with distances too large to be represented (e.g. from `i32::MIN` to `i32::MAX`) exceeding any tolerance.
+//!
+//! ```
+//! use allen_intervals::{
+//!     tolerance::{MeetsWithin, TryFromIntervalsWithin},
+//!     Interval, NonEmpty, Relation, TryFromIntervals,
+//! };
+//!
+//! let s = NonEmpty::try_from(Interval { start: 0.0, end: 10.002 }).unwrap();
+//! let t = NonEmpty::try_from(Interval { start: 10.0, end: 20.0 }).unwrap();
+//!
+//! assert_eq!(
+//!     Relation::try_from_intervals(&s, &t),
+//!     Ok(Relation::Overlaps { is_inverted: false })
+//! );
+//! assert_eq!(
+//!     Relation::try_from_intervals_within(&s, &t, 0.01),
+//!     Ok(Relation::Meets { is_inverted: false })
+//! );
+//!
+//! assert!(s.meets_within(&t, 0.01));
+//! assert!(t.is_met_by_within(&s, 0.01));
+//! ```
+//!
+//! # Chaining
+//!
+//! Equality within a tolerance is not transitive: `a` may be within the tolerance of `b`,
+//! and `b` within the tolerance of `c`, without `a` being within the tolerance of `c`.
+//! Endpoints are therefore never snapped or clustered,
+//! but each of the four comparisons of `s`'s and `t`'s endpoints
+//! is made between their actual values, independently of the others.
+//! The relation between a pair of intervals thus only ever depends on that pair
+//! (i.e. not on any other intervals whose tolerance windows chain with theirs),
+//! and is the converse of the relation between the intervals in reverse order.
+//! The relations between several intervals, however, need not be consistent with
+//! [`Relation::compose`](crate::Relation::compose) (e.g. `s` may meet `t`, and `t` meet `u`,
+//! while `s` also meets `u`).
+//!
+//! For intervals not much longer than the tolerance, the four comparisons may contradict each other
+//! (e.g. `s`'s end may be within the tolerance of `t`'s start, while `s`'s start is before it).
+//! Such comparisons are resolved by the relations' precedence
+//! (`p` and `pi` before `m` and `mi`, before all others), except that intervals whose
+//! ends are each within the tolerance of the other's start are considered equal.
+
+use core::cmp::Ordering;
+
+use num_traits::CheckedSub;
+
+use crate::{
+    atomic::{Bb, Be, Eb, Ee},
+    Bound, Bounds, Interval, IntervalBounds, IntervalError, IntervalFrom, IntervalFull, IntervalTo,
+    NonEmpty, Relation,
+};
+
+/// The distance between two endpoints, for comparing it against a tolerance.
+pub trait Distance: Sized {
+    /// The type of distances (e.g. `Self` for numbers).
+    type Output;
+
+    /// Returns the distance from `self` to the greater `other` (i.e. `other - self`),
+    /// or `None` if it is not representable.
+    fn checked_distance(&self, other: &Self) -> Option<Self::Output>;
+}
+
+macro_rules! distance_impl {
+    (checked: $($t:ty),*) => {
+        $(
+            impl Distance for $t {
+                type Output = Self;
+
+                #[inline]
+                fn checked_distance(&self, other: &Self) -> Option<Self> {
+                    other.checked_sub(self)
+                }
+            }
+        )*
+    };
+    (float: $($t:ty),*) => {
+        $(
+            impl Distance for $t {
+                type Output = Self;
+
+                #[inline]
+                fn checked_distance(&self, other: &Self) -> Option<Self> {
+                    Some(other - self)
+                }
+            }
+        )*
+    };
+}
+
+distance_impl!(checked: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+distance_impl!(float: f32, f64);
+
+/// Create a value from a pair of intervals, comparing their endpoints within a tolerance.
+pub trait FromIntervalsWithin<S, T, D>: Sized {
+    /// Creates a value from a pair of intervals, comparing their endpoints within `tolerance`.
+    fn from_intervals_within(s: &NonEmpty<S>, t: &NonEmpty<T>, tolerance: D) -> Self;
+}
+
+/// Create a value from a pair of intervals, comparing their endpoints within a tolerance.
+pub trait TryFromIntervalsWithin<S, T, D>: Sized {
+    /// Creates a value from a pair of intervals, comparing their endpoints within `tolerance`.
+    fn try_from_intervals_within(
+        s: &NonEmpty<S>,
+        t: &NonEmpty<T>,
+        tolerance: D,
+    ) -> Result<Self, IntervalError>;
+}
+
+/// Returns the relation between the intervals of bounds `s` and `t`,
+/// considering endpoints within `tolerance` of each other as equal.
+#[inline]
+fn try_relation_within<T, D>(
+    s: &Bounds<T>,
+    t: &Bounds<T>,
+    tolerance: &D,
+) -> Result<Relation, IntervalError>
+where
+    T: PartialOrd + Copy + Distance<Output = D>,
+    D: PartialOrd,
+{
+    let Bb(bb) = Bb::try_from_bounds(&s.start, &t.start)?;
+    let Be(be) = Be::try_from_bounds(&s.start, &t.end)?;
+    let Eb(eb) = Eb::try_from_bounds(&s.end, &t.start)?;
+    let Ee(ee) = Ee::try_from_bounds(&s.end, &t.end)?;
+
+    let bb = Bb(cmp_within(bb, &s.start, &t.start, tolerance)?);
+    let be = Be(cmp_within(be, &s.start, &t.end, tolerance)?);
+    let eb = Eb(cmp_within(eb, &s.end, &t.start, tolerance)?);
+    let ee = Ee(cmp_within(ee, &s.end, &t.end, tolerance)?);
+
+    if (eb.0, be.0) == (Ordering::Equal, Ordering::Equal) {
+        // Both intervals are within the tolerance of a single point
+        // (which also implies `bb` and `ee` to be equal):
+        return Ok(Relation::Equals);
+    }
+
+    Ok(Relation::from_atomic_relations(bb, be, eb, ee))
+}
+
+/// Returns `Equal` if the bounds `s` and `t` (of exact `ordering`)
+/// are both bounded and within `tolerance` of each other, or `ordering` otherwise
+/// (including if their distance is not representable, and thus beyond any tolerance).
+#[inline]
+fn cmp_within<T, D>(
+    ordering: Ordering,
+    s: &Bound<T>,
+    t: &Bound<T>,
+    tolerance: &D,
+) -> Result<Ordering, IntervalError>
+where
+    T: Distance<Output = D>,
+    D: PartialOrd,
+{
+    let (Bound::Bounded(s), Bound::Bounded(t)) = (s, t) else {
+        return Ok(ordering);
+    };
+
+    let distance = match ordering {
+        Ordering::Less => s.checked_distance(t),
+        Ordering::Equal => return Ok(ordering),
+        Ordering::Greater => t.checked_distance(s),
+    };
+
+    let Some(distance) = distance else {
+        return Ok(ordering);
+    };
+
+    match distance.partial_cmp(tolerance) {
+        Some(Ordering::Greater) => Ok(ordering),
+        Some(_) => Ok(Ordering::Equal),
+        None => Err(IntervalError::AmbiguousOrder),
+    }
+}
+
+macro_rules! from_intervals_within_impl {
+    ($s:ty, $t:ty) => {
+        impl<T, D> FromIntervalsWithin<$s, $t, D> for Relation
+        where
+            T: Ord + Copy + Distance<Output = D>,
+            D: Ord,
+        {
+            #[inline]
+            fn from_intervals_within(s: &NonEmpty<$s>, t: &NonEmpty<$t>, tolerance: D) -> Self {
+                match try_relation_within(&s.0.bounds(), &t.0.bounds(), &tolerance) {
+                    Ok(relation) => relation,
+                    Err(_) => unreachable!("totally ordered endpoints and distances"),
+                }
+            }
+        }
+
+        impl<T, D> TryFromIntervalsWithin<$s, $t, D> for Relation
+        where
+            T: PartialOrd + Copy + Distance<Output = D>,
+            D: PartialOrd,
+        {
+            #[inline]
+            fn try_from_intervals_within(
+                s: &NonEmpty<$s>,
+                t: &NonEmpty<$t>,
+                tolerance: D,
+            ) -> Result<Self, IntervalError> {
+                try_relation_within(&s.0.bounds(), &t.0.bounds(), &tolerance)
+            }
+        }
+    };
+}
+
+from_intervals_within_impl!(IntervalFull, IntervalTo<T>);
+from_intervals_within_impl!(IntervalFull, IntervalFrom<T>);
+from_intervals_within_impl!(IntervalFull, Interval<T>);
+
+from_intervals_within_impl!(IntervalTo<T>, IntervalFull);
+from_intervals_within_impl!(IntervalTo<T>, IntervalTo<T>);
+from_intervals_within_impl!(IntervalTo<T>, IntervalFrom<T>);
+from_intervals_within_impl!(IntervalTo<T>, Interval<T>);
+
+from_intervals_within_impl!(IntervalFrom<T>, IntervalFull);
+from_intervals_within_impl!(IntervalFrom<T>, IntervalTo<T>);
+from_intervals_within_impl!(IntervalFrom<T>, IntervalFrom<T>);
+from_intervals_within_impl!(IntervalFrom<T>, Interval<T>);
+
+from_intervals_within_impl!(Interval<T>, IntervalFull);
+from_intervals_within_impl!(Interval<T>, IntervalTo<T>);
+from_intervals_within_impl!(Interval<T>, IntervalFrom<T>);
+from_intervals_within_impl!(Interval<T>, Interval<T>);
+
+macro_rules! predicate_within_trait {
+    (
+        $(#[$attr:meta])*
+        $trait:ident: $relation:pat,
+        $(#[$predicate_attr:meta])* $predicate:ident,
+        $(#[$converse_attr:meta])* $converse:ident $(,)?
+    ) => {
+        $(#[$attr])*
+        pub trait $trait<T, D>: Sized {
+            $(#[$predicate_attr])*
+            fn $predicate(&self, other: &T, tolerance: D) -> bool;
+
+            $(#[$converse_attr])*
+            #[inline]
+            fn $converse(&self, other: &T, tolerance: D) -> bool
+            where
+                T: $trait<Self, D>,
+            {
+                other.$predicate(self, tolerance)
+            }
+        }
+
+        impl<S, T, D> $trait<NonEmpty<T>, D> for NonEmpty<S>
+        where
+            Relation: TryFromIntervalsWithin<S, T, D>,
+        {
+            #[inline]
+            fn $predicate(&self, other: &NonEmpty<T>, tolerance: D) -> bool {
+                matches!(
+                    Relation::try_from_intervals_within(self, other, tolerance),
+                    Ok($relation)
+                )
+            }
+        }
+    };
+}
+
+predicate_within_trait! {
+    /// Methods for checking for a "precedes" relation between intervals within a tolerance
+    /// (cf. [`Precedes`](crate::Precedes)).
+    PrecedesWithin: Relation::Precedes { is_inverted: false },
+    /// Returns `true` iff `self` precedes `other`, by more than `tolerance`.
+    precedes_within,
+    /// Returns `true` iff `self` is preceded by `other`, by more than `tolerance`.
+    is_preceded_by_within,
+}
+
+predicate_within_trait! {
+    /// Methods for checking for a "meets" relation between intervals within a tolerance
+    /// (cf. [`Meets`](crate::Meets)).
+    MeetsWithin: Relation::Meets { is_inverted: false },
+    /// Returns `true` iff `self` meets `other`, within `tolerance`.
+    meets_within,
+    /// Returns `true` iff `self` is met by `other`, within `tolerance`.
+    is_met_by_within,
+}
+
+predicate_within_trait! {
+    /// Methods for checking for an "overlaps" relation between intervals within a tolerance
+    /// (cf. [`Overlaps`](crate::Overlaps)).
+    OverlapsWithin: Relation::Overlaps { is_inverted: false },
+    /// Returns `true` iff `self` overlaps `other`, by more than `tolerance`.
+    overlaps_within,
+    /// Returns `true` iff `self` is overlapped by `other`, by more than `tolerance`.
+    is_overlapped_by_within,
+}
+
+predicate_within_trait! {
+    /// Methods for checking for a "starts" relation between intervals within a tolerance
+    /// (cf. [`Starts`](crate::Starts)).
+    StartsWithin: Relation::Starts { is_inverted: false },
+    /// Returns `true` iff `self` starts `other`, within `tolerance`.
+    starts_within,
+    /// Returns `true` iff `self` is started by `other`, within `tolerance`.
+    is_started_by_within,
+}
+
+predicate_within_trait! {
+    /// Methods for checking for a "contains" relation between intervals within a tolerance
+    /// (cf. [`Contains`](crate::Contains)).
+    ContainsWithin: Relation::Contains { is_inverted: false },
+    /// Returns `true` iff `self` contains `other`, by more than `tolerance` at either end.
+    contains_within,
+    /// Returns `true` iff `self` is contained by `other`, by more than `tolerance` at either end.
+    is_contained_by_within,
+}
+
+predicate_within_trait! {
+    /// Methods for checking for a "finishes" relation between intervals within a tolerance
+    /// (cf. [`Finishes`](crate::Finishes)).
+    FinishesWithin: Relation::Finishes { is_inverted: false },
+    /// Returns `true` iff `self` finishes `other`, within `tolerance`.
+    finishes_within,
+    /// Returns `true` iff `self` is finished by `other`, within `tolerance`.
+    is_finished_by_within,
+}
+
+/// Methods for checking for an "equals" relation between intervals within a tolerance
+/// (cf. [`Equals`](crate::Equals)).
+pub trait EqualsWithin<T, D>: Sized {
+    /// Returns `true` iff `self` equals `other`, within `tolerance`.
+    fn equals_within(&self, other: &T, tolerance: D) -> bool;
+}
+
+impl<S, T, D> EqualsWithin<NonEmpty<T>, D> for NonEmpty<S>
+where
+    Relation: TryFromIntervalsWithin<S, T, D>,
+{
+    #[inline]
+    fn equals_within(&self, other: &NonEmpty<T>, tolerance: D) -> bool {
+        matches!(
+            Relation::try_from_intervals_within(self, other, tolerance),
+            Ok(Relation::Equals)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_helpers::{interval, intervals},
+        FromIntervals, RelationSet,
+    };
+
+    #[test]
+    fn zero_tolerance() {
+        let intervals = intervals(6);
+        let mut relations = RelationSet::empty();
+
+        for s in &intervals {
+            for t in &intervals {
+                let relation = Relation::from_intervals(s, t);
+                relations.insert(relation);
+
+                assert_eq!(Relation::from_intervals_within(s, t, 0), relation);
+            }
+        }
+
+        assert_eq!(relations, RelationSet::all());
+
+        let s = NonEmpty::from(IntervalFrom { start: 2 });
+        let t = NonEmpty::from(IntervalTo { end: 2 });
+        assert_eq!(
+            Relation::from_intervals_within(&s, &t, 0),
+            Relation::from_intervals(&s, &t)
+        );
+    }
+
+    #[test]
+    fn noisy_endpoints() {
+        let t = interval(10.0, 20.0);
+
+        for (s, relation) in [
+            (
+                interval(0.0, 10.004),
+                Relation::Meets { is_inverted: false },
+            ),
+            (
+                interval(9.996, 15.0),
+                Relation::Starts { is_inverted: false },
+            ),
+            (
+                interval(15.0, 19.998),
+                Relation::Finishes { is_inverted: false },
+            ),
+            (interval(10.001, 19.999), Relation::Equals),
+            (
+                interval(20.003, 30.0),
+                Relation::Meets { is_inverted: true },
+            ),
+            (
+                interval(9.9, 10.2),
+                Relation::Overlaps { is_inverted: false },
+            ),
+            (
+                interval(0.0, 9.9),
+                Relation::Precedes { is_inverted: false },
+            ),
+        ] {
+            assert_eq!(
+                Relation::try_from_intervals_within(&s, &t, 0.005),
+                Ok(relation)
+            );
+            assert_eq!(
+                Relation::try_from_intervals_within(&t, &s, 0.005),
+                Ok(relation.as_converse())
+            );
+        }
+
+        assert!(interval(0.0, 10.004).meets_within(&t, 0.005));
+        assert!(t.is_met_by_within(&interval(0.0, 10.004), 0.005));
+        assert!(!interval(0.0, 10.004).meets_within(&t, 0.001));
+        assert!(interval(9.996, 15.0).starts_within(&t, 0.005));
+        assert!(interval(15.0, 19.998).finishes_within(&t, 0.005));
+        assert!(interval(10.001, 19.999).equals_within(&t, 0.005));
+        assert!(t.contains_within(&interval(10.01, 19.99), 0.005));
+        assert!(!t.contains_within(&interval(10.001, 19.99), 0.005));
+    }
+
+    #[test]
+    fn converses() {
+        // Intervals shorter than the tolerance, which yield inconsistent comparisons:
+        for s_start in 0..6 {
+            for s_end in (s_start + 1)..8 {
+                for t_start in 0..6 {
+                    for t_end in (t_start + 1)..8 {
+                        let (s, t) = (interval(s_start, s_end), interval(t_start, t_end));
+
+                        for tolerance in 0..3 {
+                            assert_eq!(
+                                Relation::from_intervals_within(&s, &t, tolerance).as_converse(),
+                                Relation::from_intervals_within(&t, &s, tolerance),
+                                "{s:?} vs {t:?} within {tolerance}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        assert_eq!(
+            Relation::from_intervals_within(&interval(0, 1), &interval(1, 2), 2),
+            Relation::Equals
+        );
+    }
+
+    #[test]
+    fn chaining() {
+        let (s, t, u) = (
+            interval(0.0, 1.0),
+            interval(1.02, 1.06),
+            interval(1.08, 2.0),
+        );
+
+        // Each pair is compared on its own, regardless of how the tolerance windows chain:
+        assert!(s.meets_within(&t, 0.1));
+        assert!(t.meets_within(&u, 0.1));
+        assert!(s.meets_within(&u, 0.1));
+
+        assert!(s.meets_within(&t, 0.05));
+        assert!(t.meets_within(&u, 0.05));
+        assert!(s.precedes_within(&u, 0.05));
+    }
+
+    #[test]
+    fn extreme_values() {
+        let s = interval(i32::MIN, 0);
+        let t = interval(1, i32::MAX);
+
+        assert_eq!(
+            Relation::from_intervals_within(&s, &t, 5),
+            Relation::Meets { is_inverted: false }
+        );
+        assert_eq!(
+            Relation::from_intervals_within(&t, &s, 5),
+            Relation::Meets { is_inverted: true }
+        );
+        assert_eq!(
+            Relation::from_intervals_within(&s, &t, 0),
+            Relation::Precedes { is_inverted: false }
+        );
+
+        let s = interval(0, 1);
+        let t = interval(u64::MAX - 1, u64::MAX);
+        assert_eq!(
+            Relation::from_intervals_within(&s, &t, u64::MAX - 1),
+            Relation::Meets { is_inverted: false }
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let s = interval(0.0, 1.0);
+        let t = interval(1.0, 2.0);
+
+        assert_eq!(
+            Relation::try_from_intervals_within(&s, &t, f64::NAN),
+            Err(IntervalError::AmbiguousOrder)
+        );
+        assert!(!s.meets_within(&t, f64::NAN));
+    }
+}
@@ -0,0 +1,495 @@
+//! A [`tracing-subscriber`](https://docs.rs/tracing-subscriber) layer recording span lifetimes as intervals,
+//! allowing for spans to be queried by their relations.
+//!
+//! ```
+//! use allen_intervals::tracing::{SpanIntervalLayer, SpanTiming};
+//! use tracing_subscriber::{layer::SubscriberExt, Registry};
+//!
+//! let layer = SpanIntervalLayer::new(SpanTiming::Entered);
+//! let store = layer.store();
+//!
+//! let subscriber = Registry::default().with(layer);
+//!
+//! tracing_core::dispatcher::with_default(&subscriber.into(), || {
+//!     // ... run instrumented code ...
+//! });
+//!
+//! let spans = store.snapshot();
+//!
+//! for (child, parent) in spans.improperly_nested() {
+//!     eprintln!("{} escapes its parent {}", child.name, parent.name);
+//! }
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::Instant,
+};
+
+use tracing_core::{
+    span::{Attributes, Id},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+use crate::{
+    Contains, Equals, Finishes, FromIntervals, Interval, Meets, NonEmpty, Precedes, Relation,
+    Starts,
+};
+
+/// The points in time recorded as a span's interval.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum SpanTiming {
+    /// From the span's creation to its closing.
+    ///
+    /// As the registry keeps a span open for as long as any of its children are,
+    /// span lifetimes are always properly nested.
+    Lifetime,
+    /// From the span's first entering to its last exiting.
+    ///
+    /// Use this to detect tasks escaping the span that spawned them.
+    Entered,
+}
+
+/// A process-unique key of a recorded span.
+///
+/// Unlike a span's [`Id`], keys are never reused.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SpanKey(u64);
+
+/// A closed span and the interval of time it was recorded for.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct SpanRecord {
+    /// The span's key.
+    pub key: SpanKey,
+    /// The key of the span's parent, if any.
+    pub parent: Option<SpanKey>,
+    /// The span's name.
+    pub name: &'static str,
+    /// The span's target.
+    pub target: &'static str,
+    /// The interval of time the span was recorded for.
+    pub interval: NonEmpty<Interval<Instant>>,
+}
+
+impl SpanRecord {
+    /// Returns the relation between `self` and `other`.
+    pub fn relation(&self, other: &Self) -> Relation {
+        Relation::from_intervals(&self.interval, &other.interval)
+    }
+
+    /// Returns `true` iff `self` and `other` share a non-empty period of time.
+    pub fn overlaps_with(&self, other: &Self) -> bool {
+        let (s, t) = (&self.interval, &other.interval);
+
+        !(s.precedes(t) || s.is_preceded_by(t) || s.meets(t) || s.is_met_by(t))
+    }
+
+    /// Returns `true` iff `self` lies within `parent`.
+    pub fn is_nested_in(&self, parent: &Self) -> bool {
+        let (child, parent) = (&self.interval, &parent.interval);
+
+        parent.contains(child)
+            || child.starts(parent)
+            || child.finishes(parent)
+            || child.equals(parent)
+    }
+}
+
+/// A shared handle to the spans recorded by a [`SpanIntervalLayer`].
+#[derive(Clone, Default)]
+pub struct SpanStore {
+    records: Arc<Mutex<Vec<SpanRecord>>>,
+}
+
+impl SpanStore {
+    /// Returns a snapshot of all spans closed so far.
+    pub fn snapshot(&self) -> SpanRecords {
+        let records = self
+            .records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        SpanRecords::new(records)
+    }
+
+    /// Removes all spans recorded so far.
+    pub fn clear(&self) {
+        self.records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    fn push(&self, record: SpanRecord) {
+        self.records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(record);
+    }
+}
+
+impl fmt::Debug for SpanStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpanStore").finish_non_exhaustive()
+    }
+}
+
+/// A snapshot of recorded spans, supporting queries by relation.
+#[derive(Clone, Debug, Default)]
+pub struct SpanRecords {
+    records: Vec<SpanRecord>,
+    indices: HashMap<SpanKey, usize>,
+}
+
+impl SpanRecords {
+    fn new(records: Vec<SpanRecord>) -> Self {
+        let indices = records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| (record.key, index))
+            .collect();
+
+        Self { records, indices }
+    }
+
+    /// Returns all recorded spans, in the order they were closed.
+    pub fn iter(&self) -> impl Iterator<Item = &SpanRecord> {
+        self.records.iter()
+    }
+
+    /// Returns the number of recorded spans.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` iff no spans were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the span recorded for `key`, if any.
+    pub fn get(&self, key: SpanKey) -> Option<&SpanRecord> {
+        self.indices.get(&key).map(|&index| &self.records[index])
+    }
+
+    /// Returns the recorded parent of `record`, if any.
+    pub fn parent(&self, record: &SpanRecord) -> Option<&SpanRecord> {
+        record.parent.and_then(|key| self.get(key))
+    }
+
+    /// Returns all recorded spans named `name`.
+    pub fn named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a SpanRecord> + 'a {
+        self.records
+            .iter()
+            .filter(move |record| record.name == name)
+    }
+
+    /// Returns all other recorded spans `s` for which `relation` holds between `s` and `record`.
+    pub fn with_relation<'a>(
+        &'a self,
+        record: &'a SpanRecord,
+        relation: Relation,
+    ) -> impl Iterator<Item = &'a SpanRecord> + 'a {
+        self.others(record)
+            .filter(move |other| other.relation(record) == relation)
+    }
+
+    /// Returns all other recorded spans sharing a non-empty period of time with `record`.
+    pub fn overlapping<'a>(
+        &'a self,
+        record: &'a SpanRecord,
+    ) -> impl Iterator<Item = &'a SpanRecord> + 'a {
+        self.others(record)
+            .filter(move |other| other.overlaps_with(record))
+    }
+
+    /// Returns all pairs of `(child, parent)` spans, where the child does not lie within its parent
+    /// (e.g. async tasks outliving the span that spawned them).
+    pub fn improperly_nested(&self) -> impl Iterator<Item = (&SpanRecord, &SpanRecord)> {
+        self.records.iter().filter_map(|child| {
+            let parent = self.parent(child)?;
+            (!child.is_nested_in(parent)).then_some((child, parent))
+        })
+    }
+
+    fn others<'a>(&'a self, record: &'a SpanRecord) -> impl Iterator<Item = &'a SpanRecord> + 'a {
+        self.records
+            .iter()
+            .filter(move |other| other.key != record.key)
+    }
+}
+
+type Clock = Arc<dyn Fn() -> Instant + Send + Sync>;
+
+/// A layer recording the interval of time of each span.
+///
+/// Spans of zero measured duration (as well as spans that were never entered,
+/// when using [`SpanTiming::Entered`]) are not recorded,
+/// as Allen's interval algebra is only defined for non-empty intervals.
+pub struct SpanIntervalLayer {
+    timing: SpanTiming,
+    store: SpanStore,
+    next_key: AtomicU64,
+    clock: Clock,
+}
+
+impl SpanIntervalLayer {
+    /// Creates a layer recording spans with the given `timing`.
+    pub fn new(timing: SpanTiming) -> Self {
+        Self {
+            timing,
+            store: SpanStore::default(),
+            next_key: AtomicU64::new(0),
+            clock: Arc::new(Instant::now),
+        }
+    }
+
+    /// Replaces the clock used for timing spans (defaults to `Instant::now`).
+    pub fn with_clock<F>(mut self, clock: F) -> Self
+    where
+        F: Fn() -> Instant + Send + Sync + 'static,
+    {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Returns a handle to the spans recorded by this layer.
+    pub fn store(&self) -> SpanStore {
+        self.store.clone()
+    }
+}
+
+impl fmt::Debug for SpanIntervalLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpanIntervalLayer")
+            .field("timing", &self.timing)
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Per-span timing data, stored in the span's extensions.
+struct Timing {
+    key: SpanKey,
+    parent: Option<SpanKey>,
+    created: Instant,
+    entered: Option<Instant>,
+    exited: Option<Instant>,
+}
+
+impl<S> Layer<S> for SpanIntervalLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, _attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let parent = span
+            .parent()
+            .and_then(|parent| parent.extensions().get::<Timing>().map(|timing| timing.key));
+
+        let timing = Timing {
+            key: SpanKey(self.next_key.fetch_add(1, Ordering::Relaxed)),
+            parent,
+            created: (self.clock)(),
+            entered: None,
+            exited: None,
+        };
+
+        span.extensions_mut().insert(timing);
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut extensions = span.extensions_mut();
+
+        if let Some(timing) = extensions.get_mut::<Timing>() {
+            timing.entered.get_or_insert_with(|| (self.clock)());
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut extensions = span.extensions_mut();
+
+        if let Some(timing) = extensions.get_mut::<Timing>() {
+            timing.exited = Some((self.clock)());
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+
+        let Some(timing) = span.extensions_mut().remove::<Timing>() else {
+            return;
+        };
+
+        let (start, end) = match self.timing {
+            SpanTiming::Lifetime => (timing.created, (self.clock)()),
+            SpanTiming::Entered => match (timing.entered, timing.exited) {
+                (Some(entered), Some(exited)) => (entered, exited),
+                _ => return,
+            },
+        };
+
+        let Ok(interval) = NonEmpty::try_from(Interval { start, end }) else {
+            return;
+        };
+
+        self.store.push(SpanRecord {
+            key: timing.key,
+            parent: timing.parent,
+            name: span.name(),
+            target: span.metadata().target(),
+            interval,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ::tracing::info_span;
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    use super::*;
+
+    /// Runs `f` with a layer whose clock advances by one millisecond per reading.
+    fn record<F>(timing: SpanTiming, f: F) -> SpanRecords
+    where
+        F: FnOnce(),
+    {
+        let base = Instant::now();
+        let ticks = AtomicU64::new(0);
+
+        let layer = SpanIntervalLayer::new(timing).with_clock(move || {
+            base + Duration::from_millis(ticks.fetch_add(1, Ordering::Relaxed))
+        });
+        let store = layer.store();
+
+        let subscriber = Registry::default().with(layer);
+        ::tracing::subscriber::with_default(subscriber, f);
+
+        store.snapshot()
+    }
+
+    #[test]
+    fn nested_spans() {
+        let spans = record(SpanTiming::Lifetime, || {
+            let parent = info_span!("parent");
+            let _guard = parent.enter();
+
+            let db = info_span!("db");
+            let _db = db.enter();
+            drop(info_span!("query"));
+        });
+
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans.improperly_nested().count(), 0);
+
+        let parent = spans.named("parent").next().unwrap();
+        let db = spans.named("db").next().unwrap();
+        let query = spans.named("query").next().unwrap();
+
+        assert_eq!(spans.parent(db), Some(parent));
+        assert_eq!(spans.parent(query), Some(db));
+
+        assert_eq!(
+            db.relation(parent),
+            Relation::Contains { is_inverted: true }
+        );
+
+        let overlapping: Vec<_> = spans.overlapping(db).map(|span| span.name).collect();
+        assert_eq!(overlapping, ["query", "parent"]);
+
+        let during: Vec<_> = spans
+            .with_relation(db, Relation::Contains { is_inverted: true })
+            .map(|span| span.name)
+            .collect();
+        assert_eq!(during, ["query"]);
+    }
+
+    #[test]
+    fn overlaps_with() {
+        let base = Instant::now();
+        let span = |key, start, end| SpanRecord {
+            key: SpanKey(key),
+            parent: None,
+            name: "span",
+            target: "target",
+            interval: NonEmpty::try_from(Interval {
+                start: base + Duration::from_millis(start),
+                end: base + Duration::from_millis(end),
+            })
+            .unwrap(),
+        };
+
+        let s = span(0, 2, 4);
+
+        assert!(s.overlaps_with(&span(1, 3, 5)));
+        assert!(s.overlaps_with(&span(2, 0, 4)));
+        assert!(s.overlaps_with(&span(3, 2, 3)));
+        assert!(!s.overlaps_with(&span(4, 4, 5)));
+        assert!(!s.overlaps_with(&span(5, 5, 6)));
+        assert!(!span(6, 0, 1).overlaps_with(&s));
+    }
+
+    #[test]
+    fn improperly_nested_spans() {
+        let spans = record(SpanTiming::Entered, || {
+            let parent = info_span!("parent");
+            let parent_guard = parent.enter();
+
+            let task = info_span!("task");
+            let task_guard = task.enter();
+
+            // The task keeps running after the span that spawned it was exited:
+            drop(parent_guard);
+            drop(task_guard);
+        });
+
+        let task = spans.named("task").next().unwrap();
+        let parent = spans.named("parent").next().unwrap();
+
+        assert_eq!(
+            task.relation(parent),
+            Relation::Overlaps { is_inverted: true }
+        );
+
+        let improperly_nested: Vec<_> = spans
+            .improperly_nested()
+            .map(|(child, parent)| (child.name, parent.name))
+            .collect();
+        assert_eq!(improperly_nested, [("task", "parent")]);
+    }
+
+    #[test]
+    fn entered_timing() {
+        let spans = record(SpanTiming::Entered, || {
+            let entered = info_span!("entered");
+            drop(entered.enter());
+
+            drop(info_span!("never_entered"));
+        });
+
+        let names: Vec<_> = spans.iter().map(|span| span.name).collect();
+        assert_eq!(names, ["entered"]);
+    }
+}
@@ -0,0 +1,345 @@
+//! Intervals with uncertain endpoints, relating to each other by sets of possible relations.
+//!
+//! Each endpoint of an [`UncertainInterval`] is only known to lie within a bounded range
+//! (e.g. a start somewhere in `10..=12`).
+//! Each of the four atomic comparisons of two such intervals' endpoints
+//! (i.e. start to start, start to end, end to start and end to end)
+//! is thereby bounded by comparing the ranges' extremes,
+//! and a relation is possible if and only if all four of its atomic comparisons lie within those bounds.
+//!
+//! ```
+//! use allen_intervals::{uncertain::UncertainInterval, Relation, RelationSet};
+//!
+//! // A sensor reading that started somewhere in `10..=12` and ended somewhere in `18..=19`:
+//! let reading = UncertainInterval::new(10..=12, 18..=19).unwrap();
+//! let shift = UncertainInterval::new(12..=12, 20..=20).unwrap();
+//!
+//! let relation = reading.relation(&shift);
+//! assert_eq!(relation.possible(), "{o, s}".parse::<RelationSet>().unwrap());
+//! assert_eq!(relation.necessary(), None);
+//! assert!(relation.is_necessary("{o, s}".parse::<RelationSet>().unwrap()));
+//! assert!(relation.is_possible(Relation::Starts { is_inverted: false }));
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::RangeInclusive,
+};
+
+use crate::{
+    atomic::{Bb, Be, Eb, Ee},
+    Interval, IntervalError, NonEmpty, Relation, RelationSet,
+};
+
+/// An interval, each of whose endpoints is only known to lie within a (closed) range.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct UncertainInterval<T> {
+    start: RangeInclusive<T>,
+    end: RangeInclusive<T>,
+}
+
+impl<T> UncertainInterval<T> {
+    /// Creates an interval from the ranges of its endpoints,
+    /// or returns an error if either range is empty,
+    /// if no choice of endpoints forms a non-empty interval,
+    /// or if the ranges' bounds are not totally ordered.
+    pub fn new(start: RangeInclusive<T>, end: RangeInclusive<T>) -> Result<Self, IntervalError>
+    where
+        T: PartialOrd,
+    {
+        let cmp = |a: &T, b: &T| a.partial_cmp(b).ok_or(IntervalError::AmbiguousOrder);
+
+        if cmp(start.start(), start.end())?.is_gt()
+            || cmp(end.start(), end.end())?.is_gt()
+            || cmp(start.start(), end.end())?.is_ge()
+        {
+            return Err(IntervalError::EmptyInterval);
+        }
+
+        Ok(Self { start, end })
+    }
+
+    /// Returns the range of the interval's start.
+    #[inline]
+    pub fn start(&self) -> &RangeInclusive<T> {
+        &self.start
+    }
+
+    /// Returns the range of the interval's end.
+    #[inline]
+    pub fn end(&self) -> &RangeInclusive<T> {
+        &self.end
+    }
+
+    /// Returns the relation between `self` and `other`.
+    ///
+    /// This is a shorthand for [`UncertainRelation::from_uncertain_intervals`].
+    #[inline]
+    pub fn relation(&self, other: &Self) -> UncertainRelation
+    where
+        T: Ord,
+    {
+        UncertainRelation::from_uncertain_intervals(self, other)
+    }
+}
+
+impl<T> From<NonEmpty<Interval<T>>> for UncertainInterval<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn from(interval: NonEmpty<Interval<T>>) -> Self {
+        let Interval { start, end } = interval.into_inner();
+
+        Self {
+            start: start.clone()..=start,
+            end: end.clone()..=end,
+        }
+    }
+}
+
+/// The relation between two intervals of uncertain endpoints (e.g. `s` and `t`),
+/// i.e. the set of Allen relations possible for some choice of their endpoints.
+///
+/// The endpoints are assumed to be free to take any value within their ranges
+/// (as in a continuous time domain), so that the possible relations are exact
+/// for dense types (e.g. floats or timestamps), but may include relations that require
+/// a value strictly between two adjacent values of discrete types (e.g. integers).
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct UncertainRelation {
+    possible: RelationSet,
+}
+
+impl UncertainRelation {
+    /// Returns the relation between the uncertain intervals `s` and `t`.
+    #[inline]
+    pub fn from_uncertain_intervals<T>(s: &UncertainInterval<T>, t: &UncertainInterval<T>) -> Self
+    where
+        T: Ord,
+    {
+        match Self::try_from_uncertain_intervals(s, t) {
+            Ok(relation) => relation,
+            Err(_) => unreachable!("totally ordered endpoints"),
+        }
+    }
+
+    /// Returns the relation between the uncertain intervals `s` and `t`,
+    /// or an error if any of their endpoints' bounds are not totally ordered.
+    #[inline]
+    pub fn try_from_uncertain_intervals<T>(
+        s: &UncertainInterval<T>,
+        t: &UncertainInterval<T>,
+    ) -> Result<Self, IntervalError>
+    where
+        T: PartialOrd,
+    {
+        let bounds = [
+            OrderingBounds::from_ranges(&s.start, &t.start)?,
+            OrderingBounds::from_ranges(&s.start, &t.end)?,
+            OrderingBounds::from_ranges(&s.end, &t.start)?,
+            OrderingBounds::from_ranges(&s.end, &t.end)?,
+        ];
+
+        let possible = Relation::ALL
+            .into_iter()
+            .filter(|relation| {
+                let (Bb(bb), Be(be), Eb(eb), Ee(ee)) = relation.atomic_relations();
+
+                [bb, be, eb, ee]
+                    .into_iter()
+                    .zip(&bounds)
+                    .all(|(ordering, bounds)| bounds.contains(ordering))
+            })
+            .collect();
+
+        Ok(Self { possible })
+    }
+
+    /// Returns the relations possible for some choice of endpoints.
+    #[inline]
+    pub const fn possible(&self) -> RelationSet {
+        self.possible
+    }
+
+    /// Returns the relation that holds for every choice of endpoints, if any
+    /// (i.e. if only a single relation is possible).
+    #[inline]
+    pub fn necessary(&self) -> Option<Relation> {
+        let mut relations = self.possible.iter();
+
+        match (relations.next(), relations.next()) {
+            (Some(relation), None) => Some(relation),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if any of the `relations` holds for some choice of endpoints.
+    #[inline]
+    pub fn is_possible(&self, relations: impl Into<RelationSet>) -> bool {
+        !self.possible.intersection(&relations.into()).is_empty()
+    }
+
+    /// Returns `true` if any of the `relations` holds for every choice of endpoints.
+    #[inline]
+    pub fn is_necessary(&self, relations: impl Into<RelationSet>) -> bool {
+        self.possible.intersection(&relations.into()) == self.possible
+    }
+
+    /// Returns the relation between `t` and `s` (rather than `s` and `t`).
+    #[inline]
+    pub fn as_converse(&self) -> Self {
+        Self {
+            possible: self.possible.as_converse(),
+        }
+    }
+}
+
+impl From<UncertainRelation> for RelationSet {
+    #[inline]
+    fn from(relation: UncertainRelation) -> Self {
+        relation.possible
+    }
+}
+
+impl Display for UncertainRelation {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.possible, f)
+    }
+}
+
+/// The bounds on an atomic comparison of two endpoints within ranges.
+#[derive(Clone, Copy, Debug)]
+struct OrderingBounds {
+    min: Ordering,
+    max: Ordering,
+}
+
+impl OrderingBounds {
+    /// Returns the bounds on the ordering of `x` to `y`,
+    /// as the orderings of `x`'s minimum to `y`'s maximum, and of `x`'s maximum to `y`'s minimum.
+    #[inline]
+    fn from_ranges<T>(x: &RangeInclusive<T>, y: &RangeInclusive<T>) -> Result<Self, IntervalError>
+    where
+        T: PartialOrd,
+    {
+        let cmp = |a: &T, b: &T| a.partial_cmp(b).ok_or(IntervalError::AmbiguousOrder);
+
+        Ok(Self {
+            min: cmp(x.start(), y.end())?,
+            max: cmp(x.end(), y.start())?,
+        })
+    }
+
+    #[inline]
+    fn contains(&self, ordering: Ordering) -> bool {
+        self.min <= ordering && ordering <= self.max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_helpers::{interval, intervals},
+        FromIntervals,
+    };
+
+    #[test]
+    fn crisp() {
+        let intervals = intervals(6);
+        let mut relations = RelationSet::empty();
+
+        for &s in &intervals {
+            for &t in &intervals {
+                let expected = Relation::from_intervals(&s, &t);
+                relations.insert(expected);
+
+                let relation = UncertainInterval::from(s).relation(&UncertainInterval::from(t));
+                assert_eq!(relation.necessary(), Some(expected));
+                assert_eq!(relation.possible(), RelationSet::from(expected));
+            }
+        }
+
+        assert_eq!(relations, RelationSet::all());
+    }
+
+    #[test]
+    fn completeness() {
+        // Ranges of endpoints, spread apart so that some integer lies strictly between any two:
+        let ranges = [(0, 0), (0, 3), (3, 6), (3, 3), (6, 9), (0, 9), (9, 9)];
+
+        for s_start in ranges {
+            for s_end in ranges {
+                for t_start in ranges {
+                    for t_end in ranges {
+                        let (Ok(s), Ok(t)) = (
+                            UncertainInterval::new(s_start.0..=s_start.1, s_end.0..=s_end.1),
+                            UncertainInterval::new(t_start.0..=t_start.1, t_end.0..=t_end.1),
+                        ) else {
+                            continue;
+                        };
+
+                        let mut sampled = RelationSet::empty();
+
+                        for a in s_start.0..=s_start.1 {
+                            for b in s_end.0..=s_end.1 {
+                                for c in t_start.0..=t_start.1 {
+                                    for d in t_end.0..=t_end.1 {
+                                        if a < b && c < d {
+                                            sampled.insert(Relation::from_intervals(
+                                                &interval(a, b),
+                                                &interval(c, d),
+                                            ));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        assert_eq!(
+                            s.relation(&t).possible(),
+                            sampled,
+                            "{s_start:?}, {s_end:?} vs {t_start:?}, {t_end:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn converse() {
+        let s = UncertainInterval::new(10..=12, 18..=19).unwrap();
+        let t = UncertainInterval::new(12..=15, 19..=20).unwrap();
+
+        assert_eq!(s.relation(&t).as_converse(), t.relation(&s));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            UncertainInterval::new(RangeInclusive::new(3, 2), 4..=5),
+            Err(IntervalError::EmptyInterval)
+        );
+        assert_eq!(
+            UncertainInterval::new(4..=5, 3..=4),
+            Err(IntervalError::EmptyInterval)
+        );
+        assert_eq!(
+            UncertainInterval::new(1.0..=f64::NAN, 3.0..=4.0),
+            Err(IntervalError::AmbiguousOrder)
+        );
+
+        let s = UncertainInterval::new(1.0..=2.0, 3.0..=4.0).unwrap();
+        let t = UncertainInterval {
+            start: 1.0..=2.0,
+            end: 3.0..=f64::NAN,
+        };
+        assert_eq!(
+            UncertainRelation::try_from_uncertain_intervals(&s, &t),
+            Err(IntervalError::AmbiguousOrder)
+        );
+    }
+}